@@ -1,16 +1,39 @@
-use crate::window::create_surface;
-use crate::window::ViziaWindow;
+use crate::convert::cursor_icon_to_mouse_cursor;
+use crate::sub_window::{SubWindowMessage, SubWindowState};
+use crate::window::{create_surface, ViziaWindow, Window as WindowView, WindowRequest};
 use baseview::{Window, WindowHandle, WindowScalePolicy};
 use gl_rs as gl;
 use gl_rs::types::GLint;
+use hashbrown::HashMap;
 use raw_window_handle::HasRawWindowHandle;
 use skia_safe::gpu::gl::FramebufferInfo;
+use std::sync::mpsc::{channel, Receiver, Sender};
 use vizia_core::events::EventManager;
 
 use crate::proxy::queue_get;
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
 
+pub(crate) type ResizeRequestCallback = Option<Box<dyn Fn(WindowSize) -> bool + Send>>;
+
+/// Determines how the contents of the main window respond to the window being resized, either by the
+/// host or by the application itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WindowResizePolicy {
+    /// The contents of the window are laid out again at the new size of the window.
+    #[default]
+    Relayout,
+    /// The inner size of the window given to [`Application::inner_size`] is treated as a fixed design
+    /// size, and the contents of the window are scaled uniformly to fit the window. The user scale
+    /// factor is derived from the size of the window and any value given to
+    /// [`Application::user_scale_factor`] is only used for the initial size of the window.
+    Scale {
+        /// Whether the size of the window should be constrained to the aspect ratio of the design size.
+        /// When the window is resized to a different aspect ratio a resize request is made to restore it.
+        keep_aspect_ratio: bool,
+    },
+}
+
 #[derive(Debug)]
 pub enum ApplicationError {}
 
@@ -35,6 +58,8 @@ where
     window_description: WindowDescription,
     window_scale_policy: WindowScalePolicy,
     on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+    on_resize_request: ResizeRequestCallback,
+    resize_policy: WindowResizePolicy,
     ignore_default_theme: bool,
 }
 
@@ -49,6 +74,8 @@ where
             window_description: WindowDescription::new(),
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
+            on_resize_request: None,
+            resize_policy: WindowResizePolicy::default(),
             ignore_default_theme: false,
         }
     }
@@ -98,6 +125,8 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_resize_request,
+            self.resize_policy,
             self.ignore_default_theme,
         );
 
@@ -118,6 +147,8 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_resize_request,
+            self.resize_policy,
             self.ignore_default_theme,
         )
     }
@@ -145,6 +176,56 @@ where
 
         self
     }
+
+    /// Sets how the contents of the main window respond to the window being resized.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Application, WindowResizePolicy};
+    /// Application::new(|cx|{
+    ///     // Build application here
+    ///     ResizeHandle::new(cx);
+    /// })
+    /// .inner_size((400, 300))
+    /// .resize_policy(WindowResizePolicy::Scale { keep_aspect_ratio: true })
+    /// .run();
+    /// ```
+    pub fn resize_policy(mut self, resize_policy: WindowResizePolicy) -> Self {
+        self.resize_policy = resize_policy;
+
+        self
+    }
+
+    /// Takes a closure which will be called when the application requests the main window to be resized,
+    /// for example by emitting a [`WindowEvent::SetSize`] event.
+    ///
+    /// The closure receives the requested logical size of the window, including the user scale factor,
+    /// and should return whether the request was accepted. For audio plugins this is where
+    /// the request should be forwarded to the host. The window is only resized if the closure returns
+    /// `true`. If no closure is provided then resize requests are always accepted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .on_resize_request(|size| {
+    ///     // Ask the host to resize the editor
+    ///     true
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_resize_request<R: 'static + Fn(WindowSize) -> bool + Send>(
+        mut self,
+        callback: R,
+    ) -> Self {
+        self.on_resize_request = Some(Box::new(callback));
+
+        self
+    }
 }
 
 pub(crate) struct ApplicationRunner {
@@ -174,7 +255,23 @@ pub(crate) struct ApplicationRunner {
     pub surface: skia_safe::Surface,
     pub dirty_surface: skia_safe::Surface,
     window_description: WindowDescription,
+    window_scale_policy: WindowScalePolicy,
     is_initialized: bool,
+
+    on_resize_request: ResizeRequestCallback,
+    resize_policy: WindowResizePolicy,
+    /// The scale factor applied on top of `window_scale_factor`. With [`WindowResizePolicy::Scale`]
+    /// this is derived from the size of the window.
+    user_scale_factor: f64,
+    /// A resize of the main window which should be requested during the next frame.
+    pending_resize: Option<(f64, f64)>,
+    /// The last logical size of the main window reported by baseview, including the user scale factor.
+    logical_size: Option<(f64, f64)>,
+
+    /// The state of any additional windows, created with `Window::new()` or `Window::popup()`.
+    sub_windows: HashMap<Entity, SubWindowState>,
+    sub_window_sender: Sender<SubWindowMessage>,
+    sub_window_receiver: Receiver<SubWindowMessage>,
 }
 
 impl ApplicationRunner {
@@ -186,7 +283,13 @@ impl ApplicationRunner {
         surface: skia_safe::Surface,
         dirty_surface: skia_safe::Surface,
         window_description: WindowDescription,
+        window_scale_policy: WindowScalePolicy,
+        on_resize_request: ResizeRequestCallback,
+        resize_policy: WindowResizePolicy,
     ) -> Self {
+        let (sub_window_sender, sub_window_receiver) = channel();
+        let user_scale_factor = window_description.user_scale_factor;
+
         ApplicationRunner {
             should_redraw: true,
             gr_context,
@@ -199,17 +302,39 @@ impl ApplicationRunner {
             surface,
             dirty_surface,
             window_description,
+            window_scale_policy,
             is_initialized: false,
+            on_resize_request,
+            resize_policy,
+            user_scale_factor,
+            pending_resize: None,
+            logical_size: None,
+            sub_windows: HashMap::new(),
+            sub_window_sender,
+            sub_window_receiver,
         }
     }
 
     /// Handle all reactivity within a frame. The window instance is used to resize the window when
     /// needed.
     pub fn on_frame_update(&mut self, window: &mut Window) {
+        // Timers are processed every frame so that they don't rely on OS events or the idle callback.
+        self.cx.process_timers();
+        self.cx.emit_scheduled_events();
+
         while let Some(event) = queue_get() {
             self.cx.send_event(event);
         }
 
+        while let Ok(message) = self.sub_window_receiver.try_recv() {
+            match message {
+                SubWindowMessage::Event(window_entity, event) => {
+                    let mut should_close = false;
+                    self.handle_event(window_entity, event, &mut should_close);
+                }
+            }
+        }
+
         // Events
         self.event_manager.flush_events(self.cx.context(), |window_event| match window_event {
             // For some reason calling window.close() crashes baseview on macos
@@ -223,20 +348,35 @@ impl ApplicationRunner {
             _ => {}
         });
 
+        if let Some((width, height)) = self.pending_resize.take() {
+            self.request_resize(window, width, height);
+        }
+
+        self.process_window_requests(window);
+
+        self.sync_sub_windows(window);
+
         // We need to resize the window to make sure that the new size is applied. This is a workaround
         // for the fact that baseview does not resize the window when the scale factor changes.
         if !self.is_initialized {
-            // Resizing the window doesn't apply unless the size has actually changed.
-            // So we resize the window slightly larger and then back again to force a resize event.
-            window.resize(baseview::Size {
-                width: self.window_description.inner_size.width as f64 + 1.0,
-                height: self.window_description.inner_size.height as f64 + 1.0,
-            });
+            // With the scale policy the host may have opened or restored the window at a size other
+            // than the design size, so the window is resized to its current size instead. If no size
+            // has been reported yet the user scale factor will be derived from the first resize event.
+            let size = match self.resize_policy {
+                WindowResizePolicy::Relayout => Some((
+                    self.window_description.inner_size.width as f64,
+                    self.window_description.inner_size.height as f64,
+                )),
+                WindowResizePolicy::Scale { .. } => self.logical_size,
+            };
+
+            if let Some((width, height)) = size {
+                // Resizing the window doesn't apply unless the size has actually changed.
+                // So we resize the window slightly larger and then back again to force a resize event.
+                window.resize(baseview::Size { width: width + 1.0, height: height + 1.0 });
+                window.resize(baseview::Size { width, height });
+            }
 
-            window.resize(baseview::Size {
-                width: self.window_description.inner_size.width as f64,
-                height: self.window_description.inner_size.height as f64,
-            });
             self.is_initialized = true;
         }
 
@@ -333,14 +473,184 @@ impl ApplicationRunner {
             context.swap_buffers();
             unsafe { context.make_not_current() };
         }
+
+        for (window_entity, sub_window) in self.sub_windows.iter_mut() {
+            if self.cx.draw(*window_entity, &mut sub_window.surface, &mut sub_window.dirty_surface)
+            {
+                sub_window.present();
+            }
+        }
+    }
+
+    /// Applies requests made by `Window` views, such as resizing or changing the cursor, to the
+    /// baseview windows.
+    fn process_window_requests(&mut self, window: &mut Window) {
+        let window_entities = self.cx.0.windows.keys().copied().collect::<Vec<_>>();
+
+        for window_entity in window_entities {
+            let mut requests = Vec::new();
+            self.cx.mutate_window(window_entity, |_, win: &mut WindowView| {
+                requests.append(&mut win.requests);
+            });
+
+            for request in requests {
+                if window_entity == Entity::root() {
+                    match request {
+                        WindowRequest::Resize(size) => {
+                            self.request_resize(
+                                window,
+                                size.width as f64 * self.user_scale_factor,
+                                size.height as f64 * self.user_scale_factor,
+                            );
+                        }
+
+                        WindowRequest::SetCursor(cursor) => {
+                            window.set_mouse_cursor(cursor_icon_to_mouse_cursor(cursor));
+                        }
+
+                        WindowRequest::Focus => {
+                            #[cfg(not(target_os = "linux"))] // not implemented for linux yet
+                            if !window.has_focus() {
+                                window.focus();
+                            }
+                        }
+
+                        // For some reason calling window.close() crashes baseview on macos
+                        WindowRequest::Close => {}
+                    }
+                } else if let Some(sub_window) = self.sub_windows.get_mut(&window_entity) {
+                    let request = match request {
+                        WindowRequest::Resize(size) => {
+                            let user_scale_factor = self
+                                .cx
+                                .0
+                                .windows
+                                .get(&window_entity)
+                                .map(|state| state.window_description.user_scale_factor)
+                                .unwrap_or(1.0);
+
+                            WindowRequest::Resize(WindowSize::new(
+                                (size.width as f64 * user_scale_factor).round() as u32,
+                                (size.height as f64 * user_scale_factor).round() as u32,
+                            ))
+                        }
+
+                        request => request,
+                    };
+
+                    sub_window.request(request);
+                }
+            }
+        }
+    }
+
+    /// Resizes the main window to the given logical size, which includes the user scale factor, if the
+    /// request is accepted by the resize request callback.
+    fn request_resize(&mut self, window: &mut Window, width: f64, height: f64) {
+        let (width, height) = match self.resize_policy {
+            WindowResizePolicy::Scale { keep_aspect_ratio: true } => {
+                constrain_to_aspect_ratio(self.window_description.inner_size, width, height)
+            }
+            _ => (width, height),
+        };
+
+        let size = WindowSize::new(width.round() as u32, height.round() as u32);
+
+        let accepted =
+            self.on_resize_request.as_ref().map(|callback| (callback)(size)).unwrap_or(true);
+
+        if accepted {
+            if self.resize_policy == WindowResizePolicy::Relayout {
+                self.window_description.inner_size = WindowSize::new(
+                    (width / self.user_scale_factor).round() as u32,
+                    (height / self.user_scale_factor).round() as u32,
+                );
+            }
+
+            window.resize(baseview::Size { width: size.width as f64, height: size.height as f64 });
+        }
+    }
+
+    /// Opens baseview windows for any new `Window` views and closes the windows of removed views.
+    fn sync_sub_windows(&mut self, window: &mut Window) {
+        let window_entities = self
+            .cx
+            .0
+            .windows
+            .iter()
+            .filter_map(|(entity, state)| {
+                (state.should_close && *entity != Entity::root()).then_some(*entity)
+            })
+            .collect::<Vec<_>>();
+
+        for window_entity in window_entities {
+            self.cx.0.remove(window_entity);
+        }
+
+        self.sub_windows.retain(|entity, sub_window| {
+            let is_open = self.cx.0.windows.contains_key(entity);
+            if !is_open {
+                sub_window.handle.close();
+            }
+            is_open
+        });
+
+        for (window_entity, window_state) in self.cx.0.windows.clone().into_iter() {
+            if window_entity == Entity::root() || self.sub_windows.contains_key(&window_entity) {
+                continue;
+            }
+
+            let sub_window = SubWindowState::open(
+                window,
+                window_entity,
+                &window_state.window_description,
+                self.window_scale_policy,
+                self.window_scale_factor,
+                self.sub_window_sender.clone(),
+            );
+
+            self.sub_windows.insert(window_entity, sub_window);
+
+            self.cx.add_main_window(
+                window_entity,
+                &window_state.window_description,
+                self.cx.scale_factor(),
+            );
+
+            self.cx.0.with_current(window_entity, |cx| {
+                if let Some(content) = &window_state.content {
+                    (content)(cx)
+                }
+            });
+
+            self.cx.mutate_window(window_entity, |cx, win: &mut WindowView| {
+                if let Some(callback) = &win.on_create {
+                    (callback)(&mut EventContext::new_with_current(cx.context(), window_entity));
+                }
+            });
+
+            self.cx.needs_refresh(window_entity);
+        }
     }
 
-    pub fn handle_event(&mut self, event: baseview::Event, should_quit: &mut bool) {
-        if requests_exit(&event) {
+    /// Handles an event received by the baseview window of the window with the given entity.
+    pub fn handle_event(
+        &mut self,
+        window_entity: Entity,
+        event: baseview::Event,
+        should_quit: &mut bool,
+    ) {
+        if window_entity == Entity::root() && requests_exit(&event) {
             self.cx.send_event(Event::new(WindowEvent::WindowClose));
             *should_quit = true;
         }
 
+        let window_scale_factor = self
+            .sub_windows
+            .get(&window_entity)
+            .map(|sub_window| sub_window.window_scale_factor)
+            .unwrap_or(self.window_scale_factor);
+
         let mut update_modifiers = |modifiers: vizia_input::KeyboardModifiers| {
             self.cx
                 .modifiers()
@@ -367,23 +677,26 @@ impl ApplicationRunner {
                     //       done internally to be able to separate actual HiDPI scaling from
                     //       arbitrary uniform scaling baseview only knows about its own scale
                     //       factor.
-                    let physical_posx = position.x * self.window_scale_factor;
-                    let physical_posy = position.y * self.window_scale_factor;
+                    let physical_posx = position.x * window_scale_factor;
+                    let physical_posy = position.y * window_scale_factor;
                     let cursor_x = (physical_posx) as f32;
                     let cursor_y = (physical_posy) as f32;
-                    self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+                    self.cx.emit_window_event(
+                        window_entity,
+                        WindowEvent::MouseMove(cursor_x, cursor_y),
+                    );
                 }
                 baseview::MouseEvent::ButtonPressed { button, modifiers } => {
                     update_modifiers(modifiers);
 
                     let b = translate_mouse_button(button);
-                    self.cx.emit_origin(WindowEvent::MouseDown(b));
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseDown(b));
                 }
                 baseview::MouseEvent::ButtonReleased { button, modifiers } => {
                     update_modifiers(modifiers);
 
                     let b = translate_mouse_button(button);
-                    self.cx.emit_origin(WindowEvent::MouseUp(b));
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseUp(b));
                 }
                 baseview::MouseEvent::WheelScrolled { delta, modifiers } => {
                     update_modifiers(modifiers);
@@ -408,15 +721,18 @@ impl ApplicationRunner {
                         ),
                     };
 
-                    self.cx.emit_origin(WindowEvent::MouseScroll(lines_x, lines_y));
+                    self.cx.emit_window_event(
+                        window_entity,
+                        WindowEvent::MouseScroll(lines_x, lines_y),
+                    );
                 }
 
                 baseview::MouseEvent::CursorEntered => {
-                    self.cx.emit_origin(WindowEvent::MouseEnter);
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseEnter);
                 }
 
                 baseview::MouseEvent::CursorLeft => {
-                    self.cx.emit_origin(WindowEvent::MouseLeave);
+                    self.cx.emit_window_event(window_entity, WindowEvent::MouseLeave);
                 }
 
                 _ => {}
@@ -447,21 +763,50 @@ impl ApplicationRunner {
                     MouseButtonState::Pressed => {
                         if let vizia_input::Key::Character(written) = &event.key {
                             for chr in written.chars() {
-                                self.cx.emit_origin(WindowEvent::CharInput(chr));
+                                self.cx
+                                    .emit_window_event(window_entity, WindowEvent::CharInput(chr));
                             }
                         }
 
-                        self.cx.emit_origin(WindowEvent::KeyDown(event.code, Some(event.key)));
+                        self.cx.emit_window_event(
+                            window_entity,
+                            WindowEvent::KeyDown(event.code, Some(event.key)),
+                        );
                     }
 
                     MouseButtonState::Released => {
-                        self.cx.emit_origin(WindowEvent::KeyUp(event.code, Some(event.key)));
+                        self.cx.emit_window_event(
+                            window_entity,
+                            WindowEvent::KeyUp(event.code, Some(event.key)),
+                        );
                     }
                 }
             }
             baseview::Event::Window(event) => match event {
-                baseview::WindowEvent::Focused => self.cx.needs_refresh(Entity::root()),
+                baseview::WindowEvent::Focused => self.cx.needs_refresh(window_entity),
                 baseview::WindowEvent::Resized(window_info) => {
+                    if let Some(sub_window) = self.sub_windows.get_mut(&window_entity) {
+                        let physical_size = (
+                            window_info.physical_size().width as i32,
+                            window_info.physical_size().height as i32,
+                        );
+
+                        sub_window.resize(physical_size.0, physical_size.1);
+
+                        if self.use_system_scaling {
+                            sub_window.window_scale_factor = window_info.scale();
+                        }
+
+                        self.cx.set_window_size(
+                            window_entity,
+                            physical_size.0 as f32,
+                            physical_size.1 as f32,
+                        );
+
+                        self.cx.needs_refresh(window_entity);
+                        return;
+                    }
+
                     let fb_info = {
                         let mut fboid: GLint = 0;
                         unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
@@ -507,6 +852,33 @@ impl ApplicationRunner {
                         self.window_scale_factor = window_info.scale();
                     }
 
+                    let logical_size = window_info.logical_size();
+                    self.logical_size = Some((logical_size.width, logical_size.height));
+
+                    if let WindowResizePolicy::Scale { keep_aspect_ratio } = self.resize_policy {
+                        let design_size = self.window_description.inner_size;
+
+                        let (width, height) = constrain_to_aspect_ratio(
+                            design_size,
+                            logical_size.width,
+                            logical_size.height,
+                        );
+
+                        self.user_scale_factor =
+                            scale_to_fit(design_size, logical_size.width, logical_size.height);
+
+                        self.cx.set_scale_factor(self.window_scale_factor * self.user_scale_factor);
+
+                        // Ask for the window to be resized back to the aspect ratio of the design size
+                        // if the host resized it to a different one.
+                        if keep_aspect_ratio
+                            && ((width - logical_size.width).abs() >= 1.0
+                                || (height - logical_size.height).abs() >= 1.0)
+                        {
+                            self.pending_resize = Some((width, height));
+                        }
+                    }
+
                     //let user_scale_factor = self.cx.user_scale_factor();
 
                     //self.cx.set_scale_factor(self.window_scale_factor * user_scale_factor);
@@ -523,7 +895,11 @@ impl ApplicationRunner {
                     self.cx.needs_refresh(Entity::root());
                 }
                 baseview::WindowEvent::WillClose => {
-                    self.cx.send_event(Event::new(WindowEvent::WindowClose));
+                    if window_entity == Entity::root() {
+                        self.cx.send_event(Event::new(WindowEvent::WindowClose));
+                    } else {
+                        self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
+                    }
                 }
                 _ => {}
             },
@@ -538,6 +914,24 @@ impl ApplicationRunner {
     }
 }
 
+/// Returns the largest size with the aspect ratio of the design size which fits within the given size.
+fn constrain_to_aspect_ratio(design_size: WindowSize, width: f64, height: f64) -> (f64, f64) {
+    let design_width = design_size.width.max(1) as f64;
+    let design_height = design_size.height.max(1) as f64;
+
+    let scale = scale_to_fit(design_size, width, height);
+
+    (design_width * scale, design_height * scale)
+}
+
+/// Returns the user scale factor needed for the design size to fit within the given logical size.
+fn scale_to_fit(design_size: WindowSize, width: f64, height: f64) -> f64 {
+    let design_width = design_size.width.max(1) as f64;
+    let design_height = design_size.height.max(1) as f64;
+
+    (width / design_width).min(height / design_height)
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(event: &baseview::Event) -> bool {
@@ -568,3 +962,35 @@ fn translate_mouse_button(button: baseview::MouseButton) -> MouseButton {
         baseview::MouseButton::Forward => MouseButton::Other(5),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constrain_to_aspect_ratio_fits_within_size() {
+        let design_size = WindowSize::new(400, 300);
+
+        assert_eq!(constrain_to_aspect_ratio(design_size, 800.0, 600.0), (800.0, 600.0));
+        assert_eq!(constrain_to_aspect_ratio(design_size, 800.0, 300.0), (400.0, 300.0));
+        assert_eq!(constrain_to_aspect_ratio(design_size, 200.0, 600.0), (200.0, 150.0));
+    }
+
+    #[test]
+    fn scale_to_fit_uses_smallest_axis() {
+        let design_size = WindowSize::new(400, 300);
+
+        assert_eq!(scale_to_fit(design_size, 400.0, 300.0), 1.0);
+        assert_eq!(scale_to_fit(design_size, 800.0, 600.0), 2.0);
+        assert_eq!(scale_to_fit(design_size, 1000.0, 450.0), 1.5);
+        assert_eq!(scale_to_fit(design_size, 200.0, 600.0), 0.5);
+    }
+
+    #[test]
+    fn zero_design_size_does_not_divide_by_zero() {
+        let design_size = WindowSize::new(0, 0);
+
+        assert_eq!(scale_to_fit(design_size, 100.0, 50.0), 50.0);
+        assert_eq!(constrain_to_aspect_ratio(design_size, 100.0, 50.0), (50.0, 50.0));
+    }
+}
@@ -0,0 +1,43 @@
+use baseview::MouseCursor;
+use vizia_core::style::CursorIcon as ViziaCursorIcon;
+
+pub fn cursor_icon_to_mouse_cursor(cursor_icon: ViziaCursorIcon) -> MouseCursor {
+    match cursor_icon {
+        ViziaCursorIcon::Default => MouseCursor::Default,
+        ViziaCursorIcon::Crosshair => MouseCursor::Crosshair,
+        ViziaCursorIcon::Hand => MouseCursor::Hand,
+        ViziaCursorIcon::Arrow => MouseCursor::Default,
+        ViziaCursorIcon::Move => MouseCursor::Move,
+        ViziaCursorIcon::Text => MouseCursor::Text,
+        ViziaCursorIcon::Wait => MouseCursor::Working,
+        ViziaCursorIcon::Help => MouseCursor::Help,
+        ViziaCursorIcon::Progress => MouseCursor::PtrWorking,
+        ViziaCursorIcon::NotAllowed => MouseCursor::NotAllowed,
+        ViziaCursorIcon::ContextMenu => MouseCursor::Default,
+        ViziaCursorIcon::Cell => MouseCursor::Cell,
+        ViziaCursorIcon::VerticalText => MouseCursor::VerticalText,
+        ViziaCursorIcon::Alias => MouseCursor::Alias,
+        ViziaCursorIcon::Copy => MouseCursor::Copy,
+        ViziaCursorIcon::NoDrop => MouseCursor::PtrNotAllowed,
+        ViziaCursorIcon::Grab => MouseCursor::Hand,
+        ViziaCursorIcon::Grabbing => MouseCursor::HandGrabbing,
+        ViziaCursorIcon::AllScroll => MouseCursor::AllScroll,
+        ViziaCursorIcon::ZoomIn => MouseCursor::ZoomIn,
+        ViziaCursorIcon::ZoomOut => MouseCursor::ZoomOut,
+        ViziaCursorIcon::EResize => MouseCursor::EResize,
+        ViziaCursorIcon::NResize => MouseCursor::NResize,
+        ViziaCursorIcon::NeResize => MouseCursor::NeResize,
+        ViziaCursorIcon::NwResize => MouseCursor::NwResize,
+        ViziaCursorIcon::SResize => MouseCursor::SResize,
+        ViziaCursorIcon::SeResize => MouseCursor::SeResize,
+        ViziaCursorIcon::SwResize => MouseCursor::SwResize,
+        ViziaCursorIcon::WResize => MouseCursor::WResize,
+        ViziaCursorIcon::EwResize => MouseCursor::EwResize,
+        ViziaCursorIcon::NsResize => MouseCursor::NsResize,
+        ViziaCursorIcon::NeswResize => MouseCursor::NeswResize,
+        ViziaCursorIcon::NwseResize => MouseCursor::NwseResize,
+        ViziaCursorIcon::ColResize => MouseCursor::ColResize,
+        ViziaCursorIcon::RowResize => MouseCursor::RowResize,
+        ViziaCursorIcon::None => MouseCursor::Hidden,
+    }
+}
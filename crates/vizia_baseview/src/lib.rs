@@ -1,11 +1,16 @@
 #![allow(clippy::type_complexity)]
 mod application;
+mod convert;
 mod parent_window;
 pub(crate) mod proxy;
+mod sub_window;
 mod window;
+mod window_modifiers;
 
 pub use parent_window::ParentWindow;
 
-pub use application::{Application, ApplicationError};
+pub use application::{Application, ApplicationError, WindowResizePolicy};
+pub use window::Window;
+pub use window_modifiers::WindowModifiers;
 
 pub use baseview::{WindowHandle, WindowScalePolicy};
@@ -0,0 +1,228 @@
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use baseview::gl::GlConfig;
+use baseview::{
+    Event, EventStatus, Window, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy,
+};
+use gl_rs as gl;
+use gl_rs::types::GLint;
+use skia_safe::gpu::gl::FramebufferInfo;
+use skia_safe::gpu::{ganesh::context_options, ContextOptions};
+use skia_safe::{Color, Surface};
+
+use crate::convert::cursor_icon_to_mouse_cursor;
+use crate::window::{create_surface, WindowRequest};
+use vizia_core::backend::*;
+use vizia_core::prelude::*;
+
+/// Messages sent from the handler of a sub-window to the runner of the main window, which owns the
+/// [`Context`] shared by all windows of the application.
+pub(crate) enum SubWindowMessage {
+    /// A baseview event received by the sub-window.
+    Event(Entity, Event),
+}
+
+/// State shared between the runner of the main window and the handler of a sub-window.
+#[derive(Default)]
+pub(crate) struct SubWindowShared {
+    /// The most recently rendered frame, waiting to be presented by the sub-window.
+    pub frame: Option<skia_safe::Image>,
+    /// Requests from the `Window` view which must be applied by the sub-window itself.
+    pub requests: Vec<WindowRequest>,
+}
+
+/// State kept by the runner of the main window for each open sub-window.
+pub(crate) struct SubWindowState {
+    pub handle: WindowHandle,
+    pub shared: Arc<Mutex<SubWindowShared>>,
+    pub surface: Surface,
+    pub dirty_surface: Surface,
+    pub window_scale_factor: f64,
+}
+
+impl SubWindowState {
+    /// Opens a new baseview window, parented to the given window, for the sub-window with the given entity.
+    pub fn open(
+        parent: &Window,
+        window_entity: Entity,
+        win_desc: &WindowDescription,
+        scale_policy: WindowScalePolicy,
+        window_scale_factor: f64,
+        sender: Sender<SubWindowMessage>,
+    ) -> Self {
+        let window_settings = WindowOpenOptions {
+            title: win_desc.title.clone(),
+            size: baseview::Size::new(
+                win_desc.inner_size.width as f64 * win_desc.user_scale_factor,
+                win_desc.inner_size.height as f64 * win_desc.user_scale_factor,
+            ),
+            scale: scale_policy,
+            gl_config: Some(GlConfig { vsync: win_desc.vsync, ..GlConfig::default() }),
+        };
+
+        let shared = Arc::new(Mutex::new(SubWindowShared::default()));
+
+        let handle = Window::open_parented(parent, window_settings, {
+            let shared = shared.clone();
+            move |window: &mut baseview::Window<'_>| -> SubWindow {
+                SubWindow::new(window, window_entity, shared, sender)
+            }
+        });
+
+        let physical_width = (win_desc.inner_size.width as f64 * window_scale_factor) as i32;
+        let physical_height = (win_desc.inner_size.height as f64 * window_scale_factor) as i32;
+
+        let (surface, dirty_surface) = create_raster_surfaces(physical_width, physical_height);
+
+        Self { handle, shared, surface, dirty_surface, window_scale_factor }
+    }
+
+    /// Recreates the offscreen surfaces used to render the sub-window after it has been resized.
+    pub fn resize(&mut self, physical_width: i32, physical_height: i32) {
+        let (surface, dirty_surface) = create_raster_surfaces(physical_width, physical_height);
+        self.surface = surface;
+        self.dirty_surface = dirty_surface;
+    }
+
+    /// Hands the current contents of the offscreen surface to the sub-window for presentation.
+    pub fn present(&mut self) {
+        let image = self.surface.image_snapshot();
+        self.shared.lock().unwrap().frame = Some(image);
+    }
+
+    /// Forwards a request from the `Window` view to the sub-window.
+    pub fn request(&mut self, request: WindowRequest) {
+        self.shared.lock().unwrap().requests.push(request);
+    }
+}
+
+fn create_raster_surfaces(physical_width: i32, physical_height: i32) -> (Surface, Surface) {
+    let size = (physical_width.max(1), physical_height.max(1));
+
+    let mut surface =
+        skia_safe::surfaces::raster_n32_premul(size).expect("Could not create skia surface");
+    let dirty_surface =
+        surface.new_surface_with_dimensions(size).expect("Could not create skia surface");
+
+    (surface, dirty_surface)
+}
+
+/// Handles a baseview window created for a `Window` view.
+///
+/// The sub-window does not own any application state. Its contents are rendered by the runner of the
+/// main window into an offscreen surface and the resulting image is presented here, while the events
+/// it receives are forwarded back to the main runner.
+pub(crate) struct SubWindow {
+    entity: Entity,
+    shared: Arc<Mutex<SubWindowShared>>,
+    sender: Sender<SubWindowMessage>,
+    gr_context: skia_safe::gpu::DirectContext,
+    surface: Surface,
+}
+
+impl SubWindow {
+    fn new(
+        window: &mut baseview::Window,
+        entity: Entity,
+        shared: Arc<Mutex<SubWindowShared>>,
+        sender: Sender<SubWindowMessage>,
+    ) -> Self {
+        let context = window.gl_context().expect("Window was created without OpenGL support");
+
+        unsafe { context.make_current() };
+
+        gl::load_with(|s| context.get_proc_address(s));
+        let interface = skia_safe::gpu::gl::Interface::new_load_with(|name| {
+            if name == "eglGetCurrentDisplay" {
+                return std::ptr::null();
+            }
+            context.get_proc_address(name)
+        })
+        .expect("Could not create interface");
+
+        // https://github.com/rust-skia/rust-skia/issues/476
+        let mut context_options = ContextOptions::new();
+        context_options.skip_gl_error_checks = context_options::Enable::Yes;
+
+        let mut gr_context = skia_safe::gpu::direct_contexts::make_gl(interface, &context_options)
+            .expect("Could not create direct context");
+
+        // The correct size is applied by the `Resized` event sent after the window is created.
+        let surface = create_surface((1, 1), framebuffer_info(), &mut gr_context);
+
+        unsafe { context.make_not_current() };
+
+        Self { entity, shared, sender, gr_context, surface }
+    }
+}
+
+impl WindowHandler for SubWindow {
+    fn on_frame(&mut self, window: &mut Window) {
+        let (frame, requests) = {
+            let mut shared = self.shared.lock().unwrap();
+            (shared.frame.take(), std::mem::take(&mut shared.requests))
+        };
+
+        for request in requests {
+            match request {
+                WindowRequest::Resize(size) => {
+                    window.resize(baseview::Size::new(size.width as f64, size.height as f64))
+                }
+                WindowRequest::SetCursor(cursor) => {
+                    window.set_mouse_cursor(cursor_icon_to_mouse_cursor(cursor))
+                }
+                WindowRequest::Focus => {
+                    #[cfg(not(target_os = "linux"))] // not implemented for linux yet
+                    if !window.has_focus() {
+                        window.focus();
+                    }
+                }
+                WindowRequest::Close => window.close(),
+            }
+        }
+
+        if let Some(image) = frame {
+            let context = window.gl_context().expect("Window was created without OpenGL support");
+            unsafe { context.make_current() };
+            let canvas = self.surface.canvas();
+            canvas.clear(Color::TRANSPARENT);
+            canvas.draw_image(&image, (0, 0), None);
+            self.gr_context.flush_and_submit();
+            context.swap_buffers();
+            unsafe { context.make_not_current() };
+        }
+    }
+
+    fn on_event(&mut self, window: &mut Window<'_>, event: Event) -> EventStatus {
+        if let Event::Window(baseview::WindowEvent::Resized(window_info)) = &event {
+            let context = window.gl_context().expect("Window was created without OpenGL support");
+            unsafe { context.make_current() };
+            self.surface = create_surface(
+                (
+                    window_info.physical_size().width as i32,
+                    window_info.physical_size().height as i32,
+                ),
+                framebuffer_info(),
+                &mut self.gr_context,
+            );
+            unsafe { context.make_not_current() };
+        }
+
+        // The main runner may already have been dropped if the application is shutting down.
+        let _ = self.sender.send(SubWindowMessage::Event(self.entity, event));
+
+        EventStatus::Captured
+    }
+}
+
+fn framebuffer_info() -> FramebufferInfo {
+    let mut fboid: GLint = 0;
+    unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
+
+    FramebufferInfo {
+        fboid: fboid.try_into().unwrap(),
+        format: skia_safe::gpu::gl::Format::RGBA8.into(),
+        ..Default::default()
+    }
+}
@@ -1,8 +1,6 @@
-use crate::application::ApplicationRunner;
+use crate::application::{ApplicationRunner, ResizeRequestCallback, WindowResizePolicy};
 use baseview::gl::GlConfig;
-use baseview::{
-    Event, EventStatus, Window, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy,
-};
+use baseview::{EventStatus, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy};
 use gl::types::GLint;
 use gl_rs as gl;
 use raw_window_handle::HasRawWindowHandle;
@@ -13,6 +11,8 @@ use skia_safe::gpu::{
 use skia_safe::{ColorSpace, ColorType, PixelGeometry, Surface, SurfaceProps, SurfacePropsFlags};
 
 use crate::proxy::BaseviewProxy;
+use crate::window_modifiers::WindowModifiers;
+use std::sync::Arc;
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
 
@@ -31,6 +31,8 @@ impl ViziaWindow {
         window: &mut baseview::Window,
         builder: Option<Box<dyn FnOnce(&mut Context) + Send>>,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_resize_request: ResizeRequestCallback,
+        resize_policy: WindowResizePolicy,
     ) -> ViziaWindow {
         let context = window.gl_context().expect("Window was created without OpenGL support");
 
@@ -90,7 +92,7 @@ impl ViziaWindow {
         let dpi_factor = window_scale_factor * win_desc.user_scale_factor;
 
         cx.add_main_window(Entity::root(), &win_desc, dpi_factor as f32);
-        cx.add_window(WindowView {});
+        cx.add_window(Window::root());
 
         cx.0.windows.insert(
             Entity::root(),
@@ -110,6 +112,9 @@ impl ViziaWindow {
             surface,
             dirty_surface,
             win_desc,
+            window_scale_policy,
+            on_resize_request,
+            resize_policy,
         );
         unsafe { context.make_not_current() };
 
@@ -126,6 +131,8 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_resize_request: ResizeRequestCallback,
+        resize_policy: WindowResizePolicy,
         ignore_default_theme: bool,
     ) -> WindowHandle
     where
@@ -145,7 +152,7 @@ impl ViziaWindow {
             gl_config: Some(GlConfig { vsync: true, ..GlConfig::default() }),
         };
 
-        Window::open_parented(
+        baseview::Window::open_parented(
             parent,
             window_settings,
             move |window: &mut baseview::Window<'_>| -> ViziaWindow {
@@ -157,7 +164,22 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+
+                #[cfg(feature = "clipboard")]
+                if let Ok(clipboard) = copypasta::ClipboardContext::new() {
+                    cx.set_clipboard_provider(Box::new(clipboard));
+                }
+
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    on_resize_request,
+                    resize_policy,
+                )
             },
         )
     }
@@ -170,6 +192,8 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_resize_request: ResizeRequestCallback,
+        resize_policy: WindowResizePolicy,
         ignore_default_theme: bool,
     ) where
         F: Fn(&mut Context),
@@ -185,7 +209,7 @@ impl ViziaWindow {
             gl_config: Some(GlConfig { vsync: true, ..GlConfig::default() }),
         };
 
-        Window::open_blocking(
+        baseview::Window::open_blocking(
             window_settings,
             move |window: &mut baseview::Window<'_>| -> ViziaWindow {
                 let mut cx = Context::new();
@@ -196,23 +220,42 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+
+                #[cfg(feature = "clipboard")]
+                if let Ok(clipboard) = copypasta::ClipboardContext::new() {
+                    cx.set_clipboard_provider(Box::new(clipboard));
+                }
+
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    on_resize_request,
+                    resize_policy,
+                )
             },
         )
     }
 }
 
 impl WindowHandler for ViziaWindow {
-    fn on_frame(&mut self, window: &mut Window) {
+    fn on_frame(&mut self, window: &mut baseview::Window) {
         self.application.on_frame_update(window);
 
         self.application.render(window);
     }
 
-    fn on_event(&mut self, window: &mut Window<'_>, event: Event) -> EventStatus {
+    fn on_event(
+        &mut self,
+        window: &mut baseview::Window<'_>,
+        event: baseview::Event,
+    ) -> EventStatus {
         let mut should_quit = false;
 
-        self.application.handle_event(event, &mut should_quit);
+        self.application.handle_event(Entity::root(), event, &mut should_quit);
 
         self.application.handle_idle(&self.on_idle);
 
@@ -224,9 +267,243 @@ impl WindowHandler for ViziaWindow {
     }
 }
 
-pub struct WindowView {}
+/// A request from a [`Window`] view which must be applied to the underlying baseview window.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WindowRequest {
+    Resize(WindowSize),
+    SetCursor(CursorIcon),
+    Focus,
+    Close,
+}
+
+type WindowCallback = Option<Box<dyn Fn(&mut EventContext)>>;
+
+/// A view representing a window of the application.
+///
+/// The root view of the application is a `Window`, and additional windows can be created with
+/// [`Window::new`] and [`Window::popup`]. On baseview, additional windows are parented to the main
+/// window of the application, which means that position and anchor properties are ignored.
+pub struct Window {
+    pub on_close: WindowCallback,
+    pub on_create: WindowCallback,
+    pub should_close: bool,
+    pub(crate) requests: Vec<WindowRequest>,
+}
+
+impl Window {
+    pub(crate) fn root() -> Self {
+        Self { on_close: None, on_create: None, should_close: false, requests: Vec::new() }
+    }
+
+    pub fn new(cx: &mut Context, content: impl 'static + Fn(&mut Context)) -> Handle<Self> {
+        Self::root().build(cx, |cx| {
+            cx.windows.insert(
+                cx.current(),
+                WindowState { content: Some(Arc::new(content)), ..Default::default() },
+            );
+            cx.tree.set_window(cx.current(), true);
+        })
+    }
+
+    pub fn popup(
+        cx: &mut Context,
+        is_modal: bool,
+        content: impl 'static + Fn(&mut Context),
+    ) -> Handle<Self> {
+        Self::root()
+            .build(cx, |cx| {
+                let parent_window = cx.parent_window();
+                if is_modal {
+                    cx.emit_to(parent_window, WindowEvent::SetEnabled(false));
+                }
+
+                cx.windows.insert(
+                    cx.current(),
+                    WindowState {
+                        owner: Some(parent_window),
+                        is_modal: true,
+                        content: Some(Arc::new(content)),
+                        ..Default::default()
+                    },
+                );
+                cx.tree.set_window(cx.current(), true);
+            })
+            .lock_focus_to_within()
+    }
+}
+
+impl View for Window {
+    fn element(&self) -> Option<&'static str> {
+        Some("window")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::Destroyed => {
+                let parent_window = cx.parent_window();
+                cx.emit_to(parent_window, WindowEvent::SetEnabled(true));
+            }
+
+            WindowEvent::SetCursor(cursor) => {
+                self.requests.push(WindowRequest::SetCursor(*cursor));
+            }
+
+            WindowEvent::SetSize(size) => {
+                self.requests.push(WindowRequest::Resize(*size));
+            }
+
+            WindowEvent::ReloadStyles => {
+                cx.reload_styles().unwrap();
+            }
+
+            WindowEvent::WindowClose => {
+                self.should_close = true;
+
+                cx.close_window();
+
+                if let Some(callback) = &self.on_close {
+                    callback(cx);
+                }
+
+                self.requests.push(WindowRequest::Close);
+
+                meta.consume();
+            }
+
+            WindowEvent::FocusNext => {
+                cx.focus_next();
+            }
+
+            WindowEvent::FocusPrev => {
+                cx.focus_prev();
+            }
+
+            WindowEvent::SetEnabled(_) => {
+                self.requests.push(WindowRequest::Focus);
+            }
+
+            _ => {}
+        })
+    }
+}
+
+impl WindowModifiers for Handle<'_, Window> {
+    fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self {
+        self.modify(|window| window.on_close = Some(Box::new(callback)))
+    }
+
+    fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self {
+        self.modify(|window| window.on_create = Some(Box::new(callback)))
+    }
+
+    fn title<T: ToString>(self, title: impl Res<T>) -> Self {
+        let title = title.get(&self).to_string();
+        self.modify_description(|description| description.title = title)
+    }
+
+    fn inner_size<S: Into<WindowSize>>(self, size: impl Res<S>) -> Self {
+        let size = size.get(&self).into();
+        self.modify_description(|description| description.inner_size = size)
+    }
+
+    fn min_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self {
+        let size = size.get(&self).map(|size| size.into());
+        self.modify_description(|description| description.min_inner_size = size)
+    }
+
+    fn max_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self {
+        let size = size.get(&self).map(|size| size.into());
+        self.modify_description(|description| description.max_inner_size = size)
+    }
+
+    fn position<P: Into<WindowPosition>>(self, position: impl Res<P>) -> Self {
+        let position = Some(position.get(&self).into());
+        self.modify_description(|description| description.position = position)
+    }
+
+    fn offset<P: Into<WindowPosition>>(self, offset: impl Res<P>) -> Self {
+        let offset = Some(offset.get(&self).into());
+        self.modify_description(|description| description.offset = offset)
+    }
+
+    fn anchor<P: Into<Anchor>>(self, anchor: impl Res<P>) -> Self {
+        let anchor = Some(anchor.get(&self).into());
+        self.modify_description(|description| description.anchor = anchor)
+    }
+
+    fn anchor_target<P: Into<AnchorTarget>>(self, anchor_target: impl Res<P>) -> Self {
+        let anchor_target = Some(anchor_target.get(&self).into());
+        self.modify_description(|description| description.anchor_target = anchor_target)
+    }
+
+    fn parent_anchor<P: Into<Anchor>>(self, parent_anchor: impl Res<P>) -> Self {
+        let parent_anchor = Some(parent_anchor.get(&self).into());
+        self.modify_description(|description| description.parent_anchor = parent_anchor)
+    }
+
+    fn resizable(self, flag: impl Res<bool>) -> Self {
+        let flag = flag.get(&self);
+        self.modify_description(|description| description.resizable = flag)
+    }
+
+    fn minimized(self, flag: impl Res<bool>) -> Self {
+        let flag = flag.get(&self);
+        self.modify_description(|description| description.minimized = flag)
+    }
+
+    fn maximized(self, flag: impl Res<bool>) -> Self {
+        let flag = flag.get(&self);
+        self.modify_description(|description| description.maximized = flag)
+    }
+
+    fn visible(self, flag: impl Res<bool>) -> Self {
+        let flag = flag.get(&self);
+        self.modify_description(|description| description.visible = flag)
+    }
+
+    fn transparent(self, flag: bool) -> Self {
+        self.modify_description(|description| description.transparent = flag)
+    }
+
+    fn decorations(self, flag: bool) -> Self {
+        self.modify_description(|description| description.decorations = flag)
+    }
+
+    fn always_on_top(self, flag: bool) -> Self {
+        self.modify_description(|description| description.always_on_top = flag)
+    }
+
+    fn vsync(self, flag: bool) -> Self {
+        self.modify_description(|description| description.vsync = flag)
+    }
+
+    fn icon(self, width: u32, height: u32, image: Vec<u8>) -> Self {
+        self.modify_description(|description| {
+            description.icon = Some(image);
+            description.icon_width = width;
+            description.icon_height = height;
+        })
+    }
+
+    fn enabled_window_buttons(self, window_buttons: WindowButtons) -> Self {
+        self.modify_description(|description| description.enabled_window_buttons = window_buttons)
+    }
+}
+
+trait ModifyDescription {
+    fn modify_description(self, f: impl FnOnce(&mut WindowDescription)) -> Self;
+}
+
+impl ModifyDescription for Handle<'_, Window> {
+    fn modify_description(mut self, f: impl FnOnce(&mut WindowDescription)) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            (f)(&mut win_state.window_description);
+        }
 
-impl View for WindowView {}
+        self
+    }
+}
 
 pub fn create_surface(
     size: (i32, i32),
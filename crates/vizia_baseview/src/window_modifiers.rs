@@ -0,0 +1,211 @@
+use vizia_core::prelude::{Anchor, AnchorTarget, WindowButtons, WindowPosition, WindowSize};
+use vizia_core::{binding::Res, context::EventContext};
+
+/// Modifiers for setting the properties of a window.
+pub trait WindowModifiers {
+    fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
+    fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
+    /// Sets the title of the window to the given value. Accepts a type, or lens to a type, which implements `ToString`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .title("Vizia Application");
+    /// ```
+    fn title<T: ToString>(self, title: impl Res<T>) -> Self;
+    /// Sets the inner size of the window to the given value. Accepts a value, or lens, which can be converted to a [`WindowSize`].
+    ///
+    /// The inner size is the window area excluding the window borders.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .inner_size((300, 300));
+    /// ```
+    fn inner_size<S: Into<WindowSize>>(self, size: impl Res<S>) -> Self;
+    /// Sets the minimum inner size of the window to the given value. Accepts an optional value, or lens, which can be converted to a [`WindowSize`].
+    ///
+    /// Setting the minimum inner size to `None` removes the minimum inner size constraint from the window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .min_inner_size(Some((300, 300)));
+    /// ```
+    fn min_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self;
+    /// Sets the maximum inner size of the window to the given value. Accepts an optional value, or lens, which can be converted to a [`WindowSize`].
+    ///
+    /// Setting the maximum inner size to `None` removes the maximum inner size constraint from the window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .max_inner_size(Some((1000, 1000)));
+    /// ```
+    fn max_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self;
+    /// Sets the position of the window to the given value. Accepts a value, or lens, which can be converted to a [`Position`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .position((100, 200));
+    /// ```
+    fn position<P: Into<WindowPosition>>(self, position: impl Res<P>) -> Self;
+
+    fn offset<P: Into<WindowPosition>>(self, offset: impl Res<P>) -> Self;
+
+    fn anchor<P: Into<Anchor>>(self, anchor: impl Res<P>) -> Self;
+
+    fn anchor_target<P: Into<AnchorTarget>>(self, anchor_target: impl Res<P>) -> Self;
+
+    fn parent_anchor<P: Into<Anchor>>(self, anchor: impl Res<P>) -> Self;
+
+    /// Sets whether the window can be resized. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .resizable(false);
+    /// ```
+    fn resizable(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is minimized. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .minimized(true);
+    /// ```
+    fn minimized(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is maximized. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .maximized(true);
+    /// ```
+    fn maximized(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is visible. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .visible(false);
+    /// ```
+    fn visible(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is transparent. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true);
+    /// ```
+    fn transparent(self, flag: bool) -> Self;
+    /// Sets whether the window has decorations. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .decorations(false);
+    /// ```
+    fn decorations(self, flag: bool) -> Self;
+    /// Sets whether the window should be on top of other windows. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .always_on_top(true);
+    /// ```
+    fn always_on_top(self, flag: bool) -> Self;
+    /// Sets whether the window has vsync enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .vsync(true);
+    /// ```
+    fn vsync(self, flag: bool) -> Self;
+    /// Sets the icon used for the window.
+    ///
+    /// # Example
+    /// ```no_run, ignore
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::{Window, WindowModifiers};
+    /// # let cx = &mut Context::default();
+    ///
+    /// let icon = vizia::image::load_from_memory(include_bytes!("../icon.png"))
+    ///     .expect("Failed to load icon");
+    ///
+    /// Window::new(cx, |cx|{
+    ///     // Content here
+    /// })
+    /// .icon(icon.width(), icon.height(), icon.into_bytes());
+    /// ```
+    fn icon(self, width: u32, height: u32, image: Vec<u8>) -> Self;
+
+    fn enabled_window_buttons(self, window_buttons: WindowButtons) -> Self;
+}
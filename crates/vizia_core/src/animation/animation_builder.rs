@@ -1,6 +1,21 @@
 use crate::prelude::*;
 
-use vizia_style::{BorderWidth, Property};
+use vizia_style::{BorderColor, BorderWidth, Property};
+
+macro_rules! keyframe_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $t:ty, $property:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(mut self, val: impl Into<$t>) -> Self {
+            let val: $t = val.into();
+            self.properties.push(Property::$property(val.into()));
+
+            self
+        }
+    };
+}
 
 /// A builder for constructing animations.
 pub struct AnimationBuilder<'a> {
@@ -44,6 +59,13 @@ impl<'a> KeyframeBuilder<'a> {
         Self { time, properties: Vec::new() }
     }
 
+    /// Set the timing function used from this keyframe until the next one.
+    pub fn timing_function(mut self, val: impl Into<EasingFunction>) -> Self {
+        self.properties.push(Property::AnimationTimingFunction(val.into()));
+
+        self
+    }
+
     // DISPLAY
 
     /// Set the display value for the keyframe.
@@ -114,12 +136,68 @@ impl<'a> KeyframeBuilder<'a> {
     }
 
     /// Set the border color value for the keyframe.
-    pub fn border_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn border_color(mut self, val: impl Into<BorderColor>) -> Self {
         self.properties.push(Property::BorderColor(val.into()));
 
         self
     }
 
+    keyframe_property!(
+        /// Set the top border width value for the keyframe.
+        border_top_width,
+        LengthOrPercentage,
+        BorderTopWidth
+    );
+
+    keyframe_property!(
+        /// Set the right border width value for the keyframe.
+        border_right_width,
+        LengthOrPercentage,
+        BorderRightWidth
+    );
+
+    keyframe_property!(
+        /// Set the bottom border width value for the keyframe.
+        border_bottom_width,
+        LengthOrPercentage,
+        BorderBottomWidth
+    );
+
+    keyframe_property!(
+        /// Set the left border width value for the keyframe.
+        border_left_width,
+        LengthOrPercentage,
+        BorderLeftWidth
+    );
+
+    keyframe_property!(
+        /// Set the top border color value for the keyframe.
+        border_top_color,
+        Color,
+        BorderTopColor
+    );
+
+    keyframe_property!(
+        /// Set the right border color value for the keyframe.
+        border_right_color,
+        Color,
+        BorderRightColor
+    );
+
+    keyframe_property!(
+        /// Set the bottom border color value for the keyframe.
+        border_bottom_color,
+        Color,
+        BorderBottomColor
+    );
+
+    keyframe_property!(
+        /// Set the left border color value for the keyframe.
+        border_left_color,
+        Color,
+        BorderLeftColor
+    );
+
     // CORNERS
 
     pub fn corner_top_left_radius(mut self, val: impl Into<LengthOrPercentage>) -> Self {
@@ -208,6 +286,30 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn line_height(mut self, val: impl Into<LineHeight>) -> Self {
+        self.properties.push(Property::LineHeight(val.into()));
+
+        self
+    }
+
+    pub fn letter_spacing(mut self, val: impl Into<Spacing>) -> Self {
+        self.properties.push(Property::LetterSpacing(val.into()));
+
+        self
+    }
+
+    pub fn word_spacing(mut self, val: impl Into<Spacing>) -> Self {
+        self.properties.push(Property::WordSpacing(val.into()));
+
+        self
+    }
+
+    pub fn text_shadow(mut self, val: impl Into<Vec<Shadow>>) -> Self {
+        self.properties.push(Property::TextShadow(val.into()));
+
+        self
+    }
+
     pub fn caret_color(mut self, val: impl Into<Color>) -> Self {
         self.properties.push(Property::CaretColor(val.into()));
 
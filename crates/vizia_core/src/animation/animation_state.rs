@@ -5,12 +5,40 @@ use crate::prelude::*;
 
 use super::TimingFunction;
 
+/// Describes how a keyframe animation repeats and whether it applies its values outside of the
+/// time it is running.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AnimationPlayback {
+    /// The number of times the animation repeats, which may be infinite or fractional.
+    pub iterations: f32,
+    /// Whether each iteration plays forwards or backwards.
+    pub direction: AnimationDirection,
+    /// Whether the animation applies its values during its delay and after it ends.
+    pub fill_mode: AnimationFillMode,
+    /// The timing function of keyframes which don't declare their own.
+    pub timing_function: Option<TimingFunction>,
+}
+
+impl Default for AnimationPlayback {
+    /// A single forwards iteration which holds its first keyframe during its delay.
+    fn default() -> Self {
+        Self {
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::Backwards,
+            timing_function: None,
+        }
+    }
+}
+
 /// A keyframe in an animation state.
 #[derive(Debug, Clone)]
 pub(crate) struct Keyframe<T: Interpolator> {
     pub time: f32,
     pub value: T,
-    pub timing_function: TimingFunction,
+    /// The timing function until the next keyframe. Falls back to the timing function of the
+    /// animation, and then to linear, when not set.
+    pub timing_function: Option<TimingFunction>,
 }
 
 /// Represents an animation of a property with type `T`.
@@ -37,6 +65,11 @@ pub(crate) struct AnimationState<T: Interpolator> {
 
     pub active: bool,
 
+    /// How the animation repeats and fills.
+    pub playback: AnimationPlayback,
+    /// The time at which the animation was paused, if it is paused.
+    pub paused_at: Option<Instant>,
+
     /// For transitions. The starting rule for this transition.
     pub from_rule: usize,
     /// For tansitions. The ending rule for this transition.
@@ -63,6 +96,8 @@ where
             t: 0.0,
             dt: 0.0,
             active: false,
+            playback: AnimationPlayback::default(),
+            paused_at: None,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
@@ -119,6 +154,8 @@ where
             t: 0.0,
             dt: 0.0,
             active: false,
+            playback: AnimationPlayback::default(),
+            paused_at: None,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
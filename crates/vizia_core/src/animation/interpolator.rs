@@ -1,8 +1,9 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient, Length,
-    LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
-    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
+    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient,
+    HorizontalPosition, Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue,
+    LineDirection, LineHeight, LinearGradient, Opacity, PercentageOrNumber, Position, Rect, Scale,
+    Shadow, ShapeRadius, Spacing, Transform, Translate, VerticalPosition, RGBA,
 };
 
 use skia_safe::Matrix;
@@ -12,6 +13,15 @@ use crate::style::ImageOrGradient;
 /// A trait which describes how a property is interpolated for animations.
 pub(crate) trait Interpolator {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self;
+
+    /// Returns the value to interpolate from, or to, when `value` has no counterpart in a list of a
+    /// different length. Items without a neutral value are dropped from the interpolated list.
+    fn neutral(_value: &Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 // Implementations of `Interpolator` for various properties.
@@ -93,8 +103,50 @@ impl Interpolator for Filter {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+
+            (Filter::Brightness(start), Filter::Brightness(end)) => {
+                Filter::Brightness(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::Contrast(start), Filter::Contrast(end)) => {
+                Filter::Contrast(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::Grayscale(start), Filter::Grayscale(end)) => {
+                Filter::Grayscale(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::HueRotate(start), Filter::HueRotate(end)) => {
+                Filter::HueRotate(Angle::interpolate(start, end, t))
+            }
+
+            (Filter::Invert(start), Filter::Invert(end)) => {
+                Filter::Invert(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::Opacity(start), Filter::Opacity(end)) => {
+                Filter::Opacity(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::Saturate(start), Filter::Saturate(end)) => {
+                Filter::Saturate(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::Sepia(start), Filter::Sepia(end)) => {
+                Filter::Sepia(PercentageOrNumber::interpolate(start, end, t))
+            }
+
+            (Filter::DropShadow(start), Filter::DropShadow(end)) => {
+                Filter::DropShadow(Shadow::interpolate(start, end, t))
+            }
+
+            _ => end.clone(),
         }
     }
+
+    fn neutral(value: &Self) -> Option<Self> {
+        Some(value.neutral())
+    }
 }
 
 impl Interpolator for LengthValue {
@@ -165,7 +217,11 @@ impl Interpolator for PercentageOrNumber {
                 PercentageOrNumber::Percentage(end_val),
             ) => PercentageOrNumber::Percentage(f32::interpolate(start_val, end_val, t)),
 
-            _ => PercentageOrNumber::default(),
+            _ => PercentageOrNumber::Number(f32::interpolate(
+                &start.to_factor(),
+                &end.to_factor(),
+                t,
+            )),
         }
     }
 }
@@ -217,10 +273,13 @@ impl Interpolator for Matrix {
 
 impl<T: Interpolator> Interpolator for Vec<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        start
-            .iter()
-            .zip(end.iter())
-            .map(|(start, end)| T::interpolate(start, end, t))
+        (0..start.len().max(end.len()))
+            .filter_map(|index| match (start.get(index), end.get(index)) {
+                (Some(start), Some(end)) => Some(T::interpolate(start, end, t)),
+                (Some(start), None) => T::neutral(start).map(|end| T::interpolate(start, &end, t)),
+                (None, Some(end)) => T::neutral(end).map(|start| T::interpolate(&start, end, t)),
+                (None, None) => None,
+            })
             .collect::<Vec<T>>()
     }
 }
@@ -261,6 +320,15 @@ impl Interpolator for Gradient {
                 Gradient::Linear(LinearGradient::interpolate(start_gradient, end_gradient, t))
             }
 
+            (
+                Gradient::RepeatingLinear(start_gradient),
+                Gradient::RepeatingLinear(end_gradient),
+            ) => Gradient::RepeatingLinear(LinearGradient::interpolate(
+                start_gradient,
+                end_gradient,
+                t,
+            )),
+
             _ => end.clone(),
         }
     }
@@ -303,6 +371,7 @@ impl Interpolator for LinearGradient {
                             position: Some(LengthOrPercentage::interpolate(
                                 &start_pos, &end_pos, t,
                             )),
+                            hint: end_stop.hint.clone(),
                         }
                     })
                     .collect::<Vec<_>>(),
@@ -343,6 +412,42 @@ impl Interpolator for FontSize {
     }
 }
 
+impl Interpolator for LineHeight {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (LineHeight::Number(start_val), LineHeight::Number(end_val)) => {
+                LineHeight::Number(f32::interpolate(start_val, end_val, t))
+            }
+
+            (LineHeight::Length(start_val), LineHeight::Length(end_val)) => {
+                LineHeight::Length(LengthOrPercentage::interpolate(start_val, end_val, t))
+            }
+
+            _ => end.clone(),
+        }
+    }
+}
+
+impl Interpolator for Spacing {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (Spacing::Normal, Spacing::Normal) => Spacing::Normal,
+
+            (Spacing::Length(start_val), Spacing::Length(end_val)) => {
+                Spacing::Length(LengthOrPercentage::interpolate(start_val, end_val, t))
+            }
+
+            (Spacing::Normal, Spacing::Length(end_val)) => Spacing::Length(
+                LengthOrPercentage::interpolate(&LengthOrPercentage::px(0.0), end_val, t),
+            ),
+
+            (Spacing::Length(start_val), Spacing::Normal) => Spacing::Length(
+                LengthOrPercentage::interpolate(start_val, &LengthOrPercentage::px(0.0), t),
+            ),
+        }
+    }
+}
+
 impl<T: Interpolator> Interpolator for Rect<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         Rect(
@@ -358,7 +463,62 @@ impl Interpolator for ClipPath {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
             (ClipPath::Shape(s), ClipPath::Shape(e)) => ClipPath::Shape(Rect::interpolate(s, e, t)),
+            (ClipPath::Circle(sr, sc), ClipPath::Circle(er, ec)) => ClipPath::Circle(
+                ShapeRadius::interpolate(sr, er, t),
+                Position::interpolate(sc, ec, t),
+            ),
+            (ClipPath::Ellipse(srx, sry, sc), ClipPath::Ellipse(erx, ery, ec)) => {
+                ClipPath::Ellipse(
+                    ShapeRadius::interpolate(srx, erx, t),
+                    ShapeRadius::interpolate(sry, ery, t),
+                    Position::interpolate(sc, ec, t),
+                )
+            }
+            (ClipPath::Polygon(_, sp), ClipPath::Polygon(fill_rule, ep))
+                if sp.len() == ep.len() =>
+            {
+                ClipPath::Polygon(
+                    *fill_rule,
+                    sp.iter()
+                        .zip(ep.iter())
+                        .map(|((sx, sy), (ex, ey))| {
+                            (
+                                LengthOrPercentage::interpolate(sx, ex, t),
+                                LengthOrPercentage::interpolate(sy, ey, t),
+                            )
+                        })
+                        .collect(),
+                )
+            }
+            _ => end.clone(),
+        }
+    }
+}
+
+impl Interpolator for ShapeRadius {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (ShapeRadius::Length(s), ShapeRadius::Length(e)) => {
+                ShapeRadius::Length(LengthOrPercentage::interpolate(s, e, t))
+            }
             _ => end.clone(),
         }
     }
 }
+
+impl Interpolator for Position {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Position {
+            x: HorizontalPosition::Length(LengthOrPercentage::interpolate(
+                &start.x.to_length_or_percentage(),
+                &end.x.to_length_or_percentage(),
+                t,
+            )),
+            y: VerticalPosition::Length(LengthOrPercentage::interpolate(
+                &start.y.to_length_or_percentage(),
+                &end.y.to_length_or_percentage(),
+                t,
+            )),
+        }
+    }
+}
@@ -2,11 +2,14 @@ mod animation_id;
 pub use animation_id::{AnimId, Animation};
 
 mod animation_state;
-pub(crate) use animation_state::{AnimationState, Keyframe};
+pub(crate) use animation_state::{AnimationPlayback, AnimationState, Keyframe};
 
 mod interpolator;
 pub(crate) use interpolator::Interpolator;
 
+mod spring;
+pub(crate) use spring::Spring;
+
 mod timing_function;
 pub(crate) use timing_function::TimingFunction;
 
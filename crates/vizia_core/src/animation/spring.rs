@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// The distance from the end value, as a fraction of the animated distance, within which a spring
+/// is considered to be at rest.
+const REST_DISPLACEMENT: f32 = 0.001;
+/// The speed, as a fraction of the animated distance per second, below which a spring is
+/// considered to be at rest.
+const REST_VELOCITY: f32 = 0.01;
+/// The longest a spring animation can last, in seconds.
+const MAX_DURATION: f32 = 10.0;
+/// The interval, in seconds, at which the motion is sampled to find when the spring comes to rest.
+const REST_SAMPLE_INTERVAL: f32 = 1.0 / 120.0;
+
+/// A damped spring which moves the progress of an animation from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    /// The velocity at the start of the motion, as a fraction of the animated distance per second.
+    velocity: f32,
+    /// The time in seconds the spring takes to come to rest.
+    duration: f32,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32, mass: f32, velocity: f32) -> Self {
+        let mut spring = Self {
+            stiffness: stiffness.max(f32::EPSILON),
+            damping: damping.max(0.0),
+            mass: mass.max(f32::EPSILON),
+            velocity,
+            duration: MAX_DURATION,
+        };
+
+        let mut time = 0.0;
+        while time < MAX_DURATION {
+            let (displacement, velocity) = spring.motion(time);
+            if displacement.abs() < REST_DISPLACEMENT && velocity.abs() < REST_VELOCITY {
+                break;
+            }
+            time += REST_SAMPLE_INTERVAL;
+        }
+
+        spring.duration = time.clamp(REST_SAMPLE_INTERVAL, MAX_DURATION);
+
+        spring
+    }
+
+    /// Returns the same spring with a different starting velocity.
+    pub fn with_velocity(&self, velocity: f32) -> Self {
+        Self::new(self.stiffness, self.damping, self.mass, velocity)
+    }
+
+    /// The time the spring takes to come to rest.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.duration)
+    }
+
+    /// Returns the progress after the given number of seconds.
+    pub fn progress(&self, time: f32) -> f32 {
+        1.0 + self.motion(time).0
+    }
+
+    /// Returns the velocity after the given number of seconds, as a fraction of the animated
+    /// distance per second.
+    pub fn velocity(&self, time: f32) -> f32 {
+        self.motion(time).1
+    }
+
+    /// Returns the displacement from the end value and the velocity after the given number of
+    /// seconds.
+    fn motion(&self, time: f32) -> (f32, f32) {
+        let natural_frequency = (self.stiffness / self.mass).sqrt();
+        let damping_ratio = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        // The motion starts a whole distance away from the end value.
+        let start = -1.0;
+
+        if damping_ratio < 1.0 {
+            // Under-damped, so the spring oscillates around the end value.
+            let decay = damping_ratio * natural_frequency;
+            let frequency = natural_frequency * (1.0 - damping_ratio * damping_ratio).sqrt();
+            let a = start;
+            let b = (self.velocity + decay * a) / frequency;
+            let (sin, cos) = (frequency * time).sin_cos();
+            let envelope = (-decay * time).exp();
+
+            let displacement = envelope * (a * cos + b * sin);
+            let velocity =
+                envelope * (-decay * (a * cos + b * sin) + frequency * (b * cos - a * sin));
+
+            (displacement, velocity)
+        } else if damping_ratio == 1.0 {
+            // Critically damped, so the spring reaches the end value as fast as possible without
+            // oscillating.
+            let a = start;
+            let b = self.velocity + natural_frequency * a;
+            let envelope = (-natural_frequency * time).exp();
+
+            let displacement = envelope * (a + b * time);
+            let velocity = envelope * (b - natural_frequency * (a + b * time));
+
+            (displacement, velocity)
+        } else {
+            // Over-damped, so the spring slowly approaches the end value.
+            let root = natural_frequency * (damping_ratio * damping_ratio - 1.0).sqrt();
+            let r1 = -damping_ratio * natural_frequency + root;
+            let r2 = -damping_ratio * natural_frequency - root;
+            let c2 = (self.velocity - r1 * start) / (r2 - r1);
+            let c1 = start - c2;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+
+            (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Spring;
+
+    #[test]
+    fn comes_to_rest() {
+        for (stiffness, damping) in [(170.0, 26.0), (100.0, 20.0), (100.0, 40.0), (300.0, 5.0)] {
+            let spring = Spring::new(stiffness, damping, 1.0, 0.0);
+            let duration = spring.duration().as_secs_f32();
+            assert!(duration > 0.0 && duration < 10.0);
+            assert!((spring.progress(duration) - 1.0).abs() < 0.001);
+            assert_eq!(spring.progress(0.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn starting_velocity() {
+        let spring = Spring::new(170.0, 26.0, 1.0, 0.0);
+        assert_eq!(spring.velocity(0.0), 0.0);
+
+        let spring = spring.with_velocity(2.0);
+        assert!((spring.velocity(0.0) - 2.0).abs() < 0.0001);
+        assert!(spring.progress(0.01) > Spring::new(170.0, 26.0, 1.0, 0.0).progress(0.01));
+    }
+}
@@ -1,9 +1,18 @@
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+use vizia_style::{EasingFunction, StepPosition};
+
+use super::Spring;
+
+/// Describes how an animation progresses between two keyframes.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TimingFunction {
+    /// A cubic bezier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2).
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    /// Jumps between a number of equally spaced steps.
+    Steps(u32, StepPosition),
+    /// Straight lines between a list of (input, output) points.
+    Linear(Vec<(f32, f32)>),
+    /// A damped spring, whose time to come to rest is the duration of the animation.
+    Spring(Spring),
 }
 
 impl Default for TimingFunction {
@@ -30,18 +39,96 @@ impl TimingFunction {
     }
 }
 
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+            EasingFunction::Steps(count, position) => TimingFunction::Steps(count, position),
+            EasingFunction::LinearPoints(points) => TimingFunction::Linear(points),
+            EasingFunction::Spring { stiffness, damping, mass } => {
+                TimingFunction::Spring(Spring::new(stiffness, damping, mass, 0.0))
+            }
+        }
+    }
+}
+
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
     }
 
-    pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
-            return x;
+    /// Returns a spring with the given stiffness, damping and mass which starts at rest.
+    pub fn spring(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self::Spring(Spring::new(stiffness, damping, mass, 0.0))
+    }
+
+    /// Returns the spring if this is a spring timing function.
+    pub fn as_spring(&self) -> Option<&Spring> {
+        match self {
+            TimingFunction::Spring(spring) => Some(spring),
+            _ => None,
         }
+    }
+
+    pub fn value(&self, x: f32) -> f32 {
+        match self {
+            TimingFunction::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, *x1, *x2), *y1, *y2)
+            }
+
+            TimingFunction::Steps(count, position) => {
+                let jumps = position.jumps(*count).max(1) as f32;
+                let mut step = (x * *count as f32).floor();
+                if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+                    step += 1.0;
+                }
+
+                step.clamp(0.0, jumps) / jumps
+            }
+
+            TimingFunction::Linear(points) => {
+                let (Some(first), Some(last)) = (points.first(), points.last()) else {
+                    return x;
+                };
+
+                if x <= first.0 {
+                    return first.1;
+                }
+
+                if x >= last.0 {
+                    return last.1;
+                }
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+                // Use the last segment which starts at or before x, so that points sharing an
+                // input jump straight to the later output.
+                let index = points.iter().rposition(|point| point.0 <= x).unwrap_or_default();
+                let (start, end) = (points[index], points[(index + 1).min(points.len() - 1)]);
+
+                if end.0 == start.0 {
+                    end.1
+                } else {
+                    start.1 + (end.1 - start.1) * (x - start.0) / (end.0 - start.0)
+                }
+            }
+
+            TimingFunction::Spring(spring) => {
+                if x >= 1.0 {
+                    return 1.0;
+                }
+
+                spring.progress(x * spring.duration().as_secs_f32())
+            }
+        }
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,16 +147,16 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
         let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
+            let pos = Self::calc_bezier(guess, x1, x2);
             error = pos - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
             guess -= error / slope;
         }
         if error.abs() <= 0.0000001 {
@@ -83,6 +170,7 @@ impl TimingFunction {
 #[cfg(test)]
 mod tests {
     use super::TimingFunction;
+    use vizia_style::StepPosition;
 
     #[test]
     fn linear() {
@@ -95,4 +183,43 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn steps() {
+        let timing_func = TimingFunction::Steps(4, StepPosition::JumpEnd);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.3), 0.25);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::Steps(4, StepPosition::JumpStart);
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(0.8), 1.0);
+
+        let timing_func = TimingFunction::Steps(3, StepPosition::JumpNone);
+        assert_eq!(timing_func.value(0.5), 0.5);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::Steps(3, StepPosition::JumpBoth);
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn linear_points() {
+        let timing_func =
+            TimingFunction::Linear(vec![(0.0, 0.0), (0.5, 1.0), (0.5, 0.5), (1.0, 1.0)]);
+        assert_eq!(timing_func.value(0.25), 0.5);
+        assert_eq!(timing_func.value(0.5), 0.5);
+        assert_eq!(timing_func.value(0.75), 0.75);
+        assert_eq!(timing_func.value(1.5), 1.0);
+    }
+
+    #[test]
+    fn spring() {
+        let timing_func = TimingFunction::spring(300.0, 10.0, 1.0);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(1.0), 1.0);
+        // An under-damped spring overshoots the end value.
+        assert!((0..100).any(|i| timing_func.value(i as f32 / 100.0) > 1.0));
+    }
 }
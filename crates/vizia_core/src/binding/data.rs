@@ -116,6 +116,14 @@ impl<T: ?Sized + 'static> Data for Rc<T> {
     }
 }
 
+// Like an `Arc`, a rope is compared by identity, as comparing the contents of a large rope is slow.
+// Clones of a rope are the same instance until either of them is edited.
+impl Data for ropey::Rope {
+    fn same(&self, other: &Self) -> bool {
+        self.is_instance(other)
+    }
+}
+
 impl<T: ?Sized + 'static> Data for std::rc::Weak<T> {
     fn same(&self, other: &Self) -> bool {
         std::rc::Weak::ptr_eq(self, other)
@@ -123,10 +123,17 @@ impl_res_simple!(Angle);
 impl_res_simple!(TextAlign);
 impl_res_simple!(TextOverflow);
 impl_res_simple!(LineClamp);
+impl_res_simple!(Direction);
+impl_res_clone!(LanguageIdentifier);
 impl_res_clone!(Shadow);
 impl_res_clone!(LinearGradientBuilder);
+impl_res_clone!(Gradient);
 impl_res_clone!(ShadowBuilder);
 impl_res_simple!(FontVariation);
+impl_res_simple!(FontFeature);
+impl_res_clone!(LineHeight);
+impl_res_clone!(Spacing);
+impl_res_simple!(TextTransform);
 impl_res_clone!(Filter);
 impl_res_simple!(Opacity);
 impl_res_simple!(FontWidth);
@@ -18,6 +18,8 @@ pub struct CachedData {
     pub(crate) transform: SparseSet<Matrix>,
     pub(crate) clip_path: SparseSet<BoundingBox>,
     pub(crate) path: SparseSet<Path>,
+    // The bounds of each line of a text span, used for hit-testing spans which wrap across lines.
+    pub(crate) text_span_bounds: SparseSet<Vec<BoundingBox>>,
 }
 
 impl CachedData {
@@ -36,6 +38,7 @@ impl CachedData {
         self.transform.remove(entity);
         self.clip_path.remove(entity);
         self.path.remove(entity);
+        self.text_span_bounds.remove(entity);
     }
 
     /// Returns the bounding box of the entity, determined by the layout system.
@@ -24,10 +24,10 @@ impl BackendContext {
     }
 
     /// Helper function for mutating the state of a window.
-    pub fn mutate_window<W: Any, F: Fn(&mut BackendContext, &mut W)>(
+    pub fn mutate_window<W: Any, F: FnMut(&mut BackendContext, &mut W)>(
         &mut self,
         window_entity: Entity,
-        f: F,
+        mut f: F,
     ) {
         if let Some(mut window_event_handler) = self.0.views.remove(&window_entity) {
             if let Some(window) = window_event_handler.downcast_mut::<W>() {
@@ -119,7 +119,11 @@ impl BackendContext {
         surface: &mut Surface,
         dirty_surface: &mut Surface,
     ) -> bool {
-        draw_system(&mut self.0, window_entity, surface, dirty_surface)
+        let start = Instant::now();
+        let drawn = draw_system(&mut self.0, window_entity, surface, dirty_surface);
+        self.0.profiler.current.draw += start.elapsed();
+
+        drawn
     }
 
     /// Set the current entity. This is useful in user code when you're performing black magic and
@@ -202,11 +206,24 @@ impl BackendContext {
     }
 
     /// Calls the style system to match entities with shared styles.
+    ///
+    /// This marks the start of a new frame for the [Profiler](crate::profiler::Profiler).
     pub fn process_style_updates(&mut self) {
+        if self.0.profiler.begin_frame() {
+            // Redraw the windows so the overlay doesn't keep showing the statistics of an older frame.
+            for (window_entity, window_state) in self.0.windows.iter_mut() {
+                window_state.redraw_list.insert(*window_entity);
+            }
+        }
+
+        let start = Instant::now();
+
         style_system(&mut self.0);
 
         // Load any unloaded images and remove unused images.
         image_system(&mut self.0);
+
+        self.0.profiler.current.style += start.elapsed();
     }
 
     // Returns true if animations are playing
@@ -216,8 +233,12 @@ impl BackendContext {
 
     /// Massages the style system until everything is coherent
     pub fn process_visual_updates(&mut self) {
+        let start = Instant::now();
+
         // Perform layout.
         layout_system(&mut self.0);
+
+        self.0.profiler.current.layout += start.elapsed();
     }
 
     pub fn emit_origin<M: Send + Any>(&mut self, message: M) {
@@ -2,14 +2,15 @@ use skia_safe::canvas::SaveLayerRec;
 use skia_safe::gradient_shader::GradientShaderColors;
 use skia_safe::path::ArcSize;
 use skia_safe::rrect::Corner;
+use skia_safe::textlayout::Paragraph;
 use skia_safe::wrapper::PointerWrapper;
 use skia_safe::{
     BlurStyle, ClipOp, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection, PathEffect,
-    Point, RRect, Rect, SamplingOptions, Shader, TileMode,
+    PathFillType, Point, RRect, Rect, SamplingOptions, Shader, TileMode,
 };
 use std::any::{Any, TypeId};
 use std::f32::consts::SQRT_2;
-use vizia_style::LengthPercentageOrAuto;
+use vizia_style::{ColorStop, LengthPercentageOrAuto};
 
 use hashbrown::HashMap;
 
@@ -18,7 +19,8 @@ use crate::cache::CachedData;
 use crate::events::ViewHandler;
 use crate::prelude::*;
 use crate::resource::{ImageOrSvg, ResourceManager};
-use crate::text::TextContext;
+use crate::systems::paragraph_origin;
+use crate::text::{GeneratedContent, TextContext};
 use vizia_input::MouseState;
 
 use super::ModelData;
@@ -121,6 +123,18 @@ macro_rules! get_length_property {
     };
 }
 
+macro_rules! get_style_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $t:ty
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> $t {
+            self.style.$name.get(self.current).copied().unwrap_or_default()
+        }
+    };
+}
+
 impl DrawContext<'_> {
     /// Returns the bounds of the current view.
     pub fn bounds(&self) -> BoundingBox {
@@ -175,6 +189,7 @@ impl DrawContext<'_> {
                     rect.1.to_pixels(bounds.w, scale),
                     rect.2.to_pixels(bounds.h, scale),
                 ),
+                _ => bounds,
             })
             .unwrap_or(bounds);
 
@@ -205,6 +220,19 @@ impl DrawContext<'_> {
         Some(clip_path)
     }
 
+    /// Returns the clip shape of the current view, which applies regardless of overflow.
+    pub fn clip_shape(&self) -> Option<skia_safe::Path> {
+        self.style
+            .clip_path
+            .get(self.current)
+            .and_then(|clip| clip.as_clip_shape(self.bounds(), self.scale_factor()))
+    }
+
+    /// Returns the mask images of the current view.
+    pub fn mask_images(&self) -> Option<&Vec<ImageOrGradient>> {
+        self.style.mask_image.get(self.current)
+    }
+
     /// Returns the 2D transform of the current view.
     pub fn transform(&self) -> Matrix {
         let bounds = self.bounds();
@@ -324,9 +352,31 @@ impl DrawContext<'_> {
         self.style.physical_to_logical(physical)
     }
 
+    /// Returns the border width of the current view in physical pixels.
+    ///
+    /// When the sides have different widths this is the width of the top border.
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
+    get_length_property!(
+        /// Returns the top border width of the current view in physical pixels.
+        border_top_width
+    );
+
+    get_length_property!(
+        /// Returns the right border width of the current view in physical pixels.
+        border_right_width
+    );
+
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the bottom border width of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the left border width of the current view in physical pixels.
+        border_left_width
     );
 
     get_color_property!(
@@ -434,21 +484,70 @@ impl DrawContext<'_> {
         background_color
     );
 
+    /// Returns the border color of the current view.
+    ///
+    /// When the sides have different colors this is the color of the top border.
+    pub fn border_color(&self) -> Color {
+        self.border_top_color()
+    }
+
+    get_color_property!(
+        /// Returns the top border color of the current view.
+        border_top_color
+    );
+
+    get_color_property!(
+        /// Returns the right border color of the current view.
+        border_right_color
+    );
+
     get_color_property!(
-        /// Returns the border color of the current view.
-        border_color
+        /// Returns the bottom border color of the current view.
+        border_bottom_color
+    );
+
+    get_color_property!(
+        /// Returns the left border color of the current view.
+        border_left_color
     );
 
     /// Returns the border style of the current view.
+    ///
+    /// When the sides have different styles this is the style of the top border.
     pub fn border_style(&self) -> BorderStyleKeyword {
-        self.style.border_style.get(self.current).copied().unwrap_or_default()
+        self.border_top_style()
     }
 
+    get_style_property!(
+        /// Returns the top border style of the current view.
+        border_top_style: BorderStyleKeyword
+    );
+
+    get_style_property!(
+        /// Returns the right border style of the current view.
+        border_right_style: BorderStyleKeyword
+    );
+
+    get_style_property!(
+        /// Returns the bottom border style of the current view.
+        border_bottom_style: BorderStyleKeyword
+    );
+
+    get_style_property!(
+        /// Returns the left border style of the current view.
+        border_left_style: BorderStyleKeyword
+    );
+
     get_color_property!(
         /// Returns the text selection color for the current view.
         selection_color
     );
 
+    /// Returns the color of selected text for the current view, if it is set by a `::selection` rule.
+    pub fn selection_text_color(&self) -> Option<Color> {
+        self.style.selection_text_color.get(self.current).copied()
+    }
+
     get_color_property!(
         /// Returns the text caret color for the current view.
         caret_color
@@ -459,6 +558,16 @@ impl DrawContext<'_> {
         font_color
     );
 
+    /// Returns the gradient which fills the text of the current view, in place of the font color.
+    pub fn font_gradient(&self) -> Option<&Gradient> {
+        self.style.font_gradient.get(self.current).filter(|gradient| **gradient != Gradient::None)
+    }
+
+    /// Returns the gradient which fills the border of the current view, in place of the border colors.
+    pub fn border_gradient(&self) -> Option<&Gradient> {
+        self.style.border_gradient.get(self.current).filter(|gradient| **gradient != Gradient::None)
+    }
+
     /// Returns whether the current view should have its text wrapped.
     pub fn text_wrap(&self) -> bool {
         self.style.text_wrap.get(self.current).copied().unwrap_or(true)
@@ -484,8 +593,13 @@ impl DrawContext<'_> {
         self.style.shadow.get(self.current)
     }
 
-    /// Return to reference to any filter applied to the current view.
-    pub fn backdrop_filter(&self) -> Option<&Filter> {
+    /// Returns a reference to any filter functions applied to the current view.
+    pub fn filter(&self) -> Option<&Vec<Filter>> {
+        self.style.filter.get(self.current)
+    }
+
+    /// Returns a reference to any filter functions applied to the backdrop of the current view.
+    pub fn backdrop_filter(&self) -> Option<&Vec<Filter>> {
         self.style.backdrop_filter.get(self.current)
     }
 
@@ -500,12 +614,9 @@ impl DrawContext<'_> {
     }
 
     pub fn path(&mut self) -> Path {
-        let border_width = self.border_width();
         if self.cache.path.get(self.current).is_none() {
-            self.cache.path.insert(
-                self.current,
-                self.build_path(self.bounds(), (-border_width / 2.0, -border_width / 2.0)),
-            );
+            let insets = self.border_widths().map(|width| width / 2.0);
+            self.cache.path.insert(self.current, self.build_inset_path(self.bounds(), insets));
         }
         let bounds = self.bounds();
         let mut path = self.cache.path.get(self.current).unwrap().clone();
@@ -517,6 +628,12 @@ impl DrawContext<'_> {
 
     /// Get the vector path of the current view.
     pub fn build_path(&self, bounds: BoundingBox, outset: (f32, f32)) -> Path {
+        self.build_inset_path(bounds, [-outset.1, -outset.0, -outset.1, -outset.0])
+    }
+
+    /// Get the vector path of the current view, inset by the given top, right, bottom and left
+    /// amounts. Corner radii shrink by the adjacent insets.
+    pub fn build_inset_path(&self, bounds: BoundingBox, insets: [f32; 4]) -> Path {
         let corner_top_left_radius = self.corner_top_left_radius();
         let corner_top_right_radius = self.corner_top_right_radius();
         let corner_bottom_right_radius = self.corner_bottom_right_radius();
@@ -534,20 +651,29 @@ impl DrawContext<'_> {
 
         let bounds = BoundingBox::from_min_max(0.0, 0.0, bounds.w, bounds.h);
 
-        let rect: Rect = bounds.into();
+        let [inset_top, inset_right, inset_bottom, inset_left] = insets;
 
-        let mut rr = RRect::new_rect_radii(
+        let rect =
+            Rect::new(inset_left, inset_top, bounds.w - inset_right, bounds.h - inset_bottom);
+
+        let inset_radius = |radius: f32, dx: f32, dy: f32| {
+            if radius > 0.0 {
+                Point::new((radius - dx).max(0.0), (radius - dy).max(0.0))
+            } else {
+                Point::default()
+            }
+        };
+
+        let rr = RRect::new_rect_radii(
             rect,
             &[
-                Point::new(corner_top_left_radius, corner_top_left_radius),
-                Point::new(corner_top_right_radius, corner_top_right_radius),
-                Point::new(corner_bottom_right_radius, corner_bottom_right_radius),
-                Point::new(corner_bottom_left_radius, corner_bottom_left_radius),
+                inset_radius(corner_top_left_radius, inset_left, inset_top),
+                inset_radius(corner_top_right_radius, inset_right, inset_top),
+                inset_radius(corner_bottom_right_radius, inset_right, inset_bottom),
+                inset_radius(corner_bottom_left_radius, inset_left, inset_bottom),
             ],
         );
 
-        rr = rr.with_outset(outset);
-
         let x = rr.bounds().x();
         let y = rr.bounds().y();
         let width = rr.width();
@@ -562,7 +688,7 @@ impl DrawContext<'_> {
             && corner_top_left_radius == height / 2.0
             && corner_top_right_radius == height / 2.0
         {
-            path.add_circle((width / 2.0, bounds.h / 2.0), width / 2.0, PathDirection::CW);
+            path.add_circle((x + width / 2.0, y + height / 2.0), width / 2.0, PathDirection::CW);
         } else if corner_top_left_radius == corner_top_right_radius
             && corner_top_right_radius == corner_bottom_right_radius
             && corner_bottom_right_radius == corner_bottom_left_radius
@@ -732,36 +858,132 @@ impl DrawContext<'_> {
         self.draw_background_images(canvas);
     }
 
+    /// Returns the top, right, bottom and left border widths of the current view in physical pixels.
+    fn border_widths(&self) -> [f32; 4] {
+        [
+            self.border_top_width(),
+            self.border_right_width(),
+            self.border_bottom_width(),
+            self.border_left_width(),
+        ]
+    }
+
     /// Draw the border of the current view.
     pub fn draw_border(&mut self, canvas: &Canvas) {
-        let border_color = self.border_color();
-        let border_width = self.border_width();
-        let border_style = self.border_style();
+        let widths = self.border_widths();
+        let colors = [
+            self.border_top_color(),
+            self.border_right_color(),
+            self.border_bottom_color(),
+            self.border_left_color(),
+        ];
+        let styles = [
+            self.border_top_style(),
+            self.border_right_style(),
+            self.border_bottom_style(),
+            self.border_left_style(),
+        ];
+
+        let shader = self
+            .border_gradient()
+            .and_then(|gradient| self.gradient_shader(gradient, self.bounds()));
+
+        let has_gradient = shader.is_some();
+
+        let visible = |side: usize| {
+            widths[side] > 0.0
+                && (has_gradient || colors[side].a() > 0)
+                && styles[side] != BorderStyleKeyword::None
+        };
+
+        if !(0..4).any(visible) {
+            return;
+        }
 
-        if border_width > 0.0 && border_color.a() > 0 && border_style != BorderStyleKeyword::None {
+        let uniform = (1..4).all(|side| {
+            widths[side] == widths[0]
+                && (has_gradient || colors[side] == colors[0])
+                && styles[side] == styles[0]
+        });
+
+        if uniform {
             let path = self.path();
-            let mut paint = Paint::default();
-            paint.set_style(PaintStyle::Stroke);
-            paint.set_color(border_color);
-            paint.set_stroke_width(border_width);
-            match border_style {
-                BorderStyleKeyword::Dashed => {
-                    paint.set_path_effect(PathEffect::dash(
-                        &[border_width * 2.0, border_width],
-                        0.0,
-                    ));
+            let mut paint = border_paint(widths[0], colors[0], styles[0]);
+            if let Some(shader) = shader {
+                paint.set_alpha_f(1.0);
+                paint.set_shader(shader);
+            }
+            canvas.draw_path(&path, &paint);
+        } else {
+            self.draw_mixed_border(canvas, widths, colors, styles, shader, visible);
+        }
+    }
+
+    /// Draw a border whose sides differ in width, color or style. Each side is clipped to the
+    /// trapezoid between the outer and inner edges, so that adjacent sides meet along the diagonal
+    /// of each corner.
+    fn draw_mixed_border(
+        &self,
+        canvas: &Canvas,
+        widths: [f32; 4],
+        colors: [Color; 4],
+        styles: [BorderStyleKeyword; 4],
+        shader: Option<Shader>,
+        visible: impl Fn(usize) -> bool,
+    ) {
+        let bounds = self.bounds();
+        let [top, right, bottom, left] = widths;
+
+        let mut ring = self.build_path(bounds, (0.0, 0.0));
+        if left + right < bounds.w && top + bottom < bounds.h {
+            ring.add_path(&self.build_inset_path(bounds, widths), (0.0, 0.0), None);
+        }
+        ring.set_fill_type(PathFillType::EvenOdd);
+        ring.offset(bounds.top_left());
+
+        let mut center_path = self.build_inset_path(bounds, widths.map(|width| width / 2.0));
+        center_path.offset(bounds.top_left());
+
+        let (x, y, w, h) = (bounds.x, bounds.y, bounds.w, bounds.h);
+        let outer = [(x, y), (x + w, y), (x + w, y + h), (x, y + h)];
+        let inner = [
+            (x + left, y + top),
+            (x + w - right, y + top),
+            (x + w - right, y + h - bottom),
+            (x + left, y + h - bottom),
+        ];
+
+        for side in (0..4).filter(|side| visible(*side)) {
+            let next = (side + 1) % 4;
+            let mut clip = Path::new();
+            clip.move_to(outer[side])
+                .line_to(outer[next])
+                .line_to(inner[next])
+                .line_to(inner[side])
+                .close();
+
+            canvas.save();
+            canvas.clip_path(&clip, ClipOp::Intersect, true);
+            let (mut paint, path) = match styles[side] {
+                BorderStyleKeyword::Dashed | BorderStyleKeyword::Dotted => {
+                    (border_paint(widths[side], colors[side], styles[side]), &center_path)
                 }
 
-                BorderStyleKeyword::Dotted => {
-                    paint.set_path_effect(PathEffect::dash(&[0.0, border_width * 2.0], 0.0));
-                    paint.set_stroke_cap(skia_safe::PaintCap::Round);
+                _ => {
+                    let mut paint = Paint::default();
+                    paint.set_color(colors[side]);
+                    paint.set_anti_alias(true);
+                    (paint, &ring)
                 }
+            };
 
-                _ => {}
+            if let Some(shader) = &shader {
+                paint.set_alpha_f(1.0);
+                paint.set_shader(shader.clone());
             }
 
-            paint.set_anti_alias(true);
-            canvas.draw_path(&path, &paint);
+            canvas.draw_path(path, &paint);
+            canvas.restore();
         }
     }
 
@@ -865,325 +1087,237 @@ impl DrawContext<'_> {
 
     /// Draw background images (including gradients) for the current view.
     fn draw_background_images(&mut self, canvas: &Canvas) {
-        let bounds = self.bounds();
-
         if self.background_images().is_some() {
             let path = self.path();
             if let Some(images) = self.background_images() {
-                let image_sizes = self.background_size();
-
-                for (index, image) in images.iter().enumerate() {
-                    match image {
-                        ImageOrGradient::Gradient(gradient) => match gradient {
-                            Gradient::Linear(linear_gradient) => {
-                                let (start, end, parent_length) = match linear_gradient.direction {
-                                    LineDirection::Horizontal(horizontal_keyword) => {
-                                        match horizontal_keyword {
-                                            HorizontalPositionKeyword::Left => (
-                                                bounds.center_right(),
-                                                bounds.center_left(),
-                                                bounds.width(),
-                                            ),
-
-                                            HorizontalPositionKeyword::Right => (
-                                                bounds.center_left(),
-                                                bounds.center_right(),
-                                                bounds.width(),
-                                            ),
-                                        }
-                                    }
+                self.draw_images(canvas, images, &self.background_size(), &path);
+            }
+        }
+    }
 
-                                    LineDirection::Vertical(vertical_keyword) => {
-                                        match vertical_keyword {
-                                            VerticalPositionKeyword::Top => (
-                                                bounds.center_bottom(),
-                                                bounds.center_top(),
-                                                bounds.height(),
-                                            ),
-
-                                            VerticalPositionKeyword::Bottom => (
-                                                bounds.center_top(),
-                                                bounds.center_bottom(),
-                                                bounds.height(),
-                                            ),
-                                        }
-                                    }
+    /// Creates a shader which paints the given gradient within the given bounds.
+    pub fn gradient_shader(&self, gradient: &Gradient, bounds: BoundingBox) -> Option<Shader> {
+        let scale_factor = self.scale_factor();
 
-                                    LineDirection::Corner { horizontal, vertical } => {
-                                        match (horizontal, vertical) {
-                                            (
-                                                HorizontalPositionKeyword::Right,
-                                                VerticalPositionKeyword::Bottom,
-                                            ) => (
-                                                bounds.top_left(),
-                                                bounds.bottom_right(),
-                                                bounds.diagonal(),
-                                            ),
-
-                                            (
-                                                HorizontalPositionKeyword::Right,
-                                                VerticalPositionKeyword::Top,
-                                            ) => (
-                                                bounds.bottom_left(),
-                                                bounds.top_right(),
-                                                bounds.diagonal(),
-                                            ),
-
-                                            _ => (bounds.top_left(), bounds.bottom_right(), 0.0),
-                                        }
-                                    }
+        match gradient {
+            Gradient::None => None,
+
+            Gradient::Linear(linear_gradient) | Gradient::RepeatingLinear(linear_gradient) => {
+                let repeating = matches!(gradient, Gradient::RepeatingLinear(_));
+                let (start, end, parent_length) =
+                    linear_gradient_line(linear_gradient.direction, bounds);
+
+                let stops = resolve_stops(&linear_gradient.stops, |pos| {
+                    pos.to_pixels(parent_length, scale_factor) / parent_length
+                });
+                let ((first, last), offsets, colors) = gradient_stops(stops, repeating)?;
+
+                let (start, end) = (Point::from(start), Point::from(end));
+                let direction = end - start;
+
+                Shader::linear_gradient(
+                    (start + direction * first, start + direction * last),
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode(repeating),
+                    None,
+                    None,
+                )
+            }
 
-                                    LineDirection::Angle(angle) => {
-                                        let angle_rad = angle.to_radians();
-                                        let start_x = bounds.x
-                                            + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
-                                        let end_x = bounds.x
-                                            + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
-                                        let start_y = bounds.y
-                                            + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
-                                        let end_y = bounds.y
-                                            + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
+            Gradient::Radial(radial_gradient) | Gradient::RepeatingRadial(radial_gradient) => {
+                let repeating = matches!(gradient, Gradient::RepeatingRadial(_));
+                let center = gradient_center(&radial_gradient.position, bounds, scale_factor);
+                let radius = bounds.w.max(bounds.h);
+
+                let stops = resolve_stops(&radial_gradient.stops, |pos| {
+                    pos.to_pixels(radius, scale_factor) / radius
+                });
+                let ((first, last), offsets, colors) = gradient_stops(stops, repeating)?;
+
+                Shader::two_point_conical_gradient(
+                    center,
+                    radius * first,
+                    center,
+                    radius * last,
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode(repeating),
+                    None,
+                    None,
+                )
+            }
 
-                                        let x = (end_x - start_x).abs();
-                                        let y = (end_y - start_y).abs();
+            Gradient::Conic(conic_gradient) | Gradient::RepeatingConic(conic_gradient) => {
+                let repeating = matches!(gradient, Gradient::RepeatingConic(_));
+                let center = gradient_center(&conic_gradient.position, bounds, scale_factor);
+
+                let stops = resolve_stops(&conic_gradient.stops, |pos| pos.to_turns());
+                let ((first, last), offsets, colors) = gradient_stops(stops, repeating)?;
+
+                // Skia sweeps clockwise from the positive x-axis, whereas CSS starts from the top.
+                let matrix =
+                    Matrix::rotate_deg_pivot(conic_gradient.angle.to_degrees() - 90.0, center);
+
+                Shader::sweep_gradient(
+                    center,
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode(repeating),
+                    (first * 360.0, last * 360.0),
+                    None,
+                    &matrix,
+                )
+            }
+        }
+    }
 
-                                        let dist = (x * x + y * y).sqrt();
+    /// Draws the mask images of the current view, filling its bounds.
+    pub fn draw_mask_images(&mut self, canvas: &Canvas) {
+        if let Some(images) = self.mask_images() {
+            let rect: Rect = self.bounds().into();
+            self.draw_images(canvas, images, &[], &Path::rect(rect, None));
+        }
+    }
 
-                                        ((start_x, start_y), (end_x, end_y), dist)
-                                    }
-                                };
-
-                                let num_stops = linear_gradient.stops.len();
-
-                                let mut stops = linear_gradient
-                                    .stops
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(index, stop)| {
-                                        let pos = if let Some(pos) = &stop.position {
-                                            pos.to_pixels(parent_length, self.scale_factor())
-                                                / parent_length
-                                        } else {
-                                            index as f32 / (num_stops - 1) as f32
-                                        };
-                                        (pos, skia_safe::Color::from(stop.color))
-                                    })
-                                    .collect::<Vec<_>>();
-
-                                // Insert a stop at the front if the first stop is not at 0.
-                                if let Some(first) = stops.first() {
-                                    if first.0 != 0.0 {
-                                        stops.insert(0, (0.0, first.1));
-                                    }
-                                }
+    /// Draws a list of images and gradients, filling the given path, within the bounds of the current view.
+    fn draw_images(
+        &self,
+        canvas: &Canvas,
+        images: &[ImageOrGradient],
+        image_sizes: &[BackgroundSize],
+        path: &Path,
+    ) {
+        let bounds = self.bounds();
 
-                                // Insert a stop at the end if the last stop is not at 1.0.
-                                if let Some(last) = stops.last() {
-                                    if last.0 != 1.0 {
-                                        stops.push((1.0, last.1));
-                                    }
-                                }
+        for (index, image) in images.iter().enumerate() {
+            match image {
+                ImageOrGradient::Gradient(gradient) => {
+                    if let Some(shader) = self.gradient_shader(gradient, bounds) {
+                        let mut paint = Paint::default();
+                        paint.set_shader(shader);
+                        canvas.draw_path(path, &paint);
+                    }
+                }
 
-                                let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                    stops.into_iter().unzip();
+                ImageOrGradient::Image(image_name) => {
+                    if let Some(image_id) = self.resource_manager.image_ids.get(image_name) {
+                        if let Some(image) = self.resource_manager.images.get(image_id) {
+                            match &image.image {
+                                ImageOrSvg::Image(image) => {
+                                    let image_width = image.width();
+                                    let image_height = image.height();
+                                    let (width, height) =
+                                        if let Some(background_size) = image_sizes.get(index) {
+                                            match background_size {
+                                                BackgroundSize::Explicit { width, height } => {
+                                                    let w = match width {
+                                        LengthPercentageOrAuto::LengthPercentage(
+                                            length,
+                                        ) => {
+                                            length.to_pixels(bounds.w, self.scale_factor())
+                                        }
+                                        LengthPercentageOrAuto::Auto => image_width as f32,
+                                    };
+
+                                                    let h = match height {
+                                        LengthPercentageOrAuto::LengthPercentage(
+                                            length,
+                                        ) => {
+                                            length.to_pixels(bounds.h, self.scale_factor())
+                                        }
+                                        LengthPercentageOrAuto::Auto => image_height as f32,
+                                    };
 
-                                let shader = Shader::linear_gradient(
-                                    (Point::from(start), Point::from(end)),
-                                    GradientShaderColors::Colors(&colors[..]),
-                                    Some(&offsets[..]),
-                                    TileMode::Clamp,
-                                    None,
-                                    None,
-                                );
+                                                    (w, h)
+                                                }
 
-                                let mut paint = Paint::default();
-                                paint.set_shader(shader);
+                                                BackgroundSize::Contain => {
+                                                    let image_ratio =
+                                                        image_width as f32 / image_height as f32;
+                                                    let container_ratio = bounds.w / bounds.h;
 
-                                canvas.draw_path(&path, &paint);
-                            }
+                                                    let (w, h) = if image_ratio > container_ratio {
+                                                        (bounds.w, bounds.w / image_ratio)
+                                                    } else {
+                                                        (bounds.h * image_ratio, bounds.h)
+                                                    };
 
-                            Gradient::Radial(radial_gradient) => {
-                                let num_stops = radial_gradient.stops.len();
-
-                                let mut stops = radial_gradient
-                                    .stops
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(index, stop)| {
-                                        let pos = if let Some(pos) = &stop.position {
-                                            pos.to_pixels(bounds.width(), self.scale_factor())
-                                                / bounds.width()
+                                                    (w, h)
+                                                }
+
+                                                BackgroundSize::Cover => {
+                                                    let image_ratio =
+                                                        image_width as f32 / image_height as f32;
+                                                    let container_ratio = bounds.w / bounds.h;
+
+                                                    let (w, h) = if image_ratio < container_ratio {
+                                                        (bounds.w, bounds.w / image_ratio)
+                                                    } else {
+                                                        (bounds.h * image_ratio, bounds.h)
+                                                    };
+
+                                                    (w, h)
+                                                }
+                                            }
                                         } else {
-                                            index as f32 / (num_stops - 1) as f32
+                                            (image_width as f32, image_height as f32)
                                         };
 
-                                        (pos, skia_safe::Color::from(stop.color))
-                                    })
-                                    .collect::<Vec<_>>();
-
-                                // Insert a stop at the front if the first stop is not at 0.
-                                if let Some(first) = stops.first() {
-                                    if first.0 != 0.0 {
-                                        stops.insert(0, (0.0, first.1));
-                                    }
+                                    let matrix = Matrix::rect_to_rect(
+                                        Rect::new(
+                                            0.0,
+                                            0.0,
+                                            image.width() as f32,
+                                            image.height() as f32,
+                                        ),
+                                        Rect::new(
+                                            bounds.left(),
+                                            bounds.top(),
+                                            bounds.left() + width,
+                                            bounds.top() + height,
+                                        ),
+                                        None,
+                                    );
+
+                                    let mut paint = Paint::default();
+                                    paint.set_anti_alias(true);
+                                    paint.set_shader(image.to_shader(
+                                        (TileMode::Repeat, TileMode::Repeat),
+                                        SamplingOptions::default(),
+                                        &matrix,
+                                    ));
+
+                                    canvas.draw_path(path, &paint);
                                 }
 
-                                // Insert a stop at the end if the last stop is not at 1.0.
-                                if let Some(last) = stops.last() {
-                                    if last.0 != 1.0 {
-                                        stops.push((1.0, last.1));
+                                ImageOrSvg::Svg(svg) => {
+                                    canvas.save_layer(&SaveLayerRec::default());
+                                    canvas.translate((bounds.x, bounds.y));
+                                    let (scale_x, scale_y) = (
+                                        bounds.width() / svg.inner().fContainerSize.fWidth,
+                                        bounds.height() / svg.inner().fContainerSize.fHeight,
+                                    );
+
+                                    if scale_x.is_finite() && scale_y.is_finite() {
+                                        canvas.scale((scale_x, scale_y));
+                                    } else {
+                                        svg.clone()
+                                            .set_container_size((bounds.width(), bounds.height()));
                                     }
-                                }
 
-                                let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                    stops.into_iter().unzip();
-
-                                let shader = Shader::radial_gradient(
-                                    Point::from(bounds.center()),
-                                    bounds.w.max(bounds.h),
-                                    GradientShaderColors::Colors(&colors[..]),
-                                    Some(&offsets[..]),
-                                    TileMode::Clamp,
-                                    None,
-                                    None,
-                                );
-
-                                let mut paint = Paint::default();
-                                paint.set_shader(shader);
-                                canvas.draw_path(&path, &paint);
-                            }
+                                    svg.render(canvas);
 
-                            _ => {}
-                        },
-
-                        ImageOrGradient::Image(image_name) => {
-                            if let Some(image_id) = self.resource_manager.image_ids.get(image_name)
-                            {
-                                if let Some(image) = self.resource_manager.images.get(image_id) {
-                                    match &image.image {
-                                        ImageOrSvg::Image(image) => {
-                                            let image_width = image.width();
-                                            let image_height = image.height();
-                                            let (width, height) = if let Some(background_size) =
-                                                image_sizes.get(index)
-                                            {
-                                                match background_size {
-                                                    BackgroundSize::Explicit { width, height } => {
-                                                        let w = match width {
-                                                LengthPercentageOrAuto::LengthPercentage(
-                                                    length,
-                                                ) => {
-                                                    length.to_pixels(bounds.w, self.scale_factor())
-                                                }
-                                                LengthPercentageOrAuto::Auto => image_width as f32,
-                                            };
-
-                                                        let h = match height {
-                                                LengthPercentageOrAuto::LengthPercentage(
-                                                    length,
-                                                ) => {
-                                                    length.to_pixels(bounds.h, self.scale_factor())
-                                                }
-                                                LengthPercentageOrAuto::Auto => image_height as f32,
-                                            };
-
-                                                        (w, h)
-                                                    }
-
-                                                    BackgroundSize::Contain => {
-                                                        let image_ratio = image_width as f32
-                                                            / image_height as f32;
-                                                        let container_ratio = bounds.w / bounds.h;
-
-                                                        let (w, h) =
-                                                            if image_ratio > container_ratio {
-                                                                (bounds.w, bounds.w / image_ratio)
-                                                            } else {
-                                                                (bounds.h * image_ratio, bounds.h)
-                                                            };
-
-                                                        (w, h)
-                                                    }
-
-                                                    BackgroundSize::Cover => {
-                                                        let image_ratio = image_width as f32
-                                                            / image_height as f32;
-                                                        let container_ratio = bounds.w / bounds.h;
-
-                                                        let (w, h) =
-                                                            if image_ratio < container_ratio {
-                                                                (bounds.w, bounds.w / image_ratio)
-                                                            } else {
-                                                                (bounds.h * image_ratio, bounds.h)
-                                                            };
-
-                                                        (w, h)
-                                                    }
-                                                }
-                                            } else {
-                                                (image_width as f32, image_height as f32)
-                                            };
-
-                                            let matrix = Matrix::rect_to_rect(
-                                                Rect::new(
-                                                    0.0,
-                                                    0.0,
-                                                    image.width() as f32,
-                                                    image.height() as f32,
-                                                ),
-                                                Rect::new(
-                                                    bounds.left(),
-                                                    bounds.top(),
-                                                    bounds.left() + width,
-                                                    bounds.top() + height,
-                                                ),
-                                                None,
-                                            );
-
-                                            let mut paint = Paint::default();
-                                            paint.set_anti_alias(true);
-                                            paint.set_shader(image.to_shader(
-                                                (TileMode::Repeat, TileMode::Repeat),
-                                                SamplingOptions::default(),
-                                                &matrix,
-                                            ));
-
-                                            canvas.draw_path(&path, &paint);
-                                        }
+                                    if let Some(color) = self.style.fill.get(self.current).copied()
+                                    {
+                                        let mut paint = Paint::default();
 
-                                        ImageOrSvg::Svg(svg) => {
-                                            canvas.save_layer(&SaveLayerRec::default());
-                                            canvas.translate((bounds.x, bounds.y));
-                                            let (scale_x, scale_y) = (
-                                                bounds.width() / svg.inner().fContainerSize.fWidth,
-                                                bounds.height()
-                                                    / svg.inner().fContainerSize.fHeight,
-                                            );
-
-                                            if scale_x.is_finite() && scale_y.is_finite() {
-                                                canvas.scale((scale_x, scale_y));
-                                            } else {
-                                                svg.clone().set_container_size((
-                                                    bounds.width(),
-                                                    bounds.height(),
-                                                ));
-                                            }
-
-                                            svg.render(canvas);
-
-                                            if let Some(color) =
-                                                self.style.fill.get(self.current).copied()
-                                            {
-                                                let mut paint = Paint::default();
-
-                                                paint.set_anti_alias(true);
-                                                paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
-                                                paint.set_color(color);
-                                                canvas.draw_paint(&paint);
-                                            }
-                                            canvas.restore();
-                                        }
+                                        paint.set_anti_alias(true);
+                                        paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+                                        paint.set_color(color);
+                                        canvas.draw_paint(&paint);
                                     }
+                                    canvas.restore();
                                 }
                             }
                         }
@@ -1198,41 +1332,105 @@ impl DrawContext<'_> {
         if let Some(paragraph) = self.text_context.text_paragraphs.get(self.current) {
             let bounds = self.bounds();
 
-            let alignment = self.alignment();
-
-            let (mut top, _) = match alignment {
-                Alignment::TopLeft => (0.0, 0.0),
-                Alignment::TopCenter => (0.0, 0.5),
-                Alignment::TopRight => (0.0, 1.0),
-                Alignment::Left => (0.5, 0.0),
-                Alignment::Center => (0.5, 0.5),
-                Alignment::Right => (0.5, 1.0),
-                Alignment::BottomLeft => (1.0, 0.0),
-                Alignment::BottomCenter => (1.0, 0.5),
-                Alignment::BottomRight => (1.0, 1.0),
-            };
+            let position = paragraph_origin(self.style, self.current, bounds, paragraph);
+
+            if let Some(shader) =
+                self.font_gradient().and_then(|gradient| self.gradient_shader(gradient, bounds))
+            {
+                // Fill the text with the gradient by drawing it over the text in a separate layer.
+                canvas.save_layer(&SaveLayerRec::default());
+                paragraph.paint(canvas, position);
+                let mut paint = Paint::default();
+                paint.set_shader(shader);
+                paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+                canvas.draw_paint(&paint);
+                canvas.restore();
+            } else {
+                paragraph.paint(canvas, position);
+            }
 
-            let padding_top = match self.padding_top() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
-            };
+            self.draw_generated_content(canvas, paragraph, position);
+        }
+    }
+
+    /// Paints the parts of a paragraph covered by the given selection rects in the color of
+    /// selected text, if the current view has one.
+    pub fn draw_selected_text(
+        &self,
+        canvas: &Canvas,
+        paragraph: &Paragraph,
+        origin: (f32, f32),
+        rects: &[Rect],
+    ) {
+        if let Some(color) = self.selection_text_color() {
+            paint_selected_text(canvas, paragraph, origin, rects, color);
+        }
+    }
 
-            let padding_bottom = match self.padding_bottom() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
+    /// Draw the images generated by the `::before` and `::after` pseudo-elements of the current view
+    /// into the placeholders reserved for them by its paragraph. Generated text is part of the
+    /// paragraph itself.
+    pub fn draw_generated_content(
+        &self,
+        canvas: &Canvas,
+        paragraph: &Paragraph,
+        origin: (f32, f32),
+    ) {
+        let images = [
+            self.text_context.before_content.get(self.current),
+            self.text_context.after_content.get(self.current),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|content| match content {
+            GeneratedContent::Image { name, fill, .. } => Some((name, fill)),
+            GeneratedContent::Text { .. } => None,
+        });
+
+        for ((name, fill), placeholder) in images.zip(paragraph.get_rects_for_placeholders()) {
+            let rect = placeholder.rect.with_offset(origin);
+
+            let Some(image) = self
+                .resource_manager
+                .image_ids
+                .get(name)
+                .and_then(|image_id| self.resource_manager.images.get(image_id))
+            else {
+                continue;
             };
 
-            top *= bounds.height() - padding_top - padding_bottom - paragraph.height();
+            match &image.image {
+                ImageOrSvg::Image(image) => {
+                    let mut paint = Paint::default();
+                    paint.set_anti_alias(true);
+                    canvas.draw_image_rect(image, None, rect, &paint);
+                }
 
-            let padding_left = match self.padding_left() {
-                Units::Pixels(val) => val,
-                _ => 0.0,
-            };
+                ImageOrSvg::Svg(svg) => {
+                    let container_size = svg.inner().fContainerSize;
+                    let (scale_x, scale_y) = (
+                        rect.width() / container_size.fWidth,
+                        rect.height() / container_size.fHeight,
+                    );
+                    if !scale_x.is_finite() || !scale_y.is_finite() {
+                        continue;
+                    }
 
-            paragraph.paint(
-                canvas,
-                ((bounds.x + padding_left).round(), (bounds.y + padding_top + top).round()),
-            );
+                    canvas.save_layer(&SaveLayerRec::default());
+                    canvas.translate((rect.left, rect.top));
+                    canvas.scale((scale_x, scale_y));
+                    svg.render(canvas);
+
+                    if let Some(color) = fill {
+                        let mut paint = Paint::default();
+                        paint.set_anti_alias(true);
+                        paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+                        paint.set_color(*color);
+                        canvas.draw_paint(&paint);
+                    }
+                    canvas.restore();
+                }
+            }
         }
     }
 }
@@ -1264,7 +1462,258 @@ impl DataContext for DrawContext<'_> {
     }
 }
 
+/// Paints the parts of a paragraph covered by the given rects in a single color.
+pub(crate) fn paint_selected_text(
+    canvas: &Canvas,
+    paragraph: &Paragraph,
+    origin: (f32, f32),
+    rects: &[Rect],
+    color: Color,
+) {
+    if rects.is_empty() {
+        return;
+    }
+
+    let mut clip = Path::new();
+    for rect in rects {
+        clip.add_rect(*rect, None);
+    }
+
+    // Recolor the selected glyphs by drawing over them in a separate layer.
+    canvas.save();
+    canvas.clip_path(&clip, ClipOp::Intersect, true);
+    canvas.save_layer(&SaveLayerRec::default());
+    paragraph.paint(canvas, origin);
+    let mut paint = Paint::default();
+    paint.set_color(color);
+    paint.set_blend_mode(skia_safe::BlendMode::SrcIn);
+    canvas.draw_paint(&paint);
+    canvas.restore();
+    canvas.restore();
+}
+
 // Helper function for computing a rounded corner with variable smoothing
+/// Creates the stroke paint for a border with the given width, color and style.
+/// Returns the start and end points, and the length, of the line along which a linear gradient
+/// with the given direction is drawn.
+fn linear_gradient_line(
+    direction: LineDirection,
+    bounds: BoundingBox,
+) -> ((f32, f32), (f32, f32), f32) {
+    match direction {
+        LineDirection::Horizontal(horizontal_keyword) => match horizontal_keyword {
+            HorizontalPositionKeyword::Left => {
+                (bounds.center_right(), bounds.center_left(), bounds.width())
+            }
+
+            HorizontalPositionKeyword::Right => {
+                (bounds.center_left(), bounds.center_right(), bounds.width())
+            }
+        },
+
+        LineDirection::Vertical(vertical_keyword) => match vertical_keyword {
+            VerticalPositionKeyword::Top => {
+                (bounds.center_bottom(), bounds.center_top(), bounds.height())
+            }
+
+            VerticalPositionKeyword::Bottom => {
+                (bounds.center_top(), bounds.center_bottom(), bounds.height())
+            }
+        },
+
+        LineDirection::Corner { horizontal, vertical } => match (horizontal, vertical) {
+            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Bottom) => {
+                (bounds.top_left(), bounds.bottom_right(), bounds.diagonal())
+            }
+
+            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Top) => {
+                (bounds.bottom_left(), bounds.top_right(), bounds.diagonal())
+            }
+
+            (HorizontalPositionKeyword::Left, VerticalPositionKeyword::Bottom) => {
+                (bounds.top_right(), bounds.bottom_left(), bounds.diagonal())
+            }
+
+            (HorizontalPositionKeyword::Left, VerticalPositionKeyword::Top) => {
+                (bounds.bottom_right(), bounds.top_left(), bounds.diagonal())
+            }
+        },
+
+        LineDirection::Angle(angle) => {
+            let angle_rad = angle.to_radians();
+            let start_x = bounds.x + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
+            let end_x = bounds.x + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
+            let start_y = bounds.y + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
+            let end_y = bounds.y + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
+
+            let x = (end_x - start_x).abs();
+            let y = (end_y - start_y).abs();
+
+            let dist = (x * x + y * y).sqrt();
+
+            ((start_x, start_y), (end_x, end_y), dist)
+        }
+    }
+}
+
+/// Returns the center point of a radial or conic gradient within the given bounds.
+fn gradient_center(position: &Position, bounds: BoundingBox, scale_factor: f32) -> Point {
+    Point::new(
+        bounds.x + position.x.to_length_or_percentage().to_pixels(bounds.w, scale_factor),
+        bounds.y + position.y.to_length_or_percentage().to_pixels(bounds.h, scale_factor),
+    )
+}
+
+/// The number of stops used to approximate the transition curve of a color hint.
+const HINT_STOPS: usize = 8;
+
+/// Resolves the positions of the color stops of a gradient to fractions of its length. Stops
+/// without a position are spaced evenly between their neighbours, and transition hints are
+/// expanded into intermediate stops.
+fn resolve_stops<D>(
+    stops: &[ColorStop<D>],
+    to_fraction: impl Fn(&D) -> f32,
+) -> Vec<(f32, skia_safe::Color)> {
+    let num_stops = stops.len();
+    if num_stops == 0 {
+        return Vec::new();
+    }
+
+    let mut positions =
+        stops.iter().map(|stop| stop.position.as_ref().map(&to_fraction)).collect::<Vec<_>>();
+
+    positions[0] = positions[0].or(Some(0.0));
+    positions[num_stops - 1] = positions[num_stops - 1].or(Some(1.0));
+
+    // A stop cannot be positioned before any stop preceding it.
+    let mut max_position = f32::MIN;
+    for position in positions.iter_mut().flatten() {
+        *position = position.max(max_position);
+        max_position = *position;
+    }
+
+    let mut index = 1;
+    while index < num_stops {
+        if positions[index].is_none() {
+            let before = index - 1;
+            let after = (index..num_stops).find(|&i| positions[i].is_some()).unwrap();
+            let (from, to) = (positions[before].unwrap(), positions[after].unwrap());
+            for (i, position) in positions.iter_mut().enumerate().take(after).skip(index) {
+                let t = (i - before) as f32 / (after - before) as f32;
+                *position = Some(from + (to - from) * t);
+            }
+            index = after;
+        }
+        index += 1;
+    }
+
+    let mut resolved: Vec<(f32, skia_safe::Color)> = Vec::with_capacity(num_stops);
+    for (stop, position) in stops.iter().zip(positions) {
+        let position = position.unwrap();
+        let color = skia_safe::Color::from(stop.color);
+
+        if let (Some(hint), Some(&(previous_position, previous_color))) =
+            (stop.hint.as_ref(), resolved.last())
+        {
+            let length = position - previous_position;
+            if length > 0.0 {
+                let hint = ((to_fraction(hint) - previous_position) / length).clamp(0.0, 1.0);
+                for i in 1..HINT_STOPS {
+                    let t = i as f32 / HINT_STOPS as f32;
+                    let weight = match hint {
+                        h if h <= 0.0 => 1.0,
+                        h if h >= 1.0 => 0.0,
+                        h => t.powf(0.5f32.ln() / h.ln()),
+                    };
+                    resolved.push((
+                        previous_position + length * t,
+                        mix_colors(previous_color, color, weight),
+                    ));
+                }
+            }
+        }
+
+        resolved.push((position, color));
+    }
+
+    resolved
+}
+
+fn mix_colors(from: skia_safe::Color, to: skia_safe::Color, t: f32) -> skia_safe::Color {
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    skia_safe::Color::from_argb(
+        mix(from.a(), to.a()),
+        mix(from.r(), to.r()),
+        mix(from.g(), to.g()),
+        mix(from.b(), to.b()),
+    )
+}
+
+/// Converts resolved color stops into the offsets and colors of a gradient shader, along with the
+/// range of the gradient length they cover. Repeating gradients repeat the range between their
+/// first and last stops, while other gradients cover the whole length.
+#[allow(clippy::type_complexity)]
+fn gradient_stops(
+    mut stops: Vec<(f32, skia_safe::Color)>,
+    repeating: bool,
+) -> Option<((f32, f32), Vec<f32>, Vec<skia_safe::Color>)> {
+    let first = stops.first()?.0;
+    let last = stops.last()?.0;
+
+    let range = if repeating && last > first {
+        for stop in stops.iter_mut() {
+            stop.0 = (stop.0 - first) / (last - first);
+        }
+
+        (first, last)
+    } else {
+        // Insert a stop at the front if the first stop is not at 0.
+        if first > 0.0 {
+            stops.insert(0, (0.0, stops[0].1));
+        }
+
+        // Insert a stop at the end if the last stop is not at 1.0.
+        if last < 1.0 {
+            stops.push((1.0, stops[stops.len() - 1].1));
+        }
+
+        (0.0, 1.0)
+    };
+
+    let (offsets, colors) = stops.into_iter().unzip();
+    Some((range, offsets, colors))
+}
+
+fn tile_mode(repeating: bool) -> TileMode {
+    if repeating {
+        TileMode::Repeat
+    } else {
+        TileMode::Clamp
+    }
+}
+
+fn border_paint(width: f32, color: Color, style: BorderStyleKeyword) -> Paint {
+    let mut paint = Paint::default();
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_color(color);
+    paint.set_stroke_width(width);
+    match style {
+        BorderStyleKeyword::Dashed => {
+            paint.set_path_effect(PathEffect::dash(&[width * 2.0, width], 0.0));
+        }
+
+        BorderStyleKeyword::Dotted => {
+            paint.set_path_effect(PathEffect::dash(&[0.0, width * 2.0], 0.0));
+            paint.set_stroke_cap(skia_safe::PaintCap::Round);
+        }
+
+        _ => {}
+    }
+
+    paint.set_anti_alias(true);
+    paint
+}
+
 fn compute_smooth_corner(
     corner_radius: f32,
     smoothing: f32,
@@ -5,6 +5,7 @@ use std::error::Error;
 use std::rc::Rc;
 
 use hashbrown::{HashMap, HashSet};
+use log::warn;
 use vizia_storage::{LayoutTreeIterator, TreeIterator};
 
 use crate::animation::{AnimId, Interpolator};
@@ -87,6 +88,7 @@ pub struct EventContext<'a> {
     pub(crate) event_proxy: &'a mut Option<Box<dyn crate::context::EventProxy>>,
     pub(crate) ignore_default_theme: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
+    pub(crate) profiler: &'a mut Profiler,
     pub windows: &'a mut HashMap<Entity, WindowState>,
 }
 
@@ -140,6 +142,7 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            profiler: &mut cx.profiler,
             windows: &mut cx.windows,
         }
     }
@@ -174,6 +177,7 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            profiler: &mut cx.profiler,
             windows: &mut cx.windows,
         }
     }
@@ -281,6 +285,7 @@ impl<'a> EventContext<'a> {
                     rect.1.to_pixels(bounds.w, scale),
                     rect.2.to_pixels(bounds.h, scale),
                 ),
+                _ => bounds,
             })
             .unwrap_or(bounds);
 
@@ -698,6 +703,27 @@ impl<'a> EventContext<'a> {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns a reference to the [Profiler] containing the timings and counters of recent frames.
+    pub fn profiler(&self) -> &Profiler {
+        self.profiler
+    }
+
+    /// Returns the problems found when the stylesheets added to the application were last parsed.
+    pub fn stylesheet_diagnostics(&self) -> &[StyleDiagnostic] {
+        &self.resource_manager.style_diagnostics
+    }
+
+    /// Sets whether the profiling overlay is shown in the windows of the application.
+    pub fn show_profiler_overlay(&mut self, flag: bool) {
+        self.profiler.show_overlay = flag;
+
+        for window_entity in self.windows.keys().copied().collect::<Vec<_>>() {
+            if let Some(window_state) = self.windows.get_mut(&window_entity) {
+                window_state.redraw_list.insert(window_entity);
+            }
+        }
+    }
+
     /// Sets the current [theme mode](ThemeMode).
     pub fn set_theme_mode(&mut self, theme_mode: ThemeMode) {
         if !self.ignore_default_theme {
@@ -752,19 +778,53 @@ impl<'a> EventContext<'a> {
 
         self.style.clear_style_rules();
 
-        let mut overall_theme = String::new();
-
         // Reload built-in themes
         for theme in self.resource_manager.themes.iter() {
-            overall_theme += theme;
+            self.style.parse_theme(theme, "<theme>", None);
         }
 
-        for style_string in self.resource_manager.styles.iter().flat_map(|style| style.get_style())
-        {
-            overall_theme += &style_string;
+        // Stylesheets are parsed separately so that rules and diagnostics can be traced back to
+        // their source.
+        let mut diagnostics = Vec::new();
+        for style in self.resource_manager.styles.iter() {
+            let source = style.source_name();
+            match style.get_style() {
+                Ok(style_string) => {
+                    diagnostics.extend(self.style.parse_theme(
+                        &style_string,
+                        &source,
+                        style.base_path().as_deref(),
+                    ));
+                }
+
+                Err(error) => diagnostics.push(StyleDiagnostic::unreadable(&source, &error)),
+            }
         }
 
-        self.style.parse_theme(&overall_theme);
+        // Fonts declared by `@font-face` rules are loaded before the text of each view is rebuilt
+        // below, so that text using them is laid out again once they are available.
+        let mut fonts_loaded = false;
+        for declaration in std::mem::take(&mut self.style.font_faces) {
+            match self.text_context.load_font_face(&declaration.font_face) {
+                Ok(loaded) => fonts_loaded |= loaded,
+                Err(message) => diagnostics.push(StyleDiagnostic::font_face(
+                    &declaration.source,
+                    declaration.line,
+                    declaration.column,
+                    message,
+                )),
+            }
+        }
+
+        if fonts_loaded {
+            self.text_context.font_collection.clear_caches();
+        }
+
+        for diagnostic in diagnostics.iter() {
+            warn!("{}", diagnostic);
+        }
+
+        self.resource_manager.style_diagnostics = diagnostics;
 
         for entity in self.tree.into_iter() {
             self.style.needs_restyle(entity);
@@ -939,6 +999,117 @@ impl<'a> EventContext<'a> {
             .unwrap_or_default()
     }
 
+    /// Sets whether the current view has the given custom state.
+    ///
+    /// Custom states can be selected with a CSS pseudo-class selector of the same name:
+    /// ```css
+    /// step-cell:playing {
+    ///     background-color: green;
+    /// }
+    /// ```
+    ///
+    /// The view and its neighbours are only restyled if the state changes.
+    pub fn set_custom_state(&mut self, name: &str, applied: bool) {
+        if self.style.set_custom_state(self.current, name, applied) {
+            self.needs_restyle();
+        }
+    }
+
+    /// Returns true if the current view has the given custom state.
+    pub fn has_custom_state(&self, name: &str) -> bool {
+        self.style.has_custom_state(self.current, name)
+    }
+
+    /// Sets an attribute of the current view, which can be selected with a CSS attribute selector:
+    /// ```css
+    /// button[variant="primary"] {
+    ///     background-color: blue;
+    /// }
+    /// ```
+    ///
+    /// The view and its neighbours are only restyled if the value of the attribute changes.
+    pub fn set_attribute(&mut self, name: &str, value: impl ToString) {
+        if self.style.set_attribute(self.current, name, Some(value.to_string())) {
+            self.needs_restyle();
+        }
+    }
+
+    /// Removes an attribute from the current view.
+    pub fn remove_attribute(&mut self, name: &str) {
+        if self.style.set_attribute(self.current, name, None) {
+            self.needs_restyle();
+        }
+    }
+
+    /// Returns the value of an attribute of the current view.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.style.get_attribute(self.current, name)
+    }
+
+    /// Sets the language of the current view and its descendants, overriding the locale of the
+    /// application. Passing `None` removes the override.
+    ///
+    /// The language can be selected with the `:lang()` CSS pseudo-class selector:
+    /// ```css
+    /// label:lang(de) {
+    ///     font-size: 12px;
+    /// }
+    /// ```
+    pub fn set_language(&mut self, language: Option<LanguageIdentifier>) {
+        let changed = match language {
+            Some(language) => {
+                let changed = self.style.language.get(self.current) != Some(&language);
+                self.style.language.insert(self.current, language);
+                changed
+            }
+            None => self.style.language.remove(self.current).is_some(),
+        };
+
+        if changed {
+            self.needs_restyle();
+        }
+    }
+
+    /// Returns the language of the current view, which is inherited from its ancestors or
+    /// otherwise is the locale of the application.
+    pub fn language(&self) -> &LanguageIdentifier {
+        self.style.get_language(self.current, self.tree)
+    }
+
+    /// Sets the text direction of the current view and its descendants, overriding the direction
+    /// of their language. Passing `None` removes the override.
+    ///
+    /// The direction can be selected with the `:dir()` CSS pseudo-class selector.
+    pub fn set_direction(&mut self, direction: Option<Direction>) {
+        let changed = match direction {
+            Some(direction) => {
+                let changed = self.style.direction.get(self.current) != Some(&direction);
+                self.style.direction.insert(self.current, direction);
+                changed
+            }
+            None => self.style.direction.remove(self.current).is_some(),
+        };
+
+        if changed {
+            self.needs_restyle();
+        }
+    }
+
+    /// Returns the text direction of the current view.
+    pub fn direction(&self) -> Direction {
+        self.style.get_direction(self.current, self.tree)
+    }
+
+    /// Sets the locale used when matching `:lang()` and `:dir()` selectors and restyles all views.
+    pub(crate) fn set_locale(&mut self, locale: &LanguageIdentifier) {
+        if self.style.locale != *locale {
+            self.style.locale = locale.clone();
+            for entity in self.tree.into_iter() {
+                self.style.needs_restyle(entity);
+            }
+        }
+    }
+
     // Accessibility Properties
 
     /// Sets the accessibility name of the view.
@@ -1055,9 +1226,15 @@ impl<'a> EventContext<'a> {
 
     // FILTER
 
+    /// Sets the filter functions applied to the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.filter.insert(self.current, filter.into());
+        self.needs_redraw();
+    }
+
     /// Sets the backdrop filter of the current view.
-    pub fn set_backdrop_filter(&mut self, filter: Filter) {
-        self.style.backdrop_filter.insert(self.current, filter);
+    pub fn set_backdrop_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.backdrop_filter.insert(self.current, filter.into());
     }
 
     // BOX SHADOW
@@ -1158,9 +1335,31 @@ impl<'a> EventContext<'a> {
     }
 
     // GETTERS
+    /// Returns the border width of the current view in physical pixels.
+    ///
+    /// When the sides have different widths this is the width of the top border.
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
+    get_length_property!(
+        /// Returns the top border width of the current view in physical pixels.
+        border_top_width
+    );
+
+    get_length_property!(
+        /// Returns the right border width of the current view in physical pixels.
+        border_right_width
+    );
+
+    get_length_property!(
+        /// Returns the bottom border width of the current view in physical pixels.
+        border_bottom_width
+    );
+
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the left border width of the current view in physical pixels.
+        border_left_width
     );
 
     /// Returns the font-size of the current view in physical pixels.
@@ -125,6 +125,8 @@ pub struct Context {
 
     pub text_context: TextContext,
 
+    pub(crate) code_highlighters: HashMap<String, Box<dyn Highlighter>>,
+
     pub(crate) event_proxy: Option<Box<dyn EventProxy>>,
 
     #[cfg(feature = "clipboard")]
@@ -139,6 +141,8 @@ pub struct Context {
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+
+    pub(crate) profiler: Profiler,
 }
 
 impl Default for Context {
@@ -199,9 +203,16 @@ impl Context {
                     asset_provider,
                     text_bounds: Default::default(),
                     text_paragraphs: Default::default(),
+                    text_documents: Default::default(),
+                    before_content: Default::default(),
+                    after_content: Default::default(),
+                    offset_maps: Default::default(),
+                    loaded_font_faces: Vec::new(),
                 }
             },
 
+            code_highlighters: HashMap::new(),
+
             event_proxy: None,
 
             #[cfg(feature = "clipboard")]
@@ -224,6 +235,7 @@ impl Context {
             window_has_focus: true,
 
             drop_data: None,
+            profiler: Profiler::default(),
         };
 
         result.tree.set_window(Entity::root(), true);
@@ -264,6 +276,30 @@ impl Context {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns a reference to the [Profiler] containing the timings and counters of recent frames.
+    pub fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    /// Returns the problems found when the stylesheets added to the application were last parsed.
+    ///
+    /// The diagnostics are updated whenever the stylesheets are reloaded, for example after calling
+    /// [`add_stylesheet`](Self::add_stylesheet), and can be used to fail a build on invalid CSS.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.add_stylesheet("label { width: red; }").unwrap();
+    ///
+    /// for diagnostic in cx.stylesheet_diagnostics() {
+    ///     eprintln!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn stylesheet_diagnostics(&self) -> &[StyleDiagnostic] {
+        &self.resource_manager.style_diagnostics
+    }
+
     /// Returns the entity id of the  parent window to the current view.
     pub fn parent_window(&self) -> Entity {
         self.tree.get_parent_window(self.current).unwrap_or(Entity::root())
@@ -314,7 +350,7 @@ impl Context {
         }
 
         if system_flags.contains(SystemFlags::REFLOW) {
-            self.style.needs_text_update(entity);
+            self.style.needs_paragraph_update(entity, &self.tree);
         }
     }
 
@@ -511,6 +547,10 @@ impl Context {
             self.views.remove(entity);
             self.text_context.text_bounds.remove(*entity);
             self.text_context.text_paragraphs.remove(*entity);
+            self.text_context.text_documents.remove(*entity);
+            self.text_context.before_content.remove(*entity);
+            self.text_context.after_content.remove(*entity);
+            self.text_context.offset_maps.remove(*entity);
             self.entity_manager.destroy(*entity);
         }
     }
@@ -614,6 +654,16 @@ impl Context {
         self.resource_manager.image_loader = Some(Box::new(loader));
     }
 
+    /// Registers a highlighter for the source code of a language, which is used to highlight the
+    /// code blocks of a `Markdown` view.
+    ///
+    /// The language is matched against the first word of the info string of a fenced code block,
+    /// ignoring case, so a highlighter added for `"rust"` highlights blocks beginning with ` ```rust `.
+    /// Adding a highlighter for a language which already has one replaces it.
+    pub fn add_code_highlighter(&mut self, language: &str, highlighter: impl Highlighter) {
+        self.code_highlighters.insert(language.to_lowercase(), Box::new(highlighter));
+    }
+
     pub fn add_translation(&mut self, lang: LanguageIdentifier, ftl: impl ToString) {
         self.resource_manager.add_translation(lang, ftl.to_string());
     }
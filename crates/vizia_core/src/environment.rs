@@ -67,6 +67,7 @@ pub struct Environment {
 impl Environment {
     pub(crate) fn new(cx: &mut Context) -> Self {
         let locale = sys_locale::get_locale().and_then(|l| l.parse().ok()).unwrap_or_default();
+        cx.style.locale = locale.clone();
         let caret_timer = cx.add_timer(Duration::from_millis(530), None, |cx, action| {
             if matches!(action, TimerAction::Tick(_)) {
                 cx.emit(TextEvent::ToggleCaret);
@@ -94,6 +95,7 @@ impl Model for Environment {
         event.take(|event, _| match event {
             EnvironmentEvent::SetLocale(locale) => {
                 self.locale = locale;
+                cx.set_locale(&self.locale);
             }
 
             EnvironmentEvent::SetThemeMode(theme) => {
@@ -106,6 +108,7 @@ impl Model for Environment {
             EnvironmentEvent::UseSystemLocale => {
                 self.locale =
                     sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+                cx.set_locale(&self.locale);
             }
 
             EnvironmentEvent::ToggleThemeMode => {
@@ -43,6 +43,9 @@ impl EventManager {
         cx: &mut Context,
         mut window_event_callback: impl FnMut(&WindowEvent),
     ) {
+        let start = Instant::now();
+        let bindings = cx.profiler.pending.bindings;
+
         while {
             // Clear the event queue in the event manager.
             self.event_queue.clear();
@@ -164,6 +167,10 @@ impl EventManager {
             // Return true if there are new events in the queue.
             !cx.event_queue.is_empty()
         } {}
+
+        // Bindings are updated while dispatching events but are measured separately.
+        let bindings = cx.profiler.pending.bindings - bindings;
+        cx.profiler.pending.events += start.elapsed().saturating_sub(bindings);
     }
 }
 
@@ -513,6 +520,11 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 EventContext::new(cx).reload_styles().unwrap();
             }
 
+            if *code == Code::F6 {
+                let show = !cx.profiler.overlay_shown();
+                EventContext::new(cx).show_profiler_overlay(show);
+            }
+
             if *code == Code::Tab {
                 let lock_focus_to = cx.tree.lock_focus_within(cx.focused);
                 if cx.modifiers.shift() {
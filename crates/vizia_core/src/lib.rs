@@ -25,6 +25,7 @@ pub mod layout;
 pub mod localization;
 pub mod model;
 pub mod modifiers;
+pub mod profiler;
 pub mod resource;
 pub mod style;
 pub(crate) mod systems;
@@ -44,6 +45,13 @@ pub mod vg {
     pub use skia_safe::*;
 }
 
+/// Contains the types of the nodes of a markdown document, used to override how nodes are rendered by the
+/// [`Markdown`](crate::views::Markdown) view. This is a re-export of [comrak](https://github.com/kivikakk/comrak).
+#[cfg(feature = "markdown")]
+pub mod markdown {
+    pub use comrak::nodes::*;
+}
+
 /// A collection of built-in SVG icons.
 pub mod icons;
 
@@ -82,12 +90,17 @@ pub mod prelude {
         AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
         LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
     };
+    pub use super::profiler::{FrameStats, Profiler};
     pub use super::resource::{ImageId, ImageRetentionPolicy};
+    pub use super::text::{
+        CodeToken, HighlightState, Highlighter, RichText, RunAttribute, RunStyle, TokenKind,
+    };
     pub use super::util::{IntoCssStr, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
     pub use super::window::{DropData, WindowEvent};
     pub use accesskit::{Action, Live, Role};
+    pub use ropey::Rope;
     pub use skia_safe::Canvas;
     pub use vizia_derive::{Data, Lens};
     pub use vizia_id::GenerationalId;
@@ -57,6 +57,28 @@ macro_rules! modifier {
             self
         }
     };
+
+    // Variant for properties which change the shape of the view, and so invalidate its cached path.
+    (
+        $(#[$meta:meta])*
+        $name:ident, $t:ty, $flags:expr, clear_path
+    ) => {
+        $(#[$meta])*
+        #[allow(unused_variables)]
+        fn $name<U: Into<$t>>(mut self, value: impl Res<U>) -> Self {
+            let entity = self.entity();
+            let current = self.current();
+            value.set_or_bind(self.context(), current, move |cx, v| {
+                cx.style.$name.insert(entity, v.get(cx).into());
+                cx.cache.path.remove(entity);
+
+                cx.style.system_flags |= $flags;
+                cx.set_system_flags(entity, $flags);
+            });
+
+            self
+        }
+    };
 }
 
 // Inside private module to hide implementation details.
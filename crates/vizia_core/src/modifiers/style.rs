@@ -85,6 +85,100 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets an attribute of the view.
+    ///
+    /// Attributes can be referenced by a CSS attribute selector, which supports the `=`, `~=`, `|=`,
+    /// `^=`, `$=`, and `*=` operators. Binding the value to a lens restyles the view when it changes.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).attr("data-level", 3);
+    /// ```
+    /// css
+    /// ```css
+    /// element[data-level="3"] {
+    ///     background-color: red;
+    /// }
+    ///```
+    fn attr<U: ToString>(mut self, name: &str, value: impl Res<U>) -> Self {
+        let name = name.to_owned();
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, move |cx| {
+            value.set_or_bind(cx, entity, move |cx, val| {
+                let val = val.get(cx).to_string();
+                if cx.style.set_attribute(entity, &name, Some(val)) {
+                    cx.needs_restyle(entity);
+                }
+            });
+        });
+
+        self
+    }
+
+    /// Sets the language of the view and its descendants, overriding the locale of the application.
+    ///
+    /// The language can be referenced by the `:lang()` CSS pseudo-class selector.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).lang(langid!("ar"));
+    /// ```
+    /// css
+    /// ```css
+    /// element:lang(ar) {
+    ///     font-size: 16px;
+    /// }
+    ///```
+    fn lang(mut self, language: impl Res<LanguageIdentifier>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            language.set_or_bind(cx, entity, move |cx, val| {
+                let val = val.get(cx);
+                if cx.style.language.get(entity) != Some(&val) {
+                    cx.style.language.insert(entity, val);
+                    cx.needs_restyle(entity);
+                }
+            });
+        });
+
+        self
+    }
+
+    /// Sets the text direction of the view and its descendants, overriding the direction of their language.
+    ///
+    /// The direction can be referenced by the `:dir()` CSS pseudo-class selector.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).direction(Direction::Rtl);
+    /// ```
+    /// css
+    /// ```css
+    /// element:dir(rtl) {
+    ///     alignment: right;
+    /// }
+    ///```
+    fn direction(mut self, direction: impl Res<Direction>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            direction.set_or_bind(cx, entity, move |cx, val| {
+                let val = val.get(cx);
+                if cx.style.direction.get(entity) != Some(&val) {
+                    cx.style.direction.insert(entity, val);
+                    cx.needs_restyle(entity);
+                }
+            });
+        });
+
+        self
+    }
+
     // PseudoClassFlags
     // TODO: Should these have their own modifiers trait?
 
@@ -110,6 +204,37 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets whether the view has the given custom state.
+    ///
+    /// Custom states can be referenced by a CSS pseudo-class selector of the same name.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).custom_state("armed", true);
+    /// ```
+    /// css
+    /// ```css
+    /// element:armed {
+    ///     background-color: red;
+    /// }
+    ///```
+    fn custom_state<U: Into<bool>>(mut self, name: &str, state: impl Res<U>) -> Self {
+        let name = name.to_owned();
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, move |cx| {
+            state.set_or_bind(cx, entity, move |cx, val| {
+                let val = val.get(cx).into();
+                if cx.style.set_custom_state(entity, &name, val) {
+                    cx.needs_restyle(entity);
+                }
+            });
+        });
+
+        self
+    }
+
     /// Sets the focused state of the view.
     ///
     /// Since only one view can have keyboard focus at a time, subsequent calls to this
@@ -282,6 +407,34 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the mask image of the view, whose alpha determines the visibility of the view and its descendants.
+    fn mask_image<'i, U: Into<BackgroundImage<'i>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, val| {
+                let image = val.get(cx).into();
+                let image = match image {
+                    BackgroundImage::Gradient(gradient) => {
+                        Some(ImageOrGradient::Gradient(*gradient))
+                    }
+                    BackgroundImage::Url(url) => Some(ImageOrGradient::Image(url.url.to_string())),
+                    _ => None,
+                };
+
+                if let Some(image) = image {
+                    cx.style.mask_image.insert(cx.current, vec![image]);
+                } else {
+                    cx.style.mask_image.remove(cx.current);
+                }
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
     /// Sets the overflow behavior of the view in the horizontal and vertical directions simultaneously.
     fn overflow<U: Into<Overflow>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -317,8 +470,24 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    /// Sets the filter functions applied to the view and its children.
+    fn filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.filter.insert(cx.current, value);
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
     /// Sets the backdrop filter for the view.
-    fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
+    fn backdrop_filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
         self.context().with_current(current, |cx| {
@@ -429,11 +598,16 @@ pub trait StyleModifiers: internal::Modifiable {
     }
 
     // Border Properties
+    /// Sets the border width of all four sides of the view.
     fn border_width<U: Into<LengthOrPercentage>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
         value.set_or_bind(self.context(), current, move |cx, v| {
-            cx.style.border_width.insert(entity, v.get(cx).into());
+            let value: LengthOrPercentage = v.get(cx).into();
+            cx.style.border_top_width.insert(entity, value.clone());
+            cx.style.border_right_width.insert(entity, value.clone());
+            cx.style.border_bottom_width.insert(entity, value.clone());
+            cx.style.border_left_width.insert(entity, value);
             cx.cache.path.remove(entity);
             cx.style.system_flags |= SystemFlags::RELAYOUT | SystemFlags::REDRAW;
             cx.set_system_flags(entity, SystemFlags::RELAYOUT | SystemFlags::REDRAW);
@@ -443,15 +617,134 @@ pub trait StyleModifiers: internal::Modifiable {
     }
 
     modifier!(
-        /// Sets the border color of the view.
-        border_color,
+        /// Sets the top border width of the view.
+        border_top_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW,
+        clear_path
+    );
+
+    modifier!(
+        /// Sets the right border width of the view.
+        border_right_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW,
+        clear_path
+    );
+
+    modifier!(
+        /// Sets the bottom border width of the view.
+        border_bottom_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW,
+        clear_path
+    );
+
+    modifier!(
+        /// Sets the left border width of the view.
+        border_left_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW,
+        clear_path
+    );
+
+    /// Sets the border color of all four sides of the view.
+    fn border_color<U: Into<Color>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        value.set_or_bind(self.context(), current, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.border_top_color.insert(entity, value);
+            cx.style.border_right_color.insert(entity, value);
+            cx.style.border_bottom_color.insert(entity, value);
+            cx.style.border_left_color.insert(entity, value);
+            // A color overrides a gradient from a style rule.
+            cx.style.border_gradient.insert(entity, Gradient::None);
+
+            cx.style.system_flags |= SystemFlags::REDRAW;
+            cx.set_system_flags(entity, SystemFlags::REDRAW);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the top border color of the view.
+        border_top_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the right border color of the view.
+        border_right_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the bottom border color of the view.
+        border_bottom_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the left border color of the view.
+        border_left_color,
         Color,
         SystemFlags::REDRAW
     );
 
     modifier!(
-        /// Sets the border color of the view.
-        border_style,
+        /// Sets a gradient which fills the border of the view in place of the border colors.
+        border_gradient,
+        Gradient,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the border style of all four sides of the view.
+    fn border_style<U: Into<BorderStyleKeyword>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        value.set_or_bind(self.context(), current, move |cx, v| {
+            let value = v.get(cx).into();
+            cx.style.border_top_style.insert(entity, value);
+            cx.style.border_right_style.insert(entity, value);
+            cx.style.border_bottom_style.insert(entity, value);
+            cx.style.border_left_style.insert(entity, value);
+
+            cx.style.system_flags |= SystemFlags::REDRAW;
+            cx.set_system_flags(entity, SystemFlags::REDRAW);
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the top border style of the view.
+        border_top_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the right border style of the view.
+        border_right_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the bottom border style of the view.
+        border_bottom_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the left border style of the view.
+        border_left_style,
         BorderStyleKeyword,
         SystemFlags::REDRAW
     );
@@ -827,3 +1120,21 @@ impl From<ShadowBuilder> for Shadow {
         value.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn border_color_overrides_border_gradient() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx)
+            .border_gradient("linear-gradient(red, blue)")
+            .border_color(Color::red())
+            .entity();
+
+        assert_eq!(cx.style.border_top_color.get(entity), Some(&Color::red()));
+        assert_eq!(cx.style.border_left_color.get(entity), Some(&Color::red()));
+        assert_eq!(cx.style.border_gradient.get(entity), Some(&Gradient::None));
+    }
+}
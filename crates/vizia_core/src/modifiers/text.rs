@@ -14,7 +14,7 @@ pub trait TextModifiers: internal::Modifiable {
                 // cx.text_context.set_text(entity, &text_data);
                 cx.style.text.insert(entity, text_data);
 
-                cx.style.needs_text_update(entity);
+                cx.style.needs_paragraph_update(entity, cx.tree);
                 cx.needs_relayout();
                 cx.needs_redraw();
             });
@@ -60,6 +60,48 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the OpenType font features, such as tabular numerals, that should be used by the view.
+        font_feature_settings,
+        Vec<FontFeature>,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the height of each line of text, as a multiple of the font size or a length.
+        line_height,
+        LineHeight,
+        SystemFlags::RELAYOUT | SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional spacing between the letters of the text.
+        letter_spacing,
+        Spacing,
+        SystemFlags::RELAYOUT | SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional spacing between the words of the text.
+        word_spacing,
+        Spacing,
+        SystemFlags::RELAYOUT | SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the shadows drawn behind the text of the view.
+        text_shadow,
+        Vec<Shadow>,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets how the capitalization of the text is changed before it is displayed.
+        text_transform,
+        TextTransform,
+        SystemFlags::RELAYOUT | SystemFlags::REFLOW
+    );
+
     /// Sets the text color of the view.
     fn color<U: Clone + Into<Color>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -67,6 +109,8 @@ pub trait TextModifiers: internal::Modifiable {
         self.context().with_current(current, move |cx| {
             value.set_or_bind(cx, entity, move |cx, v| {
                 cx.style.font_color.insert(entity, v.get(cx).into());
+                // A color overrides a gradient from a style rule or an ancestor.
+                cx.style.font_gradient.insert(entity, Gradient::None);
                 cx.style.needs_text_update(entity);
                 cx.needs_redraw(entity);
             });
@@ -74,6 +118,19 @@ pub trait TextModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets a gradient which fills the text of the view in place of the text color.
+    fn text_gradient<U: Into<Gradient>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, move |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                cx.style.font_gradient.insert(entity, v.get(cx).into());
+                cx.needs_redraw(entity);
+            });
+        });
+        self
+    }
+
     /// Sets the font size of the view.
     fn font_size<U: Into<FontSize>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -167,3 +224,20 @@ pub trait TextModifiers: internal::Modifiable {
 }
 
 impl<V> TextModifiers for Handle<'_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn color_overrides_text_gradient() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx)
+            .text_gradient("linear-gradient(red, blue)")
+            .color(Color::red())
+            .entity();
+
+        assert_eq!(cx.style.font_color.get(entity), Some(&Color::red()));
+        assert_eq!(cx.style.font_gradient.get(entity), Some(&Gradient::None));
+    }
+}
@@ -0,0 +1,284 @@
+//! Per-frame timings and counters for the systems which update and draw the view tree.
+//!
+//! The [`Profiler`] of an application is accessed with [`Context::profiler`] or
+//! [`EventContext::profiler`](crate::context::EventContext::profiler). In addition, an overlay showing
+//! the statistics for the most recent frame can be toggled in a window by pressing F6.
+
+use std::collections::VecDeque;
+use std::ops::AddAssign;
+
+use skia_safe::{Canvas, Color, Font, FontStyle, Paint, Rect};
+
+use crate::prelude::*;
+use crate::text::TextContext;
+
+/// The number of frames kept by the profiler for computing averages.
+const HISTORY_LEN: usize = 120;
+
+/// Timings and counters collected for a single frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FrameStats {
+    /// Time spent dispatching events, not including updating bindings.
+    pub events: Duration,
+    /// Time spent updating bindings after events have been dispatched.
+    pub bindings: Duration,
+    /// Time spent matching and linking style rules.
+    pub style: Duration,
+    /// Time spent on layout, including text layout.
+    pub layout: Duration,
+    /// Time spent drawing to the window surfaces.
+    pub draw: Duration,
+    /// The number of bindings which were rebuilt.
+    pub bindings_updated: usize,
+    /// The number of entities which had their style rules rematched.
+    pub restyled: usize,
+    /// The number of entities which changed size or position during layout.
+    pub relaid_out: usize,
+    /// The number of entities which were marked for redraw.
+    pub redrawn: usize,
+}
+
+impl FrameStats {
+    /// Returns the total time spent on the frame.
+    pub fn total(&self) -> Duration {
+        self.events + self.bindings + self.style + self.layout + self.draw
+    }
+}
+
+impl AddAssign for FrameStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.events += rhs.events;
+        self.bindings += rhs.bindings;
+        self.style += rhs.style;
+        self.layout += rhs.layout;
+        self.draw += rhs.draw;
+        self.bindings_updated += rhs.bindings_updated;
+        self.restyled += rhs.restyled;
+        self.relaid_out += rhs.relaid_out;
+        self.redrawn += rhs.redrawn;
+    }
+}
+
+/// Collects the timings and counters of the systems which update and draw the view tree.
+///
+/// A frame starts when the style system is run by the windowing backend, and includes the events
+/// dispatched before it as well as any drawing which happens afterwards.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    /// Statistics for events dispatched since the start of the current frame, which belong to the next frame.
+    pub(crate) pending: FrameStats,
+    /// Statistics for the frame currently being processed.
+    pub(crate) current: FrameStats,
+    history: VecDeque<FrameStats>,
+    pub(crate) show_overlay: bool,
+}
+
+impl Profiler {
+    /// Returns the statistics of the most recently completed frame.
+    pub fn last_frame(&self) -> FrameStats {
+        self.history.back().copied().unwrap_or_default()
+    }
+
+    /// Returns the average statistics of the recently completed frames.
+    pub fn average(&self) -> FrameStats {
+        let count = self.history.len() as u32;
+        if count == 0 {
+            return FrameStats::default();
+        }
+
+        let mut sum = FrameStats::default();
+        for frame in self.history.iter() {
+            sum += *frame;
+        }
+
+        FrameStats {
+            events: sum.events / count,
+            bindings: sum.bindings / count,
+            style: sum.style / count,
+            layout: sum.layout / count,
+            draw: sum.draw / count,
+            bindings_updated: sum.bindings_updated / count as usize,
+            restyled: sum.restyled / count as usize,
+            relaid_out: sum.relaid_out / count as usize,
+            redrawn: sum.redrawn / count as usize,
+        }
+    }
+
+    /// Returns an iterator over the statistics of the recently completed frames, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = &FrameStats> {
+        self.history.iter()
+    }
+
+    /// Returns whether the profiling overlay is shown.
+    pub fn overlay_shown(&self) -> bool {
+        self.show_overlay
+    }
+
+    /// Finishes the current frame and starts a new one with any statistics recorded while dispatching events.
+    ///
+    /// Returns `true` if the overlay is shown and the finished frame was not drawn, in which case the
+    /// overlay is showing the statistics of an older frame and needs to be redrawn.
+    pub(crate) fn begin_frame(&mut self) -> bool {
+        let finished = std::mem::replace(&mut self.current, std::mem::take(&mut self.pending));
+
+        if finished == FrameStats::default() {
+            return false;
+        }
+
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(finished);
+
+        self.show_overlay && finished.draw.is_zero()
+    }
+
+    /// Draws the profiling overlay into the top-left corner of the canvas.
+    pub(crate) fn draw_overlay(
+        &self,
+        canvas: &Canvas,
+        text_context: &TextContext,
+        scale_factor: f32,
+    ) {
+        let Some(typeface) =
+            text_context.default_font_manager.legacy_make_typeface(None, FontStyle::normal())
+        else {
+            return;
+        };
+
+        let font = Font::from_typeface(typeface, 12.0 * scale_factor);
+
+        let last = self.last_frame();
+        let average = self.average();
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+
+        let lines = [
+            format!("Frame:  {:6.2} ms  (avg {:6.2} ms)", ms(last.total()), ms(average.total())),
+            format!("Events: {:6.2} ms", ms(last.events)),
+            format!("Bind:   {:6.2} ms  {} updated", ms(last.bindings), last.bindings_updated),
+            format!("Style:  {:6.2} ms  {} restyled", ms(last.style), last.restyled),
+            format!("Layout: {:6.2} ms  {} relaid out", ms(last.layout), last.relaid_out),
+            format!("Draw:   {:6.2} ms  {} redrawn", ms(last.draw), last.redrawn),
+        ];
+
+        let padding = 6.0 * scale_factor;
+        let line_height = 16.0 * scale_factor;
+        let width = lines.iter().map(|line| font.measure_str(line, None).0).fold(0.0, f32::max)
+            + 2.0 * padding;
+        let height = lines.len() as f32 * line_height + 2.0 * padding;
+
+        let mut background = Paint::default();
+        background.set_color(Color::from_argb(200, 0, 0, 0));
+        canvas.draw_rect(Rect::from_xywh(0.0, 0.0, width, height), &background);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(Color::WHITE);
+
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = padding + (index as f32 + 0.75) * line_height;
+            canvas.draw_str(line, (padding, baseline), &font, &paint);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(events_ms: u64, draw_ms: u64, redrawn: usize) -> FrameStats {
+        FrameStats {
+            events: Duration::from_millis(events_ms),
+            draw: Duration::from_millis(draw_ms),
+            redrawn,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn total_includes_bindings() {
+        let stats = FrameStats {
+            events: Duration::from_millis(1),
+            bindings: Duration::from_millis(2),
+            style: Duration::from_millis(3),
+            layout: Duration::from_millis(4),
+            draw: Duration::from_millis(5),
+            ..Default::default()
+        };
+
+        assert_eq!(stats.total(), Duration::from_millis(15));
+    }
+
+    #[test]
+    fn begin_frame_moves_pending_into_current() {
+        let mut profiler = Profiler::default();
+        profiler.pending = frame(2, 0, 0);
+
+        profiler.begin_frame();
+        assert_eq!(profiler.current, frame(2, 0, 0));
+        assert_eq!(profiler.pending, FrameStats::default());
+        assert_eq!(profiler.history().count(), 0);
+
+        profiler.current.draw = Duration::from_millis(3);
+        profiler.begin_frame();
+        assert_eq!(profiler.last_frame(), frame(2, 3, 0));
+        assert_eq!(profiler.current, FrameStats::default());
+    }
+
+    #[test]
+    fn empty_frames_are_not_recorded() {
+        let mut profiler = Profiler::default();
+
+        profiler.begin_frame();
+        profiler.begin_frame();
+
+        assert_eq!(profiler.history().count(), 0);
+        assert_eq!(profiler.last_frame(), FrameStats::default());
+        assert_eq!(profiler.average(), FrameStats::default());
+    }
+
+    #[test]
+    fn history_is_limited() {
+        let mut profiler = Profiler::default();
+
+        for index in 0..HISTORY_LEN + 10 {
+            profiler.current = frame(index as u64 + 1, 0, 0);
+            profiler.begin_frame();
+        }
+
+        assert_eq!(profiler.history().count(), HISTORY_LEN);
+        assert_eq!(profiler.history().next().unwrap().events, Duration::from_millis(11));
+        assert_eq!(profiler.last_frame().events, Duration::from_millis(HISTORY_LEN as u64 + 10));
+    }
+
+    #[test]
+    fn average_of_history() {
+        let mut profiler = Profiler::default();
+
+        for stats in [frame(1, 4, 2), frame(3, 2, 4), frame(5, 6, 9)] {
+            profiler.current = stats;
+            profiler.begin_frame();
+        }
+
+        let average = profiler.average();
+        assert_eq!(average.events, Duration::from_millis(3));
+        assert_eq!(average.draw, Duration::from_millis(4));
+        assert_eq!(average.redrawn, 5);
+    }
+
+    #[test]
+    fn undrawn_frame_requests_overlay_redraw() {
+        let mut profiler = Profiler::default();
+
+        profiler.current = frame(1, 0, 0);
+        assert!(!profiler.begin_frame());
+
+        profiler.show_overlay = true;
+
+        profiler.current = frame(1, 0, 0);
+        assert!(profiler.begin_frame());
+
+        profiler.current = frame(1, 1, 1);
+        assert!(!profiler.begin_frame());
+    }
+}
@@ -7,7 +7,7 @@ use vizia_id::{GenerationalId, IdManager};
 
 use crate::context::ResourceContext;
 use crate::entity::Entity;
-use crate::prelude::IntoCssStr;
+use crate::prelude::{IntoCssStr, StyleDiagnostic};
 // use crate::view::Canvas;
 use fluent_bundle::{FluentBundle, FluentResource};
 use hashbrown::{HashMap, HashSet};
@@ -42,6 +42,8 @@ pub enum ImageRetentionPolicy {
 pub struct ResourceManager {
     pub themes: Vec<String>, // Themes are the string content stylesheets
     pub styles: Vec<Box<dyn IntoCssStr>>,
+    /// Problems found when the stylesheets were last parsed.
+    pub(crate) style_diagnostics: Vec<StyleDiagnostic>,
 
     pub(crate) image_id_manager: IdManager<ImageId>,
     pub(crate) images: HashMap<ImageId, StoredImage>,
@@ -117,6 +119,7 @@ impl ResourceManager {
             images,
             image_ids: HashMap::new(),
             styles: Vec::new(),
+            style_diagnostics: Vec::new(),
 
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
@@ -1,4 +1,4 @@
-use crate::animation::{AnimationState, Interpolator};
+use crate::animation::{AnimationPlayback, AnimationState, Interpolator, TimingFunction};
 use crate::prelude::*;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
 
@@ -317,6 +317,7 @@ where
         start_time: Instant,
         duration: Duration,
         delay: Duration,
+        playback: &AnimationPlayback,
     ) {
         let entity_index = entity.index();
 
@@ -370,6 +371,21 @@ where
             anim_state.id = animation;
             anim_state.delay = delay;
             anim_state.dt = delay.as_secs_f32() / duration.as_secs_f32();
+            anim_state.playback = playback.clone();
+            anim_state.persistent |= playback.fill_mode.fills_forwards();
+
+            // A spring comes to rest in its own time, which replaces the duration of the animation.
+            if let Some(spring) = anim_state
+                .keyframes
+                .first()
+                .and_then(|keyframe| keyframe.timing_function.as_ref())
+                .or(playback.timing_function.as_ref())
+                .and_then(TimingFunction::as_spring)
+                .copied()
+            {
+                anim_state.duration = spring.duration();
+                anim_state.dt = delay.as_secs_f32() / anim_state.duration.as_secs_f32();
+            }
             anim_state.output = Some(
                 self.animations
                     .get(animation)
@@ -403,14 +419,44 @@ where
         }
     }
 
+    /// Pause or resume an animation for a given entity.
+    ///
+    /// A paused animation holds its current value, and resuming it continues from the same point.
+    pub(crate) fn set_animation_paused(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        paused: bool,
+        time: Instant,
+    ) {
+        let entity_index = entity.index();
+
+        if entity_index < self.inline_data.sparse.len() {
+            let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+            if active_anim_index < self.active_animations.len() {
+                let anim_state = &mut self.active_animations[active_anim_index];
+                if anim_state.id == animation {
+                    match (paused, anim_state.paused_at) {
+                        (true, None) => anim_state.paused_at = Some(time),
+                        (false, Some(paused_at)) => {
+                            anim_state.start_time += time.duration_since(paused_at);
+                            anim_state.paused_at = None;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
     /// Tick the animation for the given time and return a list of entities which have been animated.
     pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
         self.remove_innactive_animations();
 
         if self.has_animations() {
             for state in self.active_animations.iter_mut() {
-                // If the animation is already finished then skip
-                if state.t == 1.0 {
+                // If the animation is already finished or is paused then skip
+                if state.t == 1.0 || state.paused_at.is_some() {
                     continue;
                 }
 
@@ -420,13 +466,41 @@ where
                 }
 
                 let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
+                let normalised_time =
                     (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
 
-                normalised_time = normalised_time.clamp(0.0, 1.0);
+                // Without a backwards fill the animation has no effect until its delay has passed
+                if normalised_time < 0.0 && !state.playback.fill_mode.fills_backwards() {
+                    state.output = None;
+                    continue;
+                }
+
+                let iterations = state.playback.iterations;
+                let progress = normalised_time.clamp(0.0, iterations);
+                let finished = normalised_time >= iterations;
+
+                // An animation which ends on a whole number of iterations holds the end of its
+                // last iteration rather than the start of the next one.
+                let (iteration, mut iteration_time) =
+                    if finished && progress > 0.0 && progress.fract() == 0.0 {
+                        (progress - 1.0, 1.0)
+                    } else {
+                        (progress.floor(), progress.fract())
+                    };
+
+                let reversed = match state.playback.direction {
+                    AnimationDirection::Normal => false,
+                    AnimationDirection::Reverse => true,
+                    AnimationDirection::Alternate => iteration % 2.0 == 1.0,
+                    AnimationDirection::AlternateReverse => iteration % 2.0 == 0.0,
+                };
+
+                if reversed {
+                    iteration_time = 1.0 - iteration_time;
+                }
 
                 let mut i = 0;
-                while i < state.keyframes.len() - 1 && state.keyframes[i + 1].time < normalised_time
+                while i < state.keyframes.len() - 2 && state.keyframes[i + 1].time < iteration_time
                 {
                     i += 1;
                 }
@@ -434,11 +508,17 @@ where
                 let end = &state.keyframes[i + 1];
 
                 let normalised_elapsed_time =
-                    (normalised_time - start.time) / (end.time - start.time);
+                    ((iteration_time - start.time) / (end.time - start.time)).clamp(0.0, 1.0);
 
-                state.t = normalised_time;
+                state.t = if finished { 1.0 } else { progress / iterations };
 
-                let timing_t = start.timing_function.value(normalised_elapsed_time);
+                let timing_t = start
+                    .timing_function
+                    .as_ref()
+                    .or(state.playback.timing_function.as_ref())
+                    .map_or(normalised_elapsed_time, |timing_function| {
+                        timing_function.value(normalised_elapsed_time)
+                    });
                 state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
             }
 
@@ -470,13 +550,13 @@ where
         let inactive: Vec<AnimationState<T>> = self
             .active_animations
             .iter()
-            .filter(|e| e.t == 1.0 && !e.persistent)
+            .filter(|e| (e.t == 1.0 && !e.persistent) || e.entities.is_empty())
             .cloned()
             .collect();
 
         // Remove inactive animation states from active animations list
-        // Retains persistent animations
-        self.active_animations.retain(|e| e.t < 1.0 || e.persistent);
+        // Retains persistent animations which are still linked to an entity
+        self.active_animations.retain(|e| (e.t < 1.0 || e.persistent) && !e.entities.is_empty());
 
         for state in inactive.into_iter() {
             for entity in state.entities.iter() {
@@ -494,7 +574,7 @@ where
     /// Returns true if there are any active animations.
     pub fn has_animations(&self) -> bool {
         for state in self.active_animations.iter() {
-            if state.t < 1.0 {
+            if state.t < 1.0 && state.paused_at.is_none() {
                 return true;
             }
         }
@@ -543,10 +623,10 @@ where
         None
     }
 
-    // /// Returns a reference to any shared data for a given rule if it exists.
-    // pub(crate) fn get_shared(&self, rule: Rule) -> Option<&T> {
-    //     self.shared_data.get(rule)
-    // }
+    /// Returns a reference to any shared data for a given rule if it exists.
+    pub(crate) fn get_shared(&self, rule: Rule) -> Option<&T> {
+        self.shared_data.get(rule)
+    }
 
     // /// Returns a mutable reference to any shared data for a given rule if it exists.
     // pub(crate) fn get_shared_mut(&mut self, rule: Rule) -> Option<&mut T> {
@@ -587,7 +667,9 @@ where
             let animation_index = self.inline_data.sparse[entity_index].anim_index as usize;
 
             if animation_index < self.active_animations.len() {
-                return self.active_animations[animation_index].get_output();
+                if let Some(output) = self.active_animations[animation_index].get_output() {
+                    return Some(output);
+                }
             }
 
             let data_index = self.inline_data.sparse[entity_index].data_index;
@@ -638,7 +720,51 @@ where
                     if current_anim_state.is_transition() {
                         // Skip if the transition hasn't changed
                         if current_anim_state.to_rule != rule_data_index {
-                            if rule_data_index == current_anim_state.from_rule {
+                            let spring = current_anim_state
+                                .keyframes
+                                .first()
+                                .and_then(|keyframe| keyframe.timing_function.as_ref())
+                                .and_then(TimingFunction::as_spring)
+                                .copied();
+
+                            if let Some(spring) = spring {
+                                // Springs are retargeted from the current value, keeping their
+                                // current speed so that the motion stays continuous.
+                                let time = current_anim_state.t * spring.duration().as_secs_f32();
+                                let progress = spring.progress(time);
+                                let velocity = spring.velocity(time);
+
+                                let velocity = if rule_data_index == current_anim_state.from_rule {
+                                    // Heading back to the start, which is `progress` of the old
+                                    // distance away in the opposite direction.
+                                    if progress.abs() > f32::EPSILON {
+                                        -velocity / progress
+                                    } else {
+                                        0.0
+                                    }
+                                } else if (1.0 - progress).abs() > f32::EPSILON {
+                                    // Assume the new end value is about as far away as the old one.
+                                    velocity / (1.0 - progress)
+                                } else {
+                                    0.0
+                                };
+
+                                let spring = spring.with_velocity(velocity);
+
+                                current_anim_state.from_rule = current_anim_state.to_rule;
+                                current_anim_state.to_rule = rule_data_index;
+                                current_anim_state.keyframes.first_mut().unwrap().value =
+                                    current_value;
+                                current_anim_state.keyframes.last_mut().unwrap().value =
+                                    self.shared_data.dense[rule_data_index].value.clone();
+                                for keyframe in current_anim_state.keyframes.iter_mut() {
+                                    keyframe.timing_function = Some(TimingFunction::Spring(spring));
+                                }
+                                current_anim_state.duration = spring.duration();
+                                current_anim_state.t = 0.0;
+                                current_anim_state.dt = 0.0;
+                                current_anim_state.start_time = Instant::now();
+                            } else if rule_data_index == current_anim_state.from_rule {
                                 // Transitioning back to previous rule
                                 current_anim_state.from_rule = current_anim_state.to_rule;
                                 current_anim_state.to_rule = rule_data_index;
@@ -701,6 +827,7 @@ where
                             Instant::now(),
                             duration,
                             delay,
+                            &AnimationPlayback::default(),
                         );
                     }
                     //}
@@ -757,6 +884,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation::Keyframe;
 
     // DataIndex tests
 
@@ -825,4 +953,165 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    fn storage_with_animation(animation: Animation) -> AnimatableSet<f32> {
+        let mut animatable_storage = AnimatableSet::default();
+        animatable_storage.insert(Entity::root(), 5.0);
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(Keyframe {
+                    time: 0.0,
+                    value: 0.0,
+                    timing_function: Some(TimingFunction::linear()),
+                })
+                .with_keyframe(Keyframe {
+                    time: 1.0,
+                    value: 10.0,
+                    timing_function: Some(TimingFunction::linear()),
+                }),
+        );
+        animatable_storage
+    }
+
+    /// Test that an alternating animation plays every other iteration backwards and ends at the
+    /// end of its last iteration when filling forwards.
+    #[test]
+    fn alternate_iterations() {
+        let animation = Animation::new(0, 0);
+        let mut animatable_storage = storage_with_animation(animation);
+        let start_time = Instant::now();
+        let playback = AnimationPlayback {
+            iterations: 2.0,
+            direction: AnimationDirection::Alternate,
+            fill_mode: AnimationFillMode::Forwards,
+            timing_function: None,
+        };
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            start_time,
+            Duration::from_secs(1),
+            Duration::ZERO,
+            &playback,
+        );
+
+        animatable_storage.tick(start_time + Duration::from_millis(250));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&2.5));
+
+        animatable_storage.tick(start_time + Duration::from_millis(1250));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&7.5));
+
+        animatable_storage.tick(start_time + Duration::from_secs(3));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&0.0));
+
+        animatable_storage.tick(start_time + Duration::from_secs(4));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&0.0));
+    }
+
+    /// Test that an animation without a backwards fill leaves the value untouched during its delay.
+    #[test]
+    fn delay_without_fill() {
+        let animation = Animation::new(0, 0);
+        let mut animatable_storage = storage_with_animation(animation);
+        let start_time = Instant::now();
+        let playback =
+            AnimationPlayback { fill_mode: AnimationFillMode::None, ..Default::default() };
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            start_time,
+            Duration::from_secs(1),
+            Duration::from_secs(1),
+            &playback,
+        );
+
+        animatable_storage.tick(start_time + Duration::from_millis(500));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&5.0));
+
+        animatable_storage.tick(start_time + Duration::from_millis(1500));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&5.0));
+    }
+
+    /// Test that a paused animation holds its value and continues from the same point when resumed.
+    #[test]
+    fn pause_and_resume() {
+        let animation = Animation::new(0, 0);
+        let mut animatable_storage = storage_with_animation(animation);
+        let start_time = Instant::now();
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            start_time,
+            Duration::from_secs(1),
+            Duration::ZERO,
+            &AnimationPlayback::default(),
+        );
+
+        animatable_storage.tick(start_time + Duration::from_millis(200));
+        animatable_storage.set_animation_paused(
+            Entity::root(),
+            animation,
+            true,
+            start_time + Duration::from_millis(200),
+        );
+        assert!(!animatable_storage.has_animations());
+
+        animatable_storage.set_animation_paused(
+            Entity::root(),
+            animation,
+            false,
+            start_time + Duration::from_secs(5),
+        );
+        animatable_storage.tick(start_time + Duration::from_millis(5300));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&5.0));
+    }
+
+    /// Test that a spring transition which is sent back to its starting rule keeps moving in the
+    /// same direction for a moment rather than restarting from rest.
+    #[test]
+    fn spring_transition_retarget_keeps_velocity() {
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let (from, to) = (Rule::new(0, 0), Rule::new(1, 0));
+        animatable_storage.insert_rule(from, 0.0);
+        animatable_storage.insert_rule(to, 10.0);
+
+        let spring = TimingFunction::spring(170.0, 26.0, 1.0);
+        for (index, rule) in [from, to].into_iter().enumerate() {
+            let animation = Animation::new(index as u64, 0);
+            animatable_storage.insert_animation(
+                animation,
+                AnimationState::new(Animation::null())
+                    .with_keyframe(Keyframe {
+                        time: 0.0,
+                        value: 0.0,
+                        timing_function: Some(spring.clone()),
+                    })
+                    .with_keyframe(Keyframe {
+                        time: 1.0,
+                        value: 0.0,
+                        timing_function: Some(spring.clone()),
+                    }),
+            );
+            animatable_storage.insert_transition(rule, animation);
+        }
+
+        let entity = Entity::root();
+        animatable_storage.link(entity, &[(from, 0)]);
+        animatable_storage.link(entity, &[(to, 0)]);
+        animatable_storage.tick(Instant::now() + Duration::from_millis(100));
+        let midway = *animatable_storage.get(entity).unwrap();
+        assert!(midway > 0.0 && midway < 10.0);
+
+        animatable_storage.link(entity, &[(from, 0)]);
+        let state = animatable_storage.get_active_animation(entity).unwrap();
+        assert_eq!(state.keyframes.first().unwrap().value, midway);
+        assert_eq!(state.keyframes.last().unwrap().value, 0.0);
+        let retargeted =
+            state.keyframes[0].timing_function.as_ref().and_then(TimingFunction::as_spring);
+        assert!(retargeted.unwrap().velocity(0.0) < 0.0);
+
+        animatable_storage.tick(Instant::now() + Duration::from_millis(10));
+        assert!(*animatable_storage.get(entity).unwrap() > midway);
+    }
 }
@@ -282,10 +282,10 @@ where
         None
     }
 
-    // /// Returns a reference to any shared data for a given rule if it exists.
-    // pub(crate) fn get_shared(&self, rule: Rule) -> Option<&T> {
-    //     self.shared_data.get(rule)
-    // }
+    /// Returns a reference to any shared data for a given rule if it exists.
+    pub(crate) fn get_shared(&self, rule: Rule) -> Option<&T> {
+        self.shared_data.get(rule)
+    }
 
     // /// Returns a mutable reference to any shared data for a given rule if it exists.
     // pub(crate) fn get_shared_mut(&mut self, rule: Rule) -> Option<&mut T> {
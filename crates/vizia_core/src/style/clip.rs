@@ -0,0 +1,99 @@
+use skia_safe::{Matrix, Path, PathFillType, Point, Rect};
+use std::f32::consts::SQRT_2;
+use vizia_style::{ClipPath, FillRule, ShapeRadius};
+
+use crate::layout::BoundingBox;
+
+/// Trait for converting a clip shape definition into a `Path`.
+pub(crate) trait IntoClipShape {
+    /// Returns the clip shape within the given bounds, or `None` if the clip is not a shape.
+    fn as_clip_shape(&self, bounds: BoundingBox, scale_factor: f32) -> Option<Path>;
+}
+
+impl IntoClipShape for ClipPath {
+    fn as_clip_shape(&self, bounds: BoundingBox, scale_factor: f32) -> Option<Path> {
+        let mut path = Path::new();
+
+        match self {
+            ClipPath::Auto | ClipPath::Shape(_) => return None,
+
+            ClipPath::Circle(radius, center) => {
+                let cx =
+                    bounds.x + center.x.to_length_or_percentage().to_pixels(bounds.w, scale_factor);
+                let cy =
+                    bounds.y + center.y.to_length_or_percentage().to_pixels(bounds.h, scale_factor);
+
+                // Percentage radii resolve against the normalized diagonal of the bounds.
+                let diagonal = (bounds.w * bounds.w + bounds.h * bounds.h).sqrt() / SQRT_2;
+                let sides =
+                    [cx - bounds.x, bounds.right() - cx, cy - bounds.y, bounds.bottom() - cy];
+                let r = shape_radius(radius, diagonal, &sides, scale_factor);
+
+                path.add_circle((cx, cy), r, None);
+            }
+
+            ClipPath::Ellipse(radius_x, radius_y, center) => {
+                let cx =
+                    bounds.x + center.x.to_length_or_percentage().to_pixels(bounds.w, scale_factor);
+                let cy =
+                    bounds.y + center.y.to_length_or_percentage().to_pixels(bounds.h, scale_factor);
+
+                let rx = shape_radius(
+                    radius_x,
+                    bounds.w,
+                    &[cx - bounds.x, bounds.right() - cx],
+                    scale_factor,
+                );
+                let ry = shape_radius(
+                    radius_y,
+                    bounds.h,
+                    &[cy - bounds.y, bounds.bottom() - cy],
+                    scale_factor,
+                );
+
+                path.add_oval(Rect::new(cx - rx, cy - ry, cx + rx, cy + ry), None);
+            }
+
+            ClipPath::Polygon(fill_rule, points) => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| {
+                        Point::new(
+                            bounds.x + x.to_pixels(bounds.w, scale_factor),
+                            bounds.y + y.to_pixels(bounds.h, scale_factor),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                path.add_poly(&points, true);
+                path.set_fill_type(fill_type(*fill_rule));
+            }
+
+            ClipPath::Path(fill_rule, svg) => {
+                path = Path::from_svg(svg)?;
+                path.transform(&Matrix::scale((scale_factor, scale_factor)));
+                path.offset(bounds.top_left());
+                path.set_fill_type(fill_type(*fill_rule));
+            }
+        }
+
+        Some(path)
+    }
+}
+
+/// Resolves the radius of a circle or ellipse given the distances from its center to the sides
+/// of the bounds.
+fn shape_radius(radius: &ShapeRadius, reference: f32, sides: &[f32], scale_factor: f32) -> f32 {
+    match radius {
+        ShapeRadius::Length(length) => length.to_pixels(reference, scale_factor),
+        ShapeRadius::ClosestSide => sides.iter().copied().fold(f32::MAX, f32::min).max(0.0),
+        ShapeRadius::FarthestSide => sides.iter().copied().fold(0.0, f32::max),
+    }
+}
+
+fn fill_type(fill_rule: FillRule) -> PathFillType {
+    match fill_rule {
+        FillRule::NonZero => PathFillType::Winding,
+        FillRule::EvenOdd => PathFillType::EvenOdd,
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+use vizia_style::{CustomParseError, Error};
+
+/// A problem found while parsing a stylesheet, such as an invalid property value or an unknown property.
+///
+/// The diagnostics for the stylesheets added to an application can be retrieved with
+/// [`Context::stylesheet_diagnostics`](crate::context::Context::stylesheet_diagnostics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleDiagnostic {
+    /// The name of the stylesheet, such as the path of the file it was loaded from.
+    pub source: String,
+    /// The line of the problem, starting at 1.
+    pub line: u32,
+    /// The column of the problem, starting at 1.
+    pub column: u32,
+    /// The name of the property which could not be parsed, if any.
+    pub property: Option<String>,
+    /// A description of the values accepted by the property, if the property is known.
+    pub expected: Option<String>,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl StyleDiagnostic {
+    pub(crate) fn new(source: &str, error: &Error<CustomParseError<'_>>) -> Self {
+        let (line, column) =
+            error.location.as_ref().map(|loc| (loc.line + 1, loc.column)).unwrap_or((1, 1));
+
+        let (property, expected) = match &error.kind {
+            CustomParseError::InvalidPropertyValue { property, expected } => {
+                (Some(property.to_string()), Some(expected.clone()))
+            }
+            CustomParseError::UnknownProperty(property) => (Some(property.to_string()), None),
+            _ => (None, None),
+        };
+
+        Self {
+            source: source.to_owned(),
+            line,
+            column,
+            property,
+            expected,
+            message: error.kind.to_string(),
+        }
+    }
+
+    /// Creates a diagnostic for a font declared by a `@font-face` rule which could not be loaded.
+    pub(crate) fn font_face(source: &str, line: u32, column: u32, message: String) -> Self {
+        Self { source: source.to_owned(), line, column, property: None, expected: None, message }
+    }
+
+    /// Creates a diagnostic for a stylesheet which could not be read.
+    pub(crate) fn unreadable(source: &str, error: &std::io::Error) -> Self {
+        Self {
+            source: source.to_owned(),
+            line: 1,
+            column: 1,
+            property: None,
+            expected: None,
+            message: format!("Failed to read stylesheet: {}", error),
+        }
+    }
+}
+
+impl fmt::Display for StyleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}: {}", self.source, self.line, self.column, self.message)
+    }
+}
@@ -65,25 +65,32 @@ use indexmap::IndexMap;
 use log::warn;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut, Range};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
 use vizia_style::selectors::parser::{AncestorHashes, Selector};
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Alignment, Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color,
-    CornerShape, CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient,
-    HorizontalPosition, HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue,
-    LineClamp, LineDirection, LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position,
-    PositionType, Scale, Shadow, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow,
-    TextStroke, TextStrokeStyle, Transform, Transition, Translate, VerticalPosition,
+    Alignment, Angle, AnimationDirection, AnimationFillMode, AnimationName, AnimationPlayState,
+    BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color, Content, CornerShape,
+    CssRule, CursorIcon, Direction, Display, EasingFunction, FillRule, Filter, FontFamily,
+    FontFeature, FontSize, FontSlant, FontVariation, FontWeight, FontWeightKeyword, FontWidth,
+    GenericFontFamily, Gradient, HorizontalPosition, HorizontalPositionKeyword, IterationCount,
+    Length, LengthOrPercentage, LengthValue, LineClamp, LineDirection, LineHeight, LinearGradient,
+    Matrix, Opacity, Overflow, PointerEvents, Position, PositionType, Scale, Shadow, ShapeRadius,
+    Spacing, StepPosition, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow,
+    TextStroke, TextStrokeStyle, TextTransform, Transform, Transition, Translate, VerticalPosition,
     VerticalPositionKeyword, Visibility, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, Selectors, StyleSheet,
+    BlendMode, KeyframeSelector, ParserOptions, Property, PseudoElement, Selectors, StyleSheet,
 };
 
+mod diagnostic;
+pub use diagnostic::StyleDiagnostic;
+
 mod rule;
 pub(crate) use rule::Rule;
 
@@ -93,12 +100,17 @@ pub(crate) use pseudoclass::*;
 mod transform;
 pub(crate) use transform::*;
 
-use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
+mod clip;
+pub(crate) use clip::*;
+
+use crate::animation::{AnimationPlayback, AnimationState, Interpolator, Keyframe, TimingFunction};
 use crate::storage::animatable_set::AnimatableSet;
 use crate::storage::style_set::StyleSet;
+use crate::text::{FontFace, FontFaceDeclaration};
 use bitflags::bitflags;
+use unic_langid::CharacterDirection;
 use vizia_id::IdManager;
-use vizia_storage::SparseSet;
+use vizia_storage::{LayoutParentIterator, SparseSet};
 
 bitflags! {
     /// Describes the capabilities of a view with respect to user interaction.
@@ -198,15 +210,42 @@ pub(crate) struct StyleRule {
     pub(crate) selector: Selector<Selectors>,
     /// The ancestor hashes associated with the selector.
     pub(crate) hashes: AncestorHashes,
+    /// The name of the stylesheet the rule was parsed from.
+    pub(crate) source: Arc<str>,
 }
 
 impl StyleRule {
-    pub(crate) fn new(selector: Selector<Selectors>) -> Self {
+    pub(crate) fn new(selector: Selector<Selectors>, source: Arc<str>) -> Self {
         let hashes = AncestorHashes::new(&selector, vizia_style::QuirksMode::NoQuirks);
-        Self { selector, hashes }
+        Self { selector, hashes, source }
+    }
+}
+
+/// The style rules matched by the `::before`, `::after` and `::placeholder` pseudo-elements of an
+/// entity, each sorted from the most to the least specific.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct PseudoElementRules {
+    pub(crate) before: Vec<Rule>,
+    pub(crate) after: Vec<Rule>,
+    pub(crate) placeholder: Vec<Rule>,
+}
+
+impl PseudoElementRules {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty() && self.placeholder.is_empty()
     }
 }
 
+/// The keyframe animation an entity is playing because of its `animation-*` properties.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DeclaredAnimation {
+    animation: Animation,
+    duration: Duration,
+    delay: Duration,
+    playback: AnimationPlayback,
+    paused: bool,
+}
+
 /// Stores the style properties of all entities in the application.
 #[derive(Default)]
 pub struct Style {
@@ -215,11 +254,16 @@ pub struct Style {
     // Creates and destroys animation ids
     pub(crate) animation_manager: IdManager<Animation>,
     pub(crate) animations: HashMap<String, Animation>,
+    // Fonts declared by `@font-face` rules, which are loaded when the stylesheets are reloaded.
+    pub(crate) font_faces: Vec<FontFaceDeclaration>,
     // List of animations to be started on the next frame
     pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, StyleRule>,
+    // Rules with a `::before`, `::after` or `::placeholder` pseudo-element, which are matched
+    // separately from the rules of entities.
+    pub(crate) pseudo_element_rules: Vec<Rule>,
 
     pub(crate) default_font: Vec<FamilyOwned>,
 
@@ -228,6 +272,11 @@ pub struct Style {
     pub(crate) ids: SparseSet<String>,
     pub(crate) classes: SparseSet<HashSet<String>>,
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
+    pub(crate) custom_states: SparseSet<HashSet<String>>,
+    pub(crate) attributes: SparseSet<HashMap<String, String>>,
+    pub(crate) locale: LanguageIdentifier,
+    pub(crate) language: SparseSet<LanguageIdentifier>,
+    pub(crate) direction: SparseSet<Direction>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
 
@@ -251,16 +300,30 @@ pub struct Style {
 
     // Clipping
     pub(crate) clip_path: AnimatableSet<ClipPath>,
+    pub(crate) mask_image: StyleSet<Vec<ImageOrGradient>>,
 
     // Overflow
     pub(crate) overflowx: StyleSet<Overflow>,
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
-    pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) filter: AnimatableSet<Vec<Filter>>,
+    pub(crate) backdrop_filter: AnimatableSet<Vec<Filter>>,
 
     pub(crate) blend_mode: StyleSet<BlendMode>,
 
+    // Animations
+    pub(crate) animation_name: StyleSet<AnimationName>,
+    pub(crate) animation_duration: StyleSet<Duration>,
+    pub(crate) animation_delay: StyleSet<Duration>,
+    pub(crate) animation_timing_function: StyleSet<EasingFunction>,
+    pub(crate) animation_iteration_count: StyleSet<IterationCount>,
+    pub(crate) animation_direction: StyleSet<AnimationDirection>,
+    pub(crate) animation_fill_mode: StyleSet<AnimationFillMode>,
+    pub(crate) animation_play_state: StyleSet<AnimationPlayState>,
+    // The keyframe animation each entity is playing because of its animation properties
+    pub(crate) declared_animations: SparseSet<DeclaredAnimation>,
+
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
     pub(crate) transform_origin: AnimatableSet<Translate>,
@@ -268,10 +331,24 @@ pub struct Style {
     pub(crate) rotate: AnimatableSet<Angle>,
     pub(crate) scale: AnimatableSet<Scale>,
 
-    // Border
-    pub(crate) border_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) border_color: AnimatableSet<Color>,
-    pub(crate) border_style: StyleSet<BorderStyleKeyword>,
+    // Border Width
+    pub(crate) border_top_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_right_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_bottom_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_left_width: AnimatableSet<LengthOrPercentage>,
+
+    // Border Color
+    pub(crate) border_top_color: AnimatableSet<Color>,
+    pub(crate) border_right_color: AnimatableSet<Color>,
+    pub(crate) border_bottom_color: AnimatableSet<Color>,
+    pub(crate) border_left_color: AnimatableSet<Color>,
+    pub(crate) border_gradient: StyleSet<Gradient>,
+
+    // Border Style
+    pub(crate) border_top_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_right_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_bottom_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_left_style: StyleSet<BorderStyleKeyword>,
 
     // Corner Shape
     pub(crate) corner_top_left_shape: StyleSet<CornerShape>,
@@ -321,16 +398,28 @@ pub struct Style {
     pub(crate) strikethrough_color: AnimatableSet<Color>,
     pub(crate) font_family: StyleSet<Vec<FamilyOwned>>,
     pub(crate) font_color: AnimatableSet<Color>,
+    pub(crate) font_gradient: StyleSet<Gradient>,
     pub(crate) font_size: AnimatableSet<FontSize>,
     pub(crate) font_weight: StyleSet<FontWeight>,
     pub(crate) font_slant: StyleSet<FontSlant>,
     pub(crate) font_width: StyleSet<FontWidth>,
     pub(crate) font_variation_settings: StyleSet<Vec<FontVariation>>,
+    pub(crate) font_feature_settings: StyleSet<Vec<FontFeature>>,
+    pub(crate) line_height: AnimatableSet<LineHeight>,
+    pub(crate) letter_spacing: AnimatableSet<Spacing>,
+    pub(crate) word_spacing: AnimatableSet<Spacing>,
+    pub(crate) text_shadow: AnimatableSet<Vec<Shadow>>,
+    pub(crate) text_transform: StyleSet<TextTransform>,
     pub(crate) caret_color: AnimatableSet<Color>,
     pub(crate) selection_color: AnimatableSet<Color>,
+    pub(crate) selection_text_color: AnimatableSet<Color>,
 
     pub(crate) fill: AnimatableSet<Color>,
 
+    // Pseudo-elements
+    pub(crate) content: StyleSet<Content>,
+    pub(crate) pseudo_elements: SparseSet<PseudoElementRules>,
+
     // cursor Icon
     pub(crate) cursor: StyleSet<CursorIcon>,
 
@@ -399,8 +488,11 @@ pub struct Style {
     pub(crate) text_layout: Bloom,
     pub(crate) reaccess: Bloom,
 
+    // The range of the text of a span within the paragraph of its parent, in UTF-16 code units.
     pub(crate) text_range: SparseSet<Range<usize>>,
     pub(crate) text_span: SparseSet<bool>,
+    // The character displayed in place of each grapheme of masked text, such as a password.
+    pub(crate) text_mask: SparseSet<char>,
 
     /// This includes both the system's HiDPI scaling factor as well as `cx.user_scale_factor`.
     pub(crate) dpi_factor: f64,
@@ -441,9 +533,10 @@ impl Style {
             storage: &mut AnimatableSet<T>,
             animation_id: Animation,
             time: f32,
+            timing_function: Option<&TimingFunction>,
             value: T,
         ) {
-            let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
+            let keyframe = Keyframe { time, value, timing_function: timing_function.cloned() };
 
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
                 anim_state.keyframes.push(keyframe)
@@ -453,57 +546,260 @@ impl Style {
             }
         }
 
+        // The timing function of a keyframe applies until the next keyframe.
+        let timing_function = properties.iter().find_map(|property| match property {
+            Property::AnimationTimingFunction(easing) => Some(TimingFunction::from(easing.clone())),
+            _ => None,
+        });
+
         for property in properties.iter() {
             match property {
                 // DISPLAY
                 Property::Display(value) => {
-                    insert_keyframe(&mut self.display, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.display,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Opacity(value) => {
-                    insert_keyframe(&mut self.opacity, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.opacity,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::ClipPath(value) => {
-                    insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.clip_path,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
+                }
+
+                Property::Filter(value) => {
+                    insert_keyframe(
+                        &mut self.filter,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
+                }
+
+                Property::BackdropFilter(value) => {
+                    insert_keyframe(
+                        &mut self.backdrop_filter,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 // TRANSFORM
                 Property::Transform(value) => {
-                    insert_keyframe(&mut self.transform, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.transform,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 Property::TransformOrigin(transform_origin) => {
                     let x = transform_origin.x.to_length_or_percentage();
                     let y = transform_origin.y.to_length_or_percentage();
                     let value = Translate { x, y };
-                    insert_keyframe(&mut self.transform_origin, animation_id, time, value);
+                    insert_keyframe(
+                        &mut self.transform_origin,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value,
+                    );
                 }
 
                 Property::Translate(value) => {
-                    insert_keyframe(&mut self.translate, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.translate,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 Property::Rotate(value) => {
-                    insert_keyframe(&mut self.rotate, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.rotate,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Scale(value) => {
-                    insert_keyframe(&mut self.scale, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.scale,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // BORDER
                 Property::BorderWidth(value) => {
                     insert_keyframe(
-                        &mut self.border_width,
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.top.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.right.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.bottom.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_width,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.left.0.clone(),
                     );
                 }
 
+                Property::BorderTopWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderRightWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderBottomWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderLeftWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.0.clone(),
+                    );
+                }
+
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.top,
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.right,
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.bottom,
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.left,
+                    );
+                }
+
+                Property::BorderTopColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                }
+
+                Property::BorderRightColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                }
+
+                Property::BorderBottomColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                }
+
+                Property::BorderLeftColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::CornerTopLeftRadius(value) => {
@@ -511,6 +807,7 @@ impl Style {
                         &mut self.corner_top_left_radius,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.clone(),
                     );
                 }
@@ -520,6 +817,7 @@ impl Style {
                         &mut self.corner_top_right_radius,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.clone(),
                     );
                 }
@@ -529,6 +827,7 @@ impl Style {
                         &mut self.corner_bottom_left_radius,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.clone(),
                     );
                 }
@@ -538,6 +837,7 @@ impl Style {
                         &mut self.corner_bottom_right_radius,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.clone(),
                     );
                 }
@@ -548,21 +848,40 @@ impl Style {
                         &mut self.outline_width,
                         animation_id,
                         time,
+                        timing_function.as_ref(),
                         value.left.0.clone(),
                     );
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.outline_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::OutlineOffset(value) => {
-                    insert_keyframe(&mut self.outline_offset, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.outline_offset,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 // BACKGROUND
                 Property::BackgroundColor(value) => {
-                    insert_keyframe(&mut self.background_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.background_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::BackgroundImage(images) => {
@@ -578,141 +897,391 @@ impl Style {
                             }
                         })
                         .collect::<Vec<_>>();
-                    insert_keyframe(&mut self.background_image, animation_id, time, images);
+                    insert_keyframe(
+                        &mut self.background_image,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        images,
+                    );
                 }
 
                 Property::BackgroundSize(value) => {
-                    insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.background_size,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 // BOX SHADOW
                 Property::Shadow(value) => {
-                    insert_keyframe(&mut self.shadow, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.shadow,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::FontSize(value) => {
-                    insert_keyframe(&mut self.font_size, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_size,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                }
+
+                Property::LineHeight(value) => {
+                    insert_keyframe(
+                        &mut self.line_height,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
+                }
+
+                Property::LetterSpacing(value) => {
+                    insert_keyframe(
+                        &mut self.letter_spacing,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
+                }
+
+                Property::WordSpacing(value) => {
+                    insert_keyframe(
+                        &mut self.word_spacing,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
+                }
+
+                Property::TextShadow(value) => {
+                    insert_keyframe(
+                        &mut self.text_shadow,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        value.clone(),
+                    );
                 }
 
                 Property::CaretColor(value) => {
-                    insert_keyframe(&mut self.caret_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.caret_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::SelectionColor(value) => {
-                    insert_keyframe(&mut self.selection_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.selection_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // SPACE
                 Property::Left(value) => {
-                    insert_keyframe(&mut self.left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.left,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Right(value) => {
-                    insert_keyframe(&mut self.right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.right,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Top(value) => {
-                    insert_keyframe(&mut self.top, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.top,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Bottom(value) => {
-                    insert_keyframe(&mut self.bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.bottom,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // Padding
                 Property::PaddingLeft(value) => {
-                    insert_keyframe(&mut self.padding_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_left,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::PaddingRight(value) => {
-                    insert_keyframe(&mut self.padding_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_right,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::PaddingTop(value) => {
-                    insert_keyframe(&mut self.padding_top, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_top,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::PaddingBottom(value) => {
-                    insert_keyframe(&mut self.padding_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_bottom,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::HorizontalGap(value) => {
-                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::VerticalGap(value) => {
-                    insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Gap(value) => {
-                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                    insert_keyframe(
+                        &mut self.vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // GAP CONSSTRAINTS
                 Property::MinGap(value) => {
-                    insert_keyframe(&mut self.min_horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.min_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                    insert_keyframe(
+                        &mut self.min_vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MaxGap(value) => {
-                    insert_keyframe(&mut self.max_horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.max_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
+                    insert_keyframe(
+                        &mut self.max_vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MinHorizontalGap(value) => {
-                    insert_keyframe(&mut self.min_horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MaxHorizontalGap(value) => {
-                    insert_keyframe(&mut self.max_horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_horizontal_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MinVerticalGap(value) => {
-                    insert_keyframe(&mut self.min_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MaxVerticalGap(value) => {
-                    insert_keyframe(&mut self.max_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_vertical_gap,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // SIZE
                 Property::Width(value) => {
-                    insert_keyframe(&mut self.width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Height(value) => {
-                    insert_keyframe(&mut self.height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.height,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 // SIZE CONSTRAINTS
                 Property::MinWidth(value) => {
-                    insert_keyframe(&mut self.min_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MaxWidth(value) => {
-                    insert_keyframe(&mut self.max_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_width,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MinHeight(value) => {
-                    insert_keyframe(&mut self.min_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_height,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::MaxHeight(value) => {
-                    insert_keyframe(&mut self.max_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_height,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::UnderlineColor(value) => {
-                    insert_keyframe(&mut self.underline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.underline_color,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 Property::Fill(value) => {
-                    insert_keyframe(&mut self.fill, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.fill,
+                        animation_id,
+                        time,
+                        timing_function.as_ref(),
+                        *value,
+                    );
                 }
 
                 _ => {}
@@ -745,7 +1314,14 @@ impl Style {
         let pending_animations = self.pending_animations.drain(..).collect::<Vec<_>>();
 
         for (entity, animation, duration, delay) in pending_animations {
-            self.play_animation(entity, animation, start_time + delay, duration, delay)
+            self.play_animation(
+                entity,
+                animation,
+                start_time + delay,
+                duration,
+                delay,
+                &AnimationPlayback::default(),
+            )
         }
     }
 
@@ -756,83 +1332,342 @@ impl Style {
         start_time: Instant,
         duration: Duration,
         delay: Duration,
+        playback: &AnimationPlayback,
     ) {
-        self.display.play_animation(entity, animation, start_time, duration, delay);
-        self.opacity.play_animation(entity, animation, start_time, duration, delay);
-        self.clip_path.play_animation(entity, animation, start_time, duration, delay);
-
-        self.transform.play_animation(entity, animation, start_time, duration, delay);
-        self.transform_origin.play_animation(entity, animation, start_time, duration, delay);
-        self.translate.play_animation(entity, animation, start_time, duration, delay);
-        self.rotate.play_animation(entity, animation, start_time, duration, delay);
-        self.scale.play_animation(entity, animation, start_time, duration, delay);
-
-        self.border_width.play_animation(entity, animation, start_time, duration, delay);
-        self.border_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.corner_top_left_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_top_right_radius.play_animation(entity, animation, start_time, duration, delay);
+        self.display.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.opacity.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.clip_path.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.filter.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.backdrop_filter
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.transform.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.transform_origin
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.translate.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.rotate.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.scale.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.border_top_width
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_right_width
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_bottom_width
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_left_width
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_top_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_right_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_bottom_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.border_left_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.corner_top_left_radius
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.corner_top_right_radius
+            .play_animation(entity, animation, start_time, duration, delay, playback);
         self.corner_bottom_left_radius
-            .play_animation(entity, animation, start_time, duration, delay);
+            .play_animation(entity, animation, start_time, duration, delay, playback);
         self.corner_bottom_right_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-
-        self.outline_width.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_color.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_offset.play_animation(entity, animation, start_time, duration, delay);
-
-        self.background_color.play_animation(entity, animation, start_time, duration, delay);
-        self.background_image.play_animation(entity, animation, start_time, duration, delay);
-        self.background_size.play_animation(entity, animation, start_time, duration, delay);
-
-        self.shadow.play_animation(entity, animation, start_time, duration, delay);
-
-        self.font_color.play_animation(entity, animation, start_time, duration, delay);
-        self.font_size.play_animation(entity, animation, start_time, duration, delay);
-        self.caret_color.play_animation(entity, animation, start_time, duration, delay);
-        self.selection_color.play_animation(entity, animation, start_time, duration, delay);
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.outline_width.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.outline_color.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.outline_offset
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.background_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.background_image
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.background_size
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.shadow.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.font_color.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.font_size.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.line_height.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.letter_spacing
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.word_spacing.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.text_shadow.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.caret_color.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.selection_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.selection_text_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.left.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.right.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.top.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.bottom.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.padding_left.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.padding_right.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.padding_top.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.padding_bottom
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.horizontal_gap
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.vertical_gap.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.width.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.height.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.min_width.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.max_width.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.min_height.play_animation(entity, animation, start_time, duration, delay, playback);
+        self.max_height.play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.min_horizontal_gap
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.max_horizontal_gap
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.min_vertical_gap
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+        self.max_vertical_gap
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.underline_color
+            .play_animation(entity, animation, start_time, duration, delay, playback);
+
+        self.fill.play_animation(entity, animation, start_time, duration, delay, playback);
+    }
 
-        self.left.play_animation(entity, animation, start_time, duration, delay);
-        self.right.play_animation(entity, animation, start_time, duration, delay);
-        self.top.play_animation(entity, animation, start_time, duration, delay);
-        self.bottom.play_animation(entity, animation, start_time, duration, delay);
+    pub(crate) fn stop_animation(&mut self, entity: Entity, animation: Animation) {
+        self.display.stop_animation(entity, animation);
+        self.opacity.stop_animation(entity, animation);
+        self.clip_path.stop_animation(entity, animation);
+        self.filter.stop_animation(entity, animation);
+        self.backdrop_filter.stop_animation(entity, animation);
+        self.transform.stop_animation(entity, animation);
+        self.transform_origin.stop_animation(entity, animation);
+        self.translate.stop_animation(entity, animation);
+        self.rotate.stop_animation(entity, animation);
+        self.scale.stop_animation(entity, animation);
+        self.border_top_width.stop_animation(entity, animation);
+        self.border_right_width.stop_animation(entity, animation);
+        self.border_bottom_width.stop_animation(entity, animation);
+        self.border_left_width.stop_animation(entity, animation);
+        self.border_top_color.stop_animation(entity, animation);
+        self.border_right_color.stop_animation(entity, animation);
+        self.border_bottom_color.stop_animation(entity, animation);
+        self.border_left_color.stop_animation(entity, animation);
+        self.corner_top_left_radius.stop_animation(entity, animation);
+        self.corner_top_right_radius.stop_animation(entity, animation);
+        self.corner_bottom_left_radius.stop_animation(entity, animation);
+        self.corner_bottom_right_radius.stop_animation(entity, animation);
+        self.outline_width.stop_animation(entity, animation);
+        self.outline_color.stop_animation(entity, animation);
+        self.outline_offset.stop_animation(entity, animation);
+        self.background_color.stop_animation(entity, animation);
+        self.background_image.stop_animation(entity, animation);
+        self.background_size.stop_animation(entity, animation);
+        self.shadow.stop_animation(entity, animation);
+        self.font_color.stop_animation(entity, animation);
+        self.font_size.stop_animation(entity, animation);
+        self.line_height.stop_animation(entity, animation);
+        self.letter_spacing.stop_animation(entity, animation);
+        self.word_spacing.stop_animation(entity, animation);
+        self.text_shadow.stop_animation(entity, animation);
+        self.caret_color.stop_animation(entity, animation);
+        self.selection_color.stop_animation(entity, animation);
+        self.selection_text_color.stop_animation(entity, animation);
+        self.left.stop_animation(entity, animation);
+        self.right.stop_animation(entity, animation);
+        self.top.stop_animation(entity, animation);
+        self.bottom.stop_animation(entity, animation);
+        self.padding_left.stop_animation(entity, animation);
+        self.padding_right.stop_animation(entity, animation);
+        self.padding_top.stop_animation(entity, animation);
+        self.padding_bottom.stop_animation(entity, animation);
+        self.horizontal_gap.stop_animation(entity, animation);
+        self.vertical_gap.stop_animation(entity, animation);
+        self.width.stop_animation(entity, animation);
+        self.height.stop_animation(entity, animation);
+        self.min_width.stop_animation(entity, animation);
+        self.max_width.stop_animation(entity, animation);
+        self.min_height.stop_animation(entity, animation);
+        self.max_height.stop_animation(entity, animation);
+        self.min_horizontal_gap.stop_animation(entity, animation);
+        self.max_horizontal_gap.stop_animation(entity, animation);
+        self.min_vertical_gap.stop_animation(entity, animation);
+        self.max_vertical_gap.stop_animation(entity, animation);
+        self.underline_color.stop_animation(entity, animation);
+        self.fill.stop_animation(entity, animation);
+    }
 
-        self.padding_left.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_right.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_top.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_bottom.play_animation(entity, animation, start_time, duration, delay);
-        self.horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.vertical_gap.play_animation(entity, animation, start_time, duration, delay);
+    pub(crate) fn set_animation_paused(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        paused: bool,
+        time: Instant,
+    ) {
+        self.display.set_animation_paused(entity, animation, paused, time);
+        self.opacity.set_animation_paused(entity, animation, paused, time);
+        self.clip_path.set_animation_paused(entity, animation, paused, time);
+        self.filter.set_animation_paused(entity, animation, paused, time);
+        self.backdrop_filter.set_animation_paused(entity, animation, paused, time);
+        self.transform.set_animation_paused(entity, animation, paused, time);
+        self.transform_origin.set_animation_paused(entity, animation, paused, time);
+        self.translate.set_animation_paused(entity, animation, paused, time);
+        self.rotate.set_animation_paused(entity, animation, paused, time);
+        self.scale.set_animation_paused(entity, animation, paused, time);
+        self.border_top_width.set_animation_paused(entity, animation, paused, time);
+        self.border_right_width.set_animation_paused(entity, animation, paused, time);
+        self.border_bottom_width.set_animation_paused(entity, animation, paused, time);
+        self.border_left_width.set_animation_paused(entity, animation, paused, time);
+        self.border_top_color.set_animation_paused(entity, animation, paused, time);
+        self.border_right_color.set_animation_paused(entity, animation, paused, time);
+        self.border_bottom_color.set_animation_paused(entity, animation, paused, time);
+        self.border_left_color.set_animation_paused(entity, animation, paused, time);
+        self.corner_top_left_radius.set_animation_paused(entity, animation, paused, time);
+        self.corner_top_right_radius.set_animation_paused(entity, animation, paused, time);
+        self.corner_bottom_left_radius.set_animation_paused(entity, animation, paused, time);
+        self.corner_bottom_right_radius.set_animation_paused(entity, animation, paused, time);
+        self.outline_width.set_animation_paused(entity, animation, paused, time);
+        self.outline_color.set_animation_paused(entity, animation, paused, time);
+        self.outline_offset.set_animation_paused(entity, animation, paused, time);
+        self.background_color.set_animation_paused(entity, animation, paused, time);
+        self.background_image.set_animation_paused(entity, animation, paused, time);
+        self.background_size.set_animation_paused(entity, animation, paused, time);
+        self.shadow.set_animation_paused(entity, animation, paused, time);
+        self.font_color.set_animation_paused(entity, animation, paused, time);
+        self.font_size.set_animation_paused(entity, animation, paused, time);
+        self.line_height.set_animation_paused(entity, animation, paused, time);
+        self.letter_spacing.set_animation_paused(entity, animation, paused, time);
+        self.word_spacing.set_animation_paused(entity, animation, paused, time);
+        self.text_shadow.set_animation_paused(entity, animation, paused, time);
+        self.caret_color.set_animation_paused(entity, animation, paused, time);
+        self.selection_color.set_animation_paused(entity, animation, paused, time);
+        self.selection_text_color.set_animation_paused(entity, animation, paused, time);
+        self.left.set_animation_paused(entity, animation, paused, time);
+        self.right.set_animation_paused(entity, animation, paused, time);
+        self.top.set_animation_paused(entity, animation, paused, time);
+        self.bottom.set_animation_paused(entity, animation, paused, time);
+        self.padding_left.set_animation_paused(entity, animation, paused, time);
+        self.padding_right.set_animation_paused(entity, animation, paused, time);
+        self.padding_top.set_animation_paused(entity, animation, paused, time);
+        self.padding_bottom.set_animation_paused(entity, animation, paused, time);
+        self.horizontal_gap.set_animation_paused(entity, animation, paused, time);
+        self.vertical_gap.set_animation_paused(entity, animation, paused, time);
+        self.width.set_animation_paused(entity, animation, paused, time);
+        self.height.set_animation_paused(entity, animation, paused, time);
+        self.min_width.set_animation_paused(entity, animation, paused, time);
+        self.max_width.set_animation_paused(entity, animation, paused, time);
+        self.min_height.set_animation_paused(entity, animation, paused, time);
+        self.max_height.set_animation_paused(entity, animation, paused, time);
+        self.min_horizontal_gap.set_animation_paused(entity, animation, paused, time);
+        self.max_horizontal_gap.set_animation_paused(entity, animation, paused, time);
+        self.min_vertical_gap.set_animation_paused(entity, animation, paused, time);
+        self.max_vertical_gap.set_animation_paused(entity, animation, paused, time);
+        self.underline_color.set_animation_paused(entity, animation, paused, time);
+        self.fill.set_animation_paused(entity, animation, paused, time);
+    }
 
-        self.width.play_animation(entity, animation, start_time, duration, delay);
-        self.height.play_animation(entity, animation, start_time, duration, delay);
+    /// Starts, restarts, pauses, resumes, or stops the keyframe animation declared by the
+    /// `animation-*` properties of the given entity.
+    pub(crate) fn update_declared_animation(&mut self, entity: Entity) {
+        let declared = match self.animation_name.get(entity) {
+            Some(AnimationName::Name(name)) => self.animations.get(name).copied(),
+            _ => None,
+        }
+        .map(|animation| DeclaredAnimation {
+            animation,
+            duration: self.animation_duration.get(entity).copied().unwrap_or_default(),
+            delay: self.animation_delay.get(entity).copied().unwrap_or_default(),
+            playback: AnimationPlayback {
+                iterations: self
+                    .animation_iteration_count
+                    .get(entity)
+                    .copied()
+                    .unwrap_or_default()
+                    .to_f32(),
+                direction: self.animation_direction.get(entity).copied().unwrap_or_default(),
+                fill_mode: self.animation_fill_mode.get(entity).copied().unwrap_or_default(),
+                timing_function: self
+                    .animation_timing_function
+                    .get(entity)
+                    .cloned()
+                    .map(TimingFunction::from),
+            },
+            paused: self.animation_play_state.get(entity) == Some(&AnimationPlayState::Paused),
+        })
+        // An animation with no duration has nothing to play, unless it is timed by a spring which
+        // comes to rest in its own time.
+        .filter(|declared| {
+            !declared.duration.is_zero()
+                || declared
+                    .playback
+                    .timing_function
+                    .as_ref()
+                    .is_some_and(|timing_function| timing_function.as_spring().is_some())
+        });
+
+        let current = self.declared_animations.get(entity).cloned();
+
+        let now = Instant::now();
+
+        match (current, declared) {
+            (Some(current), Some(declared))
+                if DeclaredAnimation { paused: declared.paused, ..current.clone() } == declared =>
+            {
+                if current.paused != declared.paused {
+                    self.set_animation_paused(entity, declared.animation, declared.paused, now);
+                    self.declared_animations.insert(entity, declared);
+                }
+            }
 
-        self.min_width.play_animation(entity, animation, start_time, duration, delay);
-        self.max_width.play_animation(entity, animation, start_time, duration, delay);
-        self.min_height.play_animation(entity, animation, start_time, duration, delay);
-        self.max_height.play_animation(entity, animation, start_time, duration, delay);
+            (current, declared) => {
+                if let Some(current) = current {
+                    self.stop_animation(entity, current.animation);
+                    self.declared_animations.remove(entity);
+                }
 
-        self.min_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.min_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
+                if let Some(declared) = declared {
+                    self.play_animation(
+                        entity,
+                        declared.animation,
+                        now,
+                        declared.duration,
+                        declared.delay,
+                        &declared.playback,
+                    );
 
-        self.underline_color.play_animation(entity, animation, start_time, duration, delay);
+                    if declared.paused {
+                        self.set_animation_paused(entity, declared.animation, true, now);
+                    }
 
-        self.fill.play_animation(entity, animation, start_time, duration, delay);
+                    self.declared_animations.insert(entity, declared);
+                }
+            }
+        }
     }
 
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
         self.display.has_active_animation(entity, animation)
             | self.opacity.has_active_animation(entity, animation)
             | self.clip_path.has_active_animation(entity, animation)
+            | self.filter.has_active_animation(entity, animation)
+            | self.backdrop_filter.has_active_animation(entity, animation)
             | self.transform.has_active_animation(entity, animation)
             | self.transform_origin.has_active_animation(entity, animation)
             | self.translate.has_active_animation(entity, animation)
             | self.rotate.has_active_animation(entity, animation)
             | self.scale.has_active_animation(entity, animation)
-            | self.border_width.has_active_animation(entity, animation)
-            | self.border_color.has_active_animation(entity, animation)
+            | self.border_top_width.has_active_animation(entity, animation)
+            | self.border_right_width.has_active_animation(entity, animation)
+            | self.border_bottom_width.has_active_animation(entity, animation)
+            | self.border_left_width.has_active_animation(entity, animation)
+            | self.border_top_color.has_active_animation(entity, animation)
+            | self.border_right_color.has_active_animation(entity, animation)
+            | self.border_bottom_color.has_active_animation(entity, animation)
+            | self.border_left_color.has_active_animation(entity, animation)
             | self.corner_top_left_radius.has_active_animation(entity, animation)
             | self.corner_top_right_radius.has_active_animation(entity, animation)
             | self.corner_bottom_left_radius.has_active_animation(entity, animation)
@@ -846,8 +1681,13 @@ impl Style {
             | self.shadow.has_active_animation(entity, animation)
             | self.font_color.has_active_animation(entity, animation)
             | self.font_size.has_active_animation(entity, animation)
+            | self.line_height.has_active_animation(entity, animation)
+            | self.letter_spacing.has_active_animation(entity, animation)
+            | self.word_spacing.has_active_animation(entity, animation)
+            | self.text_shadow.has_active_animation(entity, animation)
             | self.caret_color.has_active_animation(entity, animation)
             | self.selection_color.has_active_animation(entity, animation)
+            | self.selection_text_color.has_active_animation(entity, animation)
             | self.left.has_active_animation(entity, animation)
             | self.right.has_active_animation(entity, animation)
             | self.top.has_active_animation(entity, animation)
@@ -872,67 +1712,178 @@ impl Style {
             | self.fill.has_active_animation(entity, animation)
     }
 
-    pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
-        if let Ok(stylesheet) = StyleSheet::parse(stylesheet, ParserOptions::new()) {
-            let rules = stylesheet.rules.0;
+    /// Parses a stylesheet and adds its rules to the style, recording `source` as the name of the
+    /// stylesheet the rules came from. Returns any problems found while parsing.
+    ///
+    /// Relative urls in the stylesheet are resolved against `base_path`.
+    pub(crate) fn parse_theme(
+        &mut self,
+        stylesheet: &str,
+        source: &str,
+        base_path: Option<&Path>,
+    ) -> Vec<StyleDiagnostic> {
+        let warnings = Arc::new(RwLock::new(Vec::new()));
+
+        let mut options = ParserOptions::new();
+        options.filename = source.to_owned();
+        options.warnings = Some(warnings.clone());
+
+        let mut diagnostics = Vec::new();
 
-            for rule in rules {
-                match rule {
-                    CssRule::Style(style_rule) => {
-                        // let selectors = style_rule.selectors;
+        let source: Arc<str> = Arc::from(source);
 
-                        for selector in style_rule.selectors.slice() {
-                            let rule_id = self.rule_manager.create();
+        match StyleSheet::parse(stylesheet, options) {
+            Ok(stylesheet) => {
+                let rules = stylesheet.rules.0;
 
-                            for property in style_rule.declarations.declarations.iter() {
-                                match property {
-                                    Property::Transition(transitions) => {
-                                        for transition in transitions.iter() {
-                                            self.insert_transition(rule_id, transition);
+                for rule in rules {
+                    match rule {
+                        CssRule::Style(style_rule) => {
+                            // let selectors = style_rule.selectors;
+
+                            for selector in style_rule.selectors.slice() {
+                                let rule_id = self.rule_manager.create();
+
+                                // Only the colors of selected text can be styled.
+                                if selector.pseudo_element() == Some(&PseudoElement::Selection) {
+                                    self.insert_selection_properties(
+                                        rule_id,
+                                        &style_rule.declarations.declarations,
+                                    );
+
+                                    self.rules.insert(
+                                        rule_id,
+                                        StyleRule::new(selector.clone(), source.clone()),
+                                    );
+
+                                    continue;
+                                }
+
+                                for property in style_rule.declarations.declarations.iter() {
+                                    match property {
+                                        Property::Transition(transitions) => {
+                                            for transition in transitions.iter() {
+                                                self.insert_transition(rule_id, transition);
+                                            }
                                         }
-                                    }
 
-                                    _ => {
-                                        self.insert_property(rule_id, property);
+                                        _ => {
+                                            self.insert_property(rule_id, property);
+                                        }
                                     }
                                 }
-                            }
 
-                            self.rules.insert(rule_id, StyleRule::new(selector.clone()));
+                                if selector.pseudo_element().is_some() {
+                                    self.pseudo_element_rules.push(rule_id);
+                                }
+
+                                self.rules.insert(
+                                    rule_id,
+                                    StyleRule::new(selector.clone(), source.clone()),
+                                );
+                            }
                         }
-                    }
 
-                    CssRule::Keyframes(keyframes_rule) => {
-                        let name = keyframes_rule.name.as_string();
+                        CssRule::Keyframes(keyframes_rule) => {
+                            let name = keyframes_rule.name.as_string();
 
-                        let animation_id = self.animation_manager.create();
+                            let animation_id = self.animation_manager.create();
 
-                        for keyframes in keyframes_rule.keyframes {
-                            for selector in keyframes.selectors.iter() {
-                                let time = match selector {
-                                    KeyframeSelector::From => 0.0,
-                                    KeyframeSelector::To => 1.0,
-                                    KeyframeSelector::Percentage(percentage) => {
-                                        percentage.0 / 100.0
-                                    }
-                                };
+                            for keyframes in keyframes_rule.keyframes {
+                                for selector in keyframes.selectors.iter() {
+                                    let time = match selector {
+                                        KeyframeSelector::From => 0.0,
+                                        KeyframeSelector::To => 1.0,
+                                        KeyframeSelector::Percentage(percentage) => {
+                                            percentage.0 / 100.0
+                                        }
+                                    };
 
-                                self.add_keyframe(
-                                    animation_id,
-                                    time,
-                                    &keyframes.declarations.declarations,
-                                );
+                                    self.add_keyframe(
+                                        animation_id,
+                                        time,
+                                        &keyframes.declarations.declarations,
+                                    );
+                                }
+                            }
+
+                            self.animations.insert(name, animation_id);
+                        }
+
+                        CssRule::FontFace(font_face_rule) => {
+                            let declaration =
+                                FontFace::from_rule(&font_face_rule, base_path).map(|font_face| {
+                                    FontFaceDeclaration {
+                                        font_face,
+                                        source: source.clone(),
+                                        line: font_face_rule.loc.line + 1,
+                                        column: font_face_rule.loc.column,
+                                    }
+                                });
+
+                            match declaration {
+                                Ok(declaration) => self.font_faces.push(declaration),
+                                Err(message) => diagnostics.push(StyleDiagnostic::font_face(
+                                    &source,
+                                    font_face_rule.loc.line + 1,
+                                    font_face_rule.loc.column,
+                                    message,
+                                )),
                             }
                         }
 
-                        self.animations.insert(name, animation_id);
+                        _ => {}
                     }
+                }
+            }
+
+            Err(error) => diagnostics.push(StyleDiagnostic::new(&source, &error)),
+        }
+
+        if let Ok(warnings) = warnings.read() {
+            diagnostics
+                .extend(warnings.iter().map(|warning| StyleDiagnostic::new(&source, warning)));
+        }
+
+        diagnostics
+    }
+
+    /// Inserts the properties of a `::selection` rule, which apply to the selection colors of the
+    /// matching entities.
+    fn insert_selection_properties(&mut self, rule_id: Rule, properties: &[Property]) {
+        for property in properties {
+            match property {
+                Property::BackgroundColor(color) | Property::SelectionColor(color) => {
+                    self.selection_color.insert_rule(rule_id, *color);
+                }
+
+                Property::FontColor(color) => {
+                    self.selection_text_color.insert_rule(rule_id, *color);
+                }
+
+                Property::Transition(transitions) => {
+                    for transition in transitions.iter() {
+                        let animation = self.animation_manager.create();
+                        match transition.property.as_ref() {
+                            "background-color" | "selection-color" => {
+                                self.selection_color
+                                    .insert_animation(animation, self.add_transition(transition));
+                                self.selection_color.insert_transition(rule_id, animation);
+                            }
+
+                            "color" => {
+                                self.selection_text_color
+                                    .insert_animation(animation, self.add_transition(transition));
+                                self.selection_text_color.insert_transition(rule_id, animation);
+                            }
 
-                    _ => {}
+                            _ => {}
+                        }
+                    }
                 }
+
+                _ => {}
             }
-        } else {
-            println!("Failed to parse stylesheet");
         }
     }
 
@@ -954,6 +1905,16 @@ impl Style {
                 self.clip_path.insert_transition(rule_id, animation);
             }
 
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(rule_id, animation);
+            }
+
+            "backdrop-filter" => {
+                self.backdrop_filter.insert_animation(animation, self.add_transition(transition));
+                self.backdrop_filter.insert_transition(rule_id, animation);
+            }
+
             "transform" => {
                 self.transform.insert_animation(animation, self.add_transition(transition));
                 self.transform.insert_transition(rule_id, animation);
@@ -980,20 +1941,96 @@ impl Style {
             }
 
             "border" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-width" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+            }
+
+            "border-top-width" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+            }
+
+            "border-right-width" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-width" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+            }
+
+            "border-left-width" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
             }
 
             "border-color" => {
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
+            }
+
+            "border-top-color" => {
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+            }
+
+            "border-right-color" => {
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-color" => {
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+            }
+
+            "border-left-color" => {
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "corner-radius" => {
@@ -1087,6 +2124,26 @@ impl Style {
                 self.font_size.insert_transition(rule_id, animation);
             }
 
+            "line-height" => {
+                self.line_height.insert_animation(animation, self.add_transition(transition));
+                self.line_height.insert_transition(rule_id, animation);
+            }
+
+            "letter-spacing" => {
+                self.letter_spacing.insert_animation(animation, self.add_transition(transition));
+                self.letter_spacing.insert_transition(rule_id, animation);
+            }
+
+            "word-spacing" => {
+                self.word_spacing.insert_animation(animation, self.add_transition(transition));
+                self.word_spacing.insert_transition(rule_id, animation);
+            }
+
+            "text-shadow" => {
+                self.text_shadow.insert_animation(animation, self.add_transition(transition));
+                self.text_shadow.insert_transition(rule_id, animation);
+            }
+
             "caret-color" => {
                 self.caret_color.insert_animation(animation, self.add_transition(transition));
                 self.caret_color.insert_transition(rule_id, animation);
@@ -1242,7 +2299,28 @@ impl Style {
                 self.clip_path.insert_rule(rule_id, clip);
             }
 
+            Property::MaskImage(images) => {
+                let images = images
+                    .into_iter()
+                    .filter_map(|img| match img {
+                        BackgroundImage::None => None,
+                        BackgroundImage::Gradient(gradient) => {
+                            Some(ImageOrGradient::Gradient(*gradient))
+                        }
+                        BackgroundImage::Url(url) => {
+                            Some(ImageOrGradient::Image(url.url.to_string()))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                self.mask_image.insert_rule(rule_id, images);
+            }
+
             // Filters
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             Property::BackdropFilter(filter) => {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
@@ -1428,29 +2506,104 @@ impl Style {
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_top_color.insert_rule(rule_id, border_color);
+                    self.border_right_color.insert_rule(rule_id, border_color);
+                    self.border_bottom_color.insert_rule(rule_id, border_color);
+                    self.border_left_color.insert_rule(rule_id, border_color);
+                    self.border_gradient.insert_rule(rule_id, Gradient::None);
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    self.border_top_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_right_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_bottom_width.insert_rule(rule_id, border_width.0.clone());
+                    self.border_left_width.insert_rule(rule_id, border_width.0);
                 }
 
                 if let Some(border_style) = border.style {
-                    self.border_style.insert_rule(rule_id, border_style.top);
+                    self.border_top_style.insert_rule(rule_id, border_style.top);
+                    self.border_right_style.insert_rule(rule_id, border_style.right);
+                    self.border_bottom_style.insert_rule(rule_id, border_style.bottom);
+                    self.border_left_style.insert_rule(rule_id, border_style.left);
                 }
             }
 
-            // Border
+            // Border Width
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_top_width.insert_rule(rule_id, border_width.top.0);
+                self.border_right_width.insert_rule(rule_id, border_width.right.0);
+                self.border_bottom_width.insert_rule(rule_id, border_width.bottom.0);
+                self.border_left_width.insert_rule(rule_id, border_width.left.0);
+            }
+
+            Property::BorderTopWidth(border_width) => {
+                self.border_top_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderRightWidth(border_width) => {
+                self.border_right_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderBottomWidth(border_width) => {
+                self.border_bottom_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderLeftWidth(border_width) => {
+                self.border_left_width.insert_rule(rule_id, border_width.0);
             }
 
+            // Border Color
             Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+                self.border_top_color.insert_rule(rule_id, color.top);
+                self.border_right_color.insert_rule(rule_id, color.right);
+                self.border_bottom_color.insert_rule(rule_id, color.bottom);
+                self.border_left_color.insert_rule(rule_id, color.left);
+                // A color overrides a gradient from a less specific rule.
+                self.border_gradient.insert_rule(rule_id, Gradient::None);
+            }
+
+            Property::BorderGradient(gradient) => {
+                self.border_gradient.insert_rule(rule_id, gradient);
+            }
+
+            Property::BorderTopColor(color) => {
+                self.border_top_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderRightColor(color) => {
+                self.border_right_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderBottomColor(color) => {
+                self.border_bottom_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderLeftColor(color) => {
+                self.border_left_color.insert_rule(rule_id, color);
             }
 
+            // Border Style
             Property::BorderStyle(style) => {
-                self.border_style.insert_rule(rule_id, style.top);
+                self.border_top_style.insert_rule(rule_id, style.top);
+                self.border_right_style.insert_rule(rule_id, style.right);
+                self.border_bottom_style.insert_rule(rule_id, style.bottom);
+                self.border_left_style.insert_rule(rule_id, style.left);
+            }
+
+            Property::BorderTopStyle(style) => {
+                self.border_top_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderRightStyle(style) => {
+                self.border_right_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderBottomStyle(style) => {
+                self.border_bottom_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderLeftStyle(style) => {
+                self.border_left_style.insert_rule(rule_id, style);
             }
 
             // Border Radius
@@ -1518,6 +2671,12 @@ impl Style {
             // Font Color
             Property::FontColor(font_color) => {
                 self.font_color.insert_rule(rule_id, font_color);
+                // A color overrides a gradient from a less specific rule.
+                self.font_gradient.insert_rule(rule_id, Gradient::None);
+            }
+
+            Property::FontGradient(gradient) => {
+                self.font_gradient.insert_rule(rule_id, gradient);
             }
 
             // Font Size
@@ -1545,6 +2704,36 @@ impl Style {
                 self.font_variation_settings.insert_rule(rule_id, font_variation_settings);
             }
 
+            // Font Feature Settings
+            Property::FontFeatureSettings(font_feature_settings) => {
+                self.font_feature_settings.insert_rule(rule_id, font_feature_settings);
+            }
+
+            // Line Height
+            Property::LineHeight(line_height) => {
+                self.line_height.insert_rule(rule_id, line_height);
+            }
+
+            // Letter Spacing
+            Property::LetterSpacing(letter_spacing) => {
+                self.letter_spacing.insert_rule(rule_id, letter_spacing);
+            }
+
+            // Word Spacing
+            Property::WordSpacing(word_spacing) => {
+                self.word_spacing.insert_rule(rule_id, word_spacing);
+            }
+
+            // Text Shadows
+            Property::TextShadow(text_shadow) => {
+                self.text_shadow.insert_rule(rule_id, text_shadow);
+            }
+
+            // Text Transform
+            Property::TextTransform(text_transform) => {
+                self.text_transform.insert_rule(rule_id, text_transform);
+            }
+
             // Caret Color
             Property::CaretColor(caret_color) => {
                 self.caret_color.insert_rule(rule_id, caret_color);
@@ -1555,6 +2744,39 @@ impl Style {
                 self.selection_color.insert_rule(rule_id, selection_color);
             }
 
+            // Animations
+            Property::AnimationName(name) => {
+                self.animation_name.insert_rule(rule_id, name);
+            }
+
+            Property::AnimationDuration(duration) => {
+                self.animation_duration.insert_rule(rule_id, duration);
+            }
+
+            Property::AnimationDelay(delay) => {
+                self.animation_delay.insert_rule(rule_id, delay);
+            }
+
+            Property::AnimationTimingFunction(timing_function) => {
+                self.animation_timing_function.insert_rule(rule_id, timing_function);
+            }
+
+            Property::AnimationIterationCount(iteration_count) => {
+                self.animation_iteration_count.insert_rule(rule_id, iteration_count);
+            }
+
+            Property::AnimationDirection(direction) => {
+                self.animation_direction.insert_rule(rule_id, direction);
+            }
+
+            Property::AnimationFillMode(fill_mode) => {
+                self.animation_fill_mode.insert_rule(rule_id, fill_mode);
+            }
+
+            Property::AnimationPlayState(play_state) => {
+                self.animation_play_state.insert_rule(rule_id, play_state);
+            }
+
             // Transform
             Property::Transform(transforms) => {
                 self.transform.insert_rule(rule_id, transforms);
@@ -1665,10 +2887,8 @@ impl Style {
                 self.pointer_events.insert_rule(rule_id, pointer_events);
             }
 
-            // Unparsed. TODO: Log the error.
-            Property::Unparsed(unparsed) => {
-                warn!("Unparsed: {}", unparsed.name);
-            }
+            // Unparsed properties are reported as diagnostics when the stylesheet is parsed.
+            Property::Unparsed(_) => {}
 
             // TODO: Custom property support
             Property::Custom(custom) => {
@@ -1696,6 +2916,11 @@ impl Style {
             Property::Fill(fill) => {
                 self.fill.insert_rule(rule_id, fill);
             }
+
+            // Pseudo-elements
+            Property::Content(content) => {
+                self.content.insert_rule(rule_id, content);
+            }
             _ => {}
         }
     }
@@ -1705,22 +2930,17 @@ impl Style {
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function =
+            Some(transition.timing_function.clone().map(TimingFunction::from).unwrap_or_default());
 
         AnimationState::new(Animation::null())
             .with_duration(transition.duration)
             .with_delay(transition.delay.unwrap_or_default())
-            .with_keyframe(Keyframe { time: 0.0, value: Default::default(), timing_function })
+            .with_keyframe(Keyframe {
+                time: 0.0,
+                value: Default::default(),
+                timing_function: timing_function.clone(),
+            })
             .with_keyframe(Keyframe { time: 1.0, value: Default::default(), timing_function })
     }
 
@@ -1739,6 +2959,10 @@ impl Style {
         self.ids.remove(entity);
         self.classes.remove(entity);
         self.pseudo_classes.remove(entity);
+        self.custom_states.remove(entity);
+        self.attributes.remove(entity);
+        self.language.remove(entity);
+        self.direction.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
 
@@ -1761,16 +2985,29 @@ impl Style {
         self.z_index.remove(entity);
         // Clipping
         self.clip_path.remove(entity);
+        self.mask_image.remove(entity);
 
         self.overflowx.remove(entity);
         self.overflowy.remove(entity);
 
-        // Backdrop Filter
+        // Filters
+        self.filter.remove(entity);
         self.backdrop_filter.remove(entity);
 
         // Blend Mode
         self.blend_mode.remove(entity);
 
+        // Animations
+        self.animation_name.remove(entity);
+        self.animation_duration.remove(entity);
+        self.animation_delay.remove(entity);
+        self.animation_timing_function.remove(entity);
+        self.animation_iteration_count.remove(entity);
+        self.animation_direction.remove(entity);
+        self.animation_fill_mode.remove(entity);
+        self.animation_play_state.remove(entity);
+        self.declared_animations.remove(entity);
+
         // Transform
         self.transform.remove(entity);
         self.transform_origin.remove(entity);
@@ -1779,9 +3016,19 @@ impl Style {
         self.scale.remove(entity);
 
         // Border
-        self.border_width.remove(entity);
-        self.border_color.remove(entity);
-        self.border_style.remove(entity);
+        self.border_top_width.remove(entity);
+        self.border_right_width.remove(entity);
+        self.border_bottom_width.remove(entity);
+        self.border_left_width.remove(entity);
+        self.border_top_color.remove(entity);
+        self.border_right_color.remove(entity);
+        self.border_bottom_color.remove(entity);
+        self.border_left_color.remove(entity);
+        self.border_gradient.remove(entity);
+        self.border_top_style.remove(entity);
+        self.border_right_style.remove(entity);
+        self.border_bottom_style.remove(entity);
+        self.border_left_style.remove(entity);
 
         // Corner Shape
         self.corner_bottom_left_shape.remove(entity);
@@ -1822,13 +3069,21 @@ impl Style {
         self.text_align.remove(entity);
         self.font_family.remove(entity);
         self.font_color.remove(entity);
+        self.font_gradient.remove(entity);
         self.font_size.remove(entity);
         self.font_weight.remove(entity);
         self.font_slant.remove(entity);
         self.font_width.remove(entity);
         self.font_variation_settings.remove(entity);
+        self.font_feature_settings.remove(entity);
+        self.line_height.remove(entity);
+        self.letter_spacing.remove(entity);
+        self.word_spacing.remove(entity);
+        self.text_shadow.remove(entity);
+        self.text_transform.remove(entity);
         self.caret_color.remove(entity);
         self.selection_color.remove(entity);
+        self.selection_text_color.remove(entity);
         self.text_decoration_line.remove(entity);
         self.text_stroke_width.remove(entity);
         self.text_stroke_style.remove(entity);
@@ -1889,8 +3144,88 @@ impl Style {
 
         self.text_range.remove(entity);
         self.text_span.remove(entity);
+        self.text_mask.remove(entity);
 
         self.fill.remove(entity);
+
+        self.content.remove(entity);
+        self.pseudo_elements.remove(entity);
+    }
+
+    /// Adds or removes a custom state of an entity, returning true if the state changed.
+    pub(crate) fn set_custom_state(&mut self, entity: Entity, name: &str, applied: bool) -> bool {
+        if let Some(states) = self.custom_states.get_mut(entity) {
+            if applied {
+                states.insert(name.to_owned())
+            } else {
+                states.remove(name)
+            }
+        } else if applied {
+            let mut states = HashSet::new();
+            states.insert(name.to_owned());
+            self.custom_states.insert(entity, states);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn has_custom_state(&self, entity: Entity, name: &str) -> bool {
+        self.custom_states.get(entity).map(|states| states.contains(name)).unwrap_or_default()
+    }
+
+    /// Sets or removes an attribute of an entity, returning true if the attribute changed.
+    pub(crate) fn set_attribute(
+        &mut self,
+        entity: Entity,
+        name: &str,
+        value: Option<String>,
+    ) -> bool {
+        match value {
+            Some(value) => {
+                if let Some(attributes) = self.attributes.get_mut(entity) {
+                    attributes.insert(name.to_owned(), value.clone()).as_ref() != Some(&value)
+                } else {
+                    let mut attributes = HashMap::new();
+                    attributes.insert(name.to_owned(), value);
+                    self.attributes.insert(entity, attributes);
+                    true
+                }
+            }
+
+            None => self
+                .attributes
+                .get_mut(entity)
+                .map(|attributes| attributes.remove(name).is_some())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub(crate) fn get_attribute(&self, entity: Entity, name: &str) -> Option<&str> {
+        self.attributes.get(entity).and_then(|attributes| attributes.get(name)).map(|v| v.as_str())
+    }
+
+    /// Returns the language of an entity, which is inherited from its ancestors or otherwise is the
+    /// locale of the application.
+    pub(crate) fn get_language(&self, entity: Entity, tree: &Tree<Entity>) -> &LanguageIdentifier {
+        LayoutParentIterator::new(tree, entity)
+            .find_map(|ancestor| self.language.get(ancestor))
+            .unwrap_or(&self.locale)
+    }
+
+    /// Returns the text direction of an entity, which is inherited from its ancestors or otherwise is
+    /// the direction of its language.
+    pub(crate) fn get_direction(&self, entity: Entity, tree: &Tree<Entity>) -> Direction {
+        LayoutParentIterator::new(tree, entity)
+            .find_map(|ancestor| self.direction.get(ancestor).copied())
+            .unwrap_or_else(|| {
+                if self.get_language(entity, tree).character_direction() == CharacterDirection::RTL
+                {
+                    Direction::Rtl
+                } else {
+                    Direction::Ltr
+                }
+            })
     }
 
     pub(crate) fn needs_restyle(&mut self, entity: Entity) {
@@ -1910,6 +3245,20 @@ impl Style {
         self.text_layout.0.insert(entity).unwrap();
     }
 
+    /// Marks the text of an entity as needing to be rebuilt. The text of a span is part of the paragraph
+    /// of its first displayed ancestor, so that paragraph is rebuilt too.
+    pub(crate) fn needs_paragraph_update(&mut self, entity: Entity, tree: &Tree<Entity>) {
+        self.needs_text_update(entity);
+
+        if self.text_span.get(entity).copied().unwrap_or_default() {
+            if let Some(parent) = LayoutParentIterator::new(tree, entity).find(|parent| {
+                self.display.get(*parent).copied().unwrap_or_default() != Display::None
+            }) {
+                self.needs_text_update(parent);
+            }
+        }
+    }
+
     pub(crate) fn needs_text_layout(&mut self, entity: Entity) {
         self.text_layout.0.insert(entity).unwrap();
     }
@@ -1922,6 +3271,7 @@ impl Style {
 
     // Remove all shared style data.
     pub(crate) fn clear_style_rules(&mut self) {
+        self.font_faces.clear();
         self.disabled.clear_rules();
         // Display
         self.display.clear_rules();
@@ -1934,13 +3284,25 @@ impl Style {
 
         // Clipping
         self.clip_path.clear_rules();
+        self.mask_image.clear_rules();
 
-        // Backdrop Filer
+        // Filters
+        self.filter.clear_rules();
         self.backdrop_filter.clear_rules();
 
         // Blend Mode
         self.blend_mode.clear_rules();
 
+        // Animations
+        self.animation_name.clear_rules();
+        self.animation_duration.clear_rules();
+        self.animation_delay.clear_rules();
+        self.animation_timing_function.clear_rules();
+        self.animation_iteration_count.clear_rules();
+        self.animation_direction.clear_rules();
+        self.animation_fill_mode.clear_rules();
+        self.animation_play_state.clear_rules();
+
         // Transform
         self.transform.clear_rules();
         self.transform_origin.clear_rules();
@@ -1952,9 +3314,19 @@ impl Style {
         self.overflowy.clear_rules();
 
         // Border
-        self.border_width.clear_rules();
-        self.border_color.clear_rules();
-        self.border_style.clear_rules();
+        self.border_top_width.clear_rules();
+        self.border_right_width.clear_rules();
+        self.border_bottom_width.clear_rules();
+        self.border_left_width.clear_rules();
+        self.border_top_color.clear_rules();
+        self.border_right_color.clear_rules();
+        self.border_bottom_color.clear_rules();
+        self.border_left_color.clear_rules();
+        self.border_gradient.clear_rules();
+        self.border_top_style.clear_rules();
+        self.border_right_style.clear_rules();
+        self.border_bottom_style.clear_rules();
+        self.border_left_style.clear_rules();
 
         // Corner Shape
         self.corner_bottom_left_shape.clear_rules();
@@ -2038,9 +3410,17 @@ impl Style {
         self.font_weight.clear_rules();
         self.font_slant.clear_rules();
         self.font_color.clear_rules();
+        self.font_gradient.clear_rules();
         self.font_size.clear_rules();
         self.font_variation_settings.clear_rules();
+        self.font_feature_settings.clear_rules();
+        self.line_height.clear_rules();
+        self.letter_spacing.clear_rules();
+        self.word_spacing.clear_rules();
+        self.text_shadow.clear_rules();
+        self.text_transform.clear_rules();
         self.selection_color.clear_rules();
+        self.selection_text_color.clear_rules();
         self.caret_color.clear_rules();
         self.text_decoration_line.clear_rules();
         self.text_stroke_width.clear_rules();
@@ -2053,5 +3433,48 @@ impl Style {
         self.name.clear_rules();
 
         self.fill.clear_rules();
+
+        self.content.clear_rules();
+        self.pseudo_element_rules.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pseudo_element_rules() {
+        let mut style = Style::default();
+        let diagnostics = style.parse_theme(
+            r#"
+            label::before { content: "*"; color: red; }
+            textbox::placeholder { color: gray; }
+            textbox::selection { background-color: blue; color: white; }
+            element { selection-color: green; }
+            "#,
+            "test",
+            None,
+        );
+        assert!(diagnostics.is_empty());
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let &[before, placeholder, selection, element] = &rules[..] else {
+            panic!("expected four rules, found {}", rules.len());
+        };
+
+        // Only the rules of generated and placeholder content are matched separately.
+        assert_eq!(style.pseudo_element_rules, vec![before, placeholder]);
+        assert_eq!(style.content.get_shared(before), Some(&Content::from("*")));
+        assert_eq!(style.font_color.get_shared(before), Some(&Color::red()));
+
+        // Selection rules only style selected text.
+        assert_eq!(style.selection_color.get_shared(selection), Some(&Color::blue()));
+        assert_eq!(style.selection_text_color.get_shared(selection), Some(&Color::white()));
+        assert_eq!(style.background_color.get_shared(selection), None);
+        assert_eq!(style.font_color.get_shared(selection), None);
+
+        // The `selection-color` property is an alias for the background of selected text.
+        assert_eq!(style.selection_color.get_shared(element), Some(&Color::green()));
     }
 }
@@ -1,6 +1,7 @@
 use crate::{accessibility::IntoNode, events::ViewHandler, prelude::*};
 use accesskit::{Node, NodeId, Rect, Toggled, Tree, TreeUpdate};
 use hashbrown::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
 use vizia_storage::LayoutTreeIterator;
 
 /// Updates node properties from view properties
@@ -157,7 +158,13 @@ pub(crate) fn get_access_node(
     }
 
     if let Some(value) = cx.style.text_value.get(entity) {
-        node_builder.set_value(value.clone().into_boxed_str());
+        // The value of masked text, such as a password, is not exposed to assistive technologies.
+        if let Some(mask) = cx.style.text_mask.get(entity) {
+            let masked = value.graphemes(true).map(|_| *mask).collect::<String>();
+            node_builder.set_value(masked.into_boxed_str());
+        } else {
+            node_builder.set_value(value.clone().into_boxed_str());
+        }
     }
 
     // if let Some(name) = cx.style.name.get(entity) {
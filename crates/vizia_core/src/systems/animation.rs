@@ -52,6 +52,7 @@ macro_rules! process_auto_animations {
                     animation.start_time,
                     animation.duration,
                     animation.delay,
+                    &animation.playback,
                 );
             }
         }
@@ -121,8 +122,14 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
     // Properties which affect rendering
     // Opacity
     redraw_entities.extend(cx.style.opacity.tick(time));
-    // Corner Colour
-    redraw_entities.extend(cx.style.border_color.tick(time));
+    // Filters
+    redraw_entities.extend(cx.style.filter.tick(time));
+    redraw_entities.extend(cx.style.backdrop_filter.tick(time));
+    // Border Colour
+    redraw_entities.extend(cx.style.border_top_color.tick(time));
+    redraw_entities.extend(cx.style.border_right_color.tick(time));
+    redraw_entities.extend(cx.style.border_bottom_color.tick(time));
+    redraw_entities.extend(cx.style.border_left_color.tick(time));
     // Corner Radius
     redraw_entities.extend(cx.style.corner_top_left_radius.tick(time));
     redraw_entities.extend(cx.style.corner_top_right_radius.tick(time));
@@ -153,11 +160,19 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
     reflow_entities.extend(cx.style.font_color.tick(time));
     // Font Size
     reflow_entities.extend(cx.style.font_size.tick(time));
+    // Typography
+    reflow_entities.extend(cx.style.line_height.tick(time));
+    reflow_entities.extend(cx.style.letter_spacing.tick(time));
+    reflow_entities.extend(cx.style.word_spacing.tick(time));
+    reflow_entities.extend(cx.style.text_shadow.tick(time));
 
     // Properties which affect layout
     relayout_entities.extend(cx.style.display.tick(time));
     // Border Width
-    relayout_entities.extend(cx.style.border_width.tick(time));
+    relayout_entities.extend(cx.style.border_top_width.tick(time));
+    relayout_entities.extend(cx.style.border_right_width.tick(time));
+    relayout_entities.extend(cx.style.border_bottom_width.tick(time));
+    relayout_entities.extend(cx.style.border_left_width.tick(time));
     // Space
     relayout_entities.extend(cx.style.left.tick(time));
     relayout_entities.extend(cx.style.right.tick(time));
@@ -3,6 +3,8 @@ use hashbrown::{HashMap, HashSet};
 use std::any::TypeId;
 
 pub(crate) fn binding_system(cx: &mut Context) {
+    let start = Instant::now();
+
     let mut observers: HashMap<Entity, (Entity, TypeId, StoreId)> = HashMap::new();
 
     for (entity, stores) in cx.stores.iter_mut() {
@@ -61,10 +63,13 @@ pub(crate) fn binding_system(cx: &mut Context) {
             }
         }
     }
+
+    cx.profiler.pending.bindings += start.elapsed();
 }
 
 fn update_binding(cx: &mut Context, observer: Entity) {
     if let Some(mut binding) = cx.bindings.remove(&observer) {
+        cx.profiler.pending.bindings_updated += 1;
         cx.with_current(observer, |cx| {
             binding.update(cx);
         });
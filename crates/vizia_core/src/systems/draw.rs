@@ -1,7 +1,8 @@
 use crate::{animation::Interpolator, cache::CachedData, prelude::*};
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, color_filters, image_filters, ClipOp, ImageFilter, Matrix, Paint, Rect,
+    SamplingOptions, Surface,
 };
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -96,6 +97,7 @@ pub(crate) fn transform_system(cx: &mut Context) {
                         rect.1.to_pixels(bounds.w, scale),
                         rect.2.to_pixels(bounds.h, scale),
                     ),
+                    _ => bounds,
                 })
                 .unwrap_or(bounds);
 
@@ -125,6 +127,17 @@ pub(crate) fn transform_system(cx: &mut Context) {
                 (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
             };
 
+            // Clip shapes apply regardless of overflow.
+            let clip_bounds = match cx
+                .style
+                .clip_path
+                .get(entity)
+                .and_then(|clip| clip.as_clip_shape(bounds, scale))
+            {
+                Some(shape) => clip_bounds.intersection(&(*shape.bounds()).into()),
+                None => clip_bounds,
+            };
+
             let transform =
                 cx.cache.transform.get(entity).copied().unwrap_or(Matrix::new_identity());
 
@@ -178,6 +191,8 @@ pub(crate) fn draw_system(
         }
 
         if entity.visible(&cx.style) {
+            cx.profiler.current.redrawn += 1;
+
             let draw_bounds = draw_bounds(&cx.style, &cx.cache, &cx.tree, entity);
 
             let mut dirty_bounds = draw_bounds;
@@ -250,6 +265,11 @@ pub(crate) fn draw_system(
     surface.canvas().clear(Color::transparent());
     dirty_surface.draw(surface.canvas(), (0, 0), SamplingOptions::default(), None);
 
+    // The overlay is drawn over the composited frame so it is never cached in the dirty surface.
+    if cx.profiler.overlay_shown() {
+        cx.profiler.draw_overlay(surface.canvas(), &cx.text_context, cx.style.scale_factor());
+    }
+
     // Debug draw dirty rect
     // if let Some(rect) = dirty_rect.map(Rect::from) {
     //     let mut paint = Paint::default();
@@ -284,35 +304,39 @@ fn draw_entity(
         return;
     }
 
-    let backdrop_filter = cx.backdrop_filter();
+    let filter = cx.filter().filter(|filter| !filter.is_empty());
+    let backdrop_filter = cx.backdrop_filter().filter(|filter| !filter.is_empty());
     let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
 
     canvas.save();
-    let layer_count =
-        if cx.opacity() != 1.0 || backdrop_filter.is_some() || blend_mode != BlendMode::Normal {
-            let mut paint = Paint::default();
-            paint.set_alpha_f(cx.opacity());
-            paint.set_blend_mode(blend_mode.into());
+    let layer_count = if cx.opacity() != 1.0
+        || filter.is_some()
+        || backdrop_filter.is_some()
+        || blend_mode != BlendMode::Normal
+    {
+        let mut paint = Paint::default();
+        paint.set_alpha_f(cx.opacity());
+        paint.set_blend_mode(blend_mode.into());
+
+        if let Some(filter) = filter {
+            paint.set_image_filter(image_filter(filter, cx.scale_factor(), None));
+        }
 
+        let backdrop = backdrop_filter.and_then(|backdrop_filter| {
             let rect: Rect = cx.bounds().into();
-            let mut filter = ImageFilter::crop(rect, None, None).unwrap();
-
-            let slr = if let Some(backdrop_filter) = backdrop_filter {
-                match backdrop_filter {
-                    Filter::Blur(radius) => {
-                        let sigma = radius.to_px().unwrap() * cx.scale_factor() / 2.0;
-                        filter = filter.blur(None, (sigma, sigma), None).unwrap();
-                        SaveLayerRec::default().paint(&paint).backdrop(&filter)
-                    }
-                }
-            } else {
-                SaveLayerRec::default().paint(&paint)
-            };
+            let crop = ImageFilter::crop(rect, None, None);
+            image_filter(backdrop_filter, cx.scale_factor(), crop)
+        });
 
-            Some(canvas.save_layer(&slr))
-        } else {
-            None
-        };
+        let mut slr = SaveLayerRec::default().paint(&paint);
+        if let Some(backdrop) = &backdrop {
+            slr = slr.backdrop(backdrop);
+        }
+
+        Some(canvas.save_layer(&slr))
+    } else {
+        None
+    };
 
     if let Some(transform) = cx.cache.transform.get(current) {
         canvas.set_matrix(&(transform.into()));
@@ -322,6 +346,16 @@ fn draw_entity(
         canvas.clip_path(&clip_path, ClipOp::Intersect, true);
     }
 
+    if let Some(clip_shape) = cx.clip_shape() {
+        canvas.clip_path(&clip_shape, ClipOp::Intersect, true);
+    }
+
+    // Content is drawn into a layer which the mask images are composited onto.
+    let mask_layer = cx
+        .mask_images()
+        .filter(|images| !images.is_empty())
+        .map(|_| canvas.save_layer(&SaveLayerRec::default()));
+
     let is_visible = match (visible, cx.visibility()) {
         (v, None) => v,
         (_, Some(Visibility::Hidden)) => false,
@@ -350,6 +384,16 @@ fn draw_entity(
         draw_entity(cx, dirty_rect, canvas, current_z, queue, is_visible);
     }
 
+    cx.current = current;
+
+    if let Some(count) = mask_layer {
+        let mut paint = Paint::default();
+        paint.set_blend_mode(skia_safe::BlendMode::DstIn);
+        canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+        cx.draw_mask_images(canvas);
+        canvas.restore_to_count(count);
+    }
+
     if let Some(count) = layer_count {
         canvas.restore_to_count(count);
     }
@@ -407,6 +451,34 @@ pub(crate) fn draw_bounds(
 
     layout_bounds = layout_bounds.union(&outline_bounds);
 
+    if let Some(filters) = style.filter.get(entity) {
+        let scale_factor = style.scale_factor();
+        for filter in filters {
+            match filter {
+                Filter::Blur(radius) => {
+                    layout_bounds =
+                        layout_bounds.expand(radius.to_px().unwrap_or_default() * scale_factor);
+                }
+
+                Filter::DropShadow(shadow) => {
+                    let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+                    let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+                    let blur_radius = shadow
+                        .blur_radius
+                        .as_ref()
+                        .and_then(|blur_radius| blur_radius.to_px())
+                        .unwrap_or_default();
+
+                    let shadow_bounds =
+                        layout_bounds.offset(x, y).expand(blur_radius * scale_factor);
+                    layout_bounds = layout_bounds.union(&shadow_bounds);
+                }
+
+                _ => {}
+            }
+        }
+    }
+
     let matrix = cache.transform.get(entity).copied().unwrap_or_default();
 
     let rect: Rect = layout_bounds.into();
@@ -477,3 +549,151 @@ impl PartialEq for ZEntity {
 }
 
 impl Eq for ZEntity {}
+
+/// Builds an image filter which applies a list of filter functions in order to `input`, or to the
+/// source layer if `input` is `None`.
+fn image_filter(
+    filters: &[Filter],
+    scale_factor: f32,
+    input: Option<ImageFilter>,
+) -> Option<ImageFilter> {
+    filters.iter().fold(input, |input, filter| {
+        let color_matrix = |matrix: [f32; 20]| {
+            image_filters::color_filter(
+                color_filters::matrix_row_major(&matrix, None),
+                input.clone(),
+                None,
+            )
+        };
+
+        let next = match filter {
+            Filter::Blur(radius) => {
+                let sigma = radius.to_px().unwrap_or_default() * scale_factor / 2.0;
+                image_filters::blur((sigma, sigma), None, input.clone(), None)
+            }
+
+            Filter::DropShadow(shadow) => {
+                let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+                let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+                let sigma = shadow
+                    .blur_radius
+                    .as_ref()
+                    .and_then(|blur_radius| blur_radius.to_px())
+                    .unwrap_or_default()
+                    * scale_factor
+                    / 2.0;
+                let color = shadow.color.unwrap_or(Color::black());
+                image_filters::drop_shadow((x, y), (sigma, sigma), color, None, input.clone(), None)
+            }
+
+            Filter::Brightness(amount) => {
+                let a = amount.to_factor().max(0.0);
+                #[rustfmt::skip]
+                let matrix = [
+                    a, 0.0, 0.0, 0.0, 0.0,
+                    0.0, a, 0.0, 0.0, 0.0,
+                    0.0, 0.0, a, 0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Contrast(amount) => {
+                let a = amount.to_factor().max(0.0);
+                let b = 0.5 - 0.5 * a;
+                #[rustfmt::skip]
+                let matrix = [
+                    a, 0.0, 0.0, 0.0, b,
+                    0.0, a, 0.0, 0.0, b,
+                    0.0, 0.0, a, 0.0, b,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Grayscale(amount) => {
+                let s = 1.0 - amount.to_factor().clamp(0.0, 1.0);
+                #[rustfmt::skip]
+                let matrix = [
+                    0.2126 + 0.7874 * s, 0.7152 - 0.7152 * s, 0.0722 - 0.0722 * s, 0.0, 0.0,
+                    0.2126 - 0.2126 * s, 0.7152 + 0.2848 * s, 0.0722 - 0.0722 * s, 0.0, 0.0,
+                    0.2126 - 0.2126 * s, 0.7152 - 0.7152 * s, 0.0722 + 0.9278 * s, 0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::HueRotate(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                #[rustfmt::skip]
+                let matrix = [
+                    0.213 + cos * 0.787 - sin * 0.213,
+                    0.715 - cos * 0.715 - sin * 0.715,
+                    0.072 - cos * 0.072 + sin * 0.928,
+                    0.0, 0.0,
+                    0.213 - cos * 0.213 + sin * 0.143,
+                    0.715 + cos * 0.285 + sin * 0.140,
+                    0.072 - cos * 0.072 - sin * 0.283,
+                    0.0, 0.0,
+                    0.213 - cos * 0.213 - sin * 0.787,
+                    0.715 - cos * 0.715 + sin * 0.715,
+                    0.072 + cos * 0.928 + sin * 0.072,
+                    0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Invert(amount) => {
+                let a = amount.to_factor().clamp(0.0, 1.0);
+                let s = 1.0 - 2.0 * a;
+                #[rustfmt::skip]
+                let matrix = [
+                    s, 0.0, 0.0, 0.0, a,
+                    0.0, s, 0.0, 0.0, a,
+                    0.0, 0.0, s, 0.0, a,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Opacity(amount) => {
+                let a = amount.to_factor().clamp(0.0, 1.0);
+                #[rustfmt::skip]
+                let matrix = [
+                    1.0, 0.0, 0.0, 0.0, 0.0,
+                    0.0, 1.0, 0.0, 0.0, 0.0,
+                    0.0, 0.0, 1.0, 0.0, 0.0,
+                    0.0, 0.0, 0.0, a, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Saturate(amount) => {
+                let s = amount.to_factor().max(0.0);
+                #[rustfmt::skip]
+                let matrix = [
+                    0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+                    0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+                    0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s, 0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+
+            Filter::Sepia(amount) => {
+                let s = 1.0 - amount.to_factor().clamp(0.0, 1.0);
+                #[rustfmt::skip]
+                let matrix = [
+                    0.393 + 0.607 * s, 0.769 - 0.769 * s, 0.189 - 0.189 * s, 0.0, 0.0,
+                    0.349 - 0.349 * s, 0.686 + 0.314 * s, 0.168 - 0.168 * s, 0.0, 0.0,
+                    0.272 - 0.272 * s, 0.534 - 0.534 * s, 0.131 + 0.869 * s, 0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ];
+                color_matrix(matrix)
+            }
+        };
+
+        next.or(input)
+    })
+}
@@ -149,17 +149,35 @@ fn hover_entity(
     let t = t.map_point((cursor_x, cursor_y));
     let tx = t.x;
     let ty = t.y;
-    let clipping = clip_bounds.intersection(&cx.clip_region());
+    let mut clipping = clip_bounds.intersection(&cx.clip_region());
 
-    let b = bounds.intersection(&clipping);
-    // let b = bounds;
+    // A cursor outside of the clip shape of the view cannot hover it or its descendants.
+    if cx
+        .style
+        .clip_path
+        .get(cx.current)
+        .and_then(|clip| clip.as_clip_shape(bounds, cx.scale_factor()))
+        .is_some_and(|shape| !shape.contains((tx, ty)))
+    {
+        clipping = BoundingBox::default();
+    }
+
+    let contains =
+        |b: &BoundingBox| tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom();
+
+    // A text span which wraps across lines is only hovered over the glyphs of each line, rather than
+    // anywhere within its overall bounds.
+    let is_over = match cx.cache.text_span_bounds.get(cx.current) {
+        Some(rects) => rects.iter().any(|rect| contains(&rect.intersection(&clipping))),
+        None => contains(&bounds.intersection(&clipping)),
+    };
 
     if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
         pseudo_classes.set(PseudoClassFlags::HOVER, false);
     }
 
     if pointer_events {
-        if tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom() {
+        if is_over {
             *hovered = cx.current;
 
             if !cx
@@ -222,3 +240,53 @@ impl PartialEq for ZEntity {
 }
 
 impl Eq for ZEntity {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_hovered(cx: &Context, entity: Entity) -> bool {
+        cx.style
+            .pseudo_classes
+            .get(entity)
+            .is_some_and(|flags| flags.contains(PseudoClassFlags::HOVER))
+    }
+
+    #[test]
+    fn wrapped_link_is_hovered_only_over_its_lines() {
+        let cx = &mut Context::default();
+        let label = Label::rich(cx, "Visit ", |cx| {
+            TextSpan::link(cx, "https://github.com/vizia/vizia", |_| {});
+        })
+        .entity();
+        let link = cx.tree.get_first_child(label).unwrap();
+
+        // The link wraps from the end of the first line to the start of the second line.
+        let root_bounds = BoundingBox::from_min_max(0.0, 0.0, 200.0, 200.0);
+        let bounds = BoundingBox::from_min_max(0.0, 0.0, 100.0, 20.0);
+        cx.cache.set_bounds(Entity::root(), root_bounds);
+        cx.cache.set_bounds(label, bounds);
+        cx.cache.set_bounds(link, bounds);
+        cx.cache.text_span_bounds.insert(
+            link,
+            vec![
+                BoundingBox::from_min_max(50.0, 0.0, 100.0, 10.0),
+                BoundingBox::from_min_max(0.0, 10.0, 30.0, 20.0),
+            ],
+        );
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(Entity::root()) {
+            pseudo_classes.set(PseudoClassFlags::OVER, true);
+        }
+
+        for (x, y, link_hovered) in [(60.0, 5.0, true), (10.0, 15.0, true), (60.0, 15.0, false)] {
+            cx.mouse.cursor_x = x;
+            cx.mouse.cursor_y = y;
+            hover_system(cx, Entity::root());
+
+            assert_eq!(cx.hovered == link, link_hovered);
+            assert_eq!(is_hovered(cx, link), link_hovered);
+            // The label is hovered whether or not the cursor is over the link.
+            assert!(is_hovered(cx, label));
+        }
+    }
+}
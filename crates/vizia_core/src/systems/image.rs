@@ -23,6 +23,25 @@ pub(crate) fn image_system(cx: &mut Context) {
                 }
             }
         }
+
+        // Load a mask-image if the entity has one
+        if let Some(mask_images) = cx.style.mask_image.get(entity).cloned() {
+            for image in mask_images.iter() {
+                if let ImageOrGradient::Image(name) = image {
+                    load_image(cx, entity, name);
+                }
+            }
+        }
+
+        // Load any images generated by the `::before` and `::after` pseudo-elements of the entity
+        if let Some(rules) = cx.style.pseudo_elements.get(entity).cloned() {
+            for rules in [rules.before, rules.after] {
+                let content = rules.iter().find_map(|rule| cx.style.content.get_shared(*rule));
+                if let Some(Content::Url(name)) = content.cloned() {
+                    load_image(cx, entity, &name);
+                }
+            }
+        }
     }
 
     cx.resource_manager.evict_unused_images();
@@ -91,6 +91,8 @@ pub(crate) fn layout_system(cx: &mut Context) {
                 // TODO: Use geo changed to determine whether an entity needs to be redrawn.
 
                 if !geo.is_empty() {
+                    cx.profiler.current.relaid_out += 1;
+
                     let mut event = Event::new(WindowEvent::GeometryChanged(geo))
                         .target(entity)
                         .origin(entity)
@@ -1,7 +1,7 @@
-use crate::{cache::CachedData, prelude::*};
+use crate::{cache::CachedData, prelude::*, style::PseudoElementRules};
 #[cfg(feature = "rayon")]
 use dashmap::{DashMap, ReadOnlyView};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 use vizia_storage::{LayoutParentIterator, TreeBreadthIterator};
@@ -16,7 +16,8 @@ use vizia_style::{
         parser::{Component, NthType},
         OpaqueElement, SelectorImpl,
     },
-    Element, MatchingContext, MatchingMode, PseudoClass, QuirksMode, SelectorIdent, Selectors,
+    Element, MatchingContext, MatchingMode, PseudoClass, PseudoElement, QuirksMode, SelectorIdent,
+    Selectors,
 };
 
 /// A node used for style matching.
@@ -25,6 +26,14 @@ pub(crate) struct Node<'s, 't> {
     entity: Entity,
     store: &'s Style,
     tree: &'t Tree<Entity>,
+    // The pseudo-element of the entity which is being matched, if any.
+    pseudo_element: Option<PseudoElement>,
+}
+
+impl<'s, 't> Node<'s, 't> {
+    fn new(entity: Entity, store: &'s Style, tree: &'t Tree<Entity>) -> Self {
+        Node { entity, store, tree, pseudo_element: None }
+    }
 }
 
 impl std::fmt::Debug for Node<'_, '_> {
@@ -54,31 +63,38 @@ impl Element for Node<'_, '_> {
     }
 
     fn parent_element(&self) -> Option<Self> {
-        self.tree.get_layout_parent(self.entity).map(|parent| Node {
-            entity: parent,
-            store: self.store,
-            tree: self.tree,
-        })
+        // The parent of a pseudo-element is its originating element.
+        if self.pseudo_element.is_some() {
+            return Some(Node::new(self.entity, self.store, self.tree));
+        }
+
+        self.tree
+            .get_layout_parent(self.entity)
+            .map(|parent| Node::new(parent, self.store, self.tree))
     }
 
     fn prev_sibling_element(&self) -> Option<Self> {
-        self.tree.get_prev_layout_sibling(self.entity).map(|parent| Node {
-            entity: parent,
-            store: self.store,
-            tree: self.tree,
-        })
+        if self.pseudo_element.is_some() {
+            return None;
+        }
+
+        self.tree
+            .get_prev_layout_sibling(self.entity)
+            .map(|sibling| Node::new(sibling, self.store, self.tree))
     }
 
     fn next_sibling_element(&self) -> Option<Self> {
-        self.tree.get_next_layout_sibling(self.entity).map(|parent| Node {
-            entity: parent,
-            store: self.store,
-            tree: self.tree,
-        })
+        if self.pseudo_element.is_some() {
+            return None;
+        }
+
+        self.tree
+            .get_next_layout_sibling(self.entity)
+            .map(|sibling| Node::new(sibling, self.store, self.tree))
     }
 
     fn is_empty(&self) -> bool {
-        !self.tree.has_children(self.entity)
+        self.pseudo_element.is_some() || !self.tree.has_children(self.entity)
     }
 
     fn is_root(&self) -> bool {
@@ -113,7 +129,7 @@ impl Element for Node<'_, '_> {
     }
 
     fn is_pseudo_element(&self) -> bool {
-        false
+        self.pseudo_element.is_some()
     }
 
     fn is_same_type(&self, other: &Self) -> bool {
@@ -156,19 +172,29 @@ impl Element for Node<'_, '_> {
 
     fn attr_matches(
         &self,
-        _ns: &NamespaceConstraint<&<Self::Impl as SelectorImpl>::NamespaceUrl>,
-        _local_name: &<Self::Impl as SelectorImpl>::LocalName,
-        _operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
+        ns: &NamespaceConstraint<&<Self::Impl as SelectorImpl>::NamespaceUrl>,
+        local_name: &<Self::Impl as SelectorImpl>::LocalName,
+        operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
     ) -> bool {
-        false
+        // Attributes of views have no namespace.
+        if let NamespaceConstraint::Specific(url) = ns {
+            if !url.0.is_empty() {
+                return false;
+            }
+        }
+
+        self.store
+            .get_attribute(self.entity, &local_name.0)
+            .map(|value| operation.eval_str(value))
+            .unwrap_or_default()
     }
 
     fn match_pseudo_element(
         &self,
-        _pe: &<Self::Impl as SelectorImpl>::PseudoElement,
+        pe: &<Self::Impl as SelectorImpl>::PseudoElement,
         _context: &mut MatchingContext<'_, Self::Impl>,
     ) -> bool {
-        false
+        self.pseudo_element.as_ref() == Some(pe)
     }
 
     fn match_non_ts_pseudo_class(
@@ -217,12 +243,19 @@ impl Element for Node<'_, '_> {
                 PseudoClass::UserInvalid => {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
-                PseudoClass::Lang(_) => todo!(),
-                PseudoClass::Dir(_) => todo!(),
-                PseudoClass::Custom(name) => {
-                    println!("custom: {}", name);
-                    todo!()
+                PseudoClass::Lang(langs) => {
+                    let language = self.store.get_language(self.entity, self.tree);
+                    !fluent_langneg::negotiate::filter_matches(
+                        &[language],
+                        langs,
+                        fluent_langneg::NegotiationStrategy::Filtering,
+                    )
+                    .is_empty()
+                }
+                PseudoClass::Dir(direction) => {
+                    self.store.get_direction(self.entity, self.tree) == *direction
                 }
+                PseudoClass::Custom(name) => self.store.has_custom_state(self.entity, name),
             }
         } else {
             false
@@ -235,8 +268,8 @@ impl Element for Node<'_, '_> {
 
     fn apply_selector_flags(&self, _flags: ElementSelectorFlags) {}
 
-    fn has_custom_state(&self, _name: &<Self::Impl as SelectorImpl>::Identifier) -> bool {
-        false
+    fn has_custom_state(&self, name: &<Self::Impl as SelectorImpl>::Identifier) -> bool {
+        self.store.has_custom_state(self.entity, &name.0)
     }
 
     fn add_element_unique_hashes(
@@ -254,6 +287,8 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
             if cx.style.disabled.inherit_inline(entity, parent)
                 | cx.style.caret_color.inherit_inline(entity, parent)
                 | cx.style.selection_color.inherit_inline(entity, parent)
+                | cx.style.selection_text_color.inherit_inline(entity, parent)
+                | cx.style.font_gradient.inherit_inline(entity, parent)
             {
                 redraw_entities.push(entity);
             }
@@ -268,6 +303,12 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_stroke_width.inherit_inline(entity, parent)
                 | cx.style.text_stroke_style.inherit_inline(entity, parent)
                 | cx.style.font_variation_settings.inherit_inline(entity, parent)
+                | cx.style.font_feature_settings.inherit_inline(entity, parent)
+                | cx.style.line_height.inherit_inline(entity, parent)
+                | cx.style.letter_spacing.inherit_inline(entity, parent)
+                | cx.style.word_spacing.inherit_inline(entity, parent)
+                | cx.style.text_shadow.inherit_inline(entity, parent)
+                | cx.style.text_transform.inherit_inline(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
@@ -289,12 +330,20 @@ pub(crate) fn shared_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_stroke_width.inherit_shared(entity, parent)
                 | cx.style.text_stroke_style.inherit_shared(entity, parent)
                 | cx.style.font_variation_settings.inherit_shared(entity, parent)
+                | cx.style.font_feature_settings.inherit_shared(entity, parent)
+                | cx.style.line_height.inherit_shared(entity, parent)
+                | cx.style.letter_spacing.inherit_shared(entity, parent)
+                | cx.style.word_spacing.inherit_shared(entity, parent)
+                | cx.style.text_shadow.inherit_shared(entity, parent)
+                | cx.style.text_transform.inherit_shared(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
 
             if cx.style.caret_color.inherit_shared(entity, parent)
                 | cx.style.selection_color.inherit_shared(entity, parent)
+                | cx.style.selection_text_color.inherit_shared(entity, parent)
+                | cx.style.font_gradient.inherit_shared(entity, parent)
             {
                 redraw_entities.push(entity);
             }
@@ -341,6 +390,14 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.mask_image.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.backdrop_filter.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -455,17 +512,63 @@ fn link_style_data(
     }
 
     // Border
-    if style.border_width.link(entity, matched_rules) {
+    if style.border_top_width.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
         cache.path.remove(entity);
     }
 
-    if style.border_color.link(entity, matched_rules) {
+    if style.border_right_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        cache.path.remove(entity);
+    }
+
+    if style.border_bottom_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        cache.path.remove(entity);
+    }
+
+    if style.border_left_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        cache.path.remove(entity);
+    }
+
+    if style.border_top_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_gradient.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_top_style.link(entity, matched_rules) {
         should_redraw = true;
     }
 
-    if style.border_style.link(entity, matched_rules) {
+    if style.border_right_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_style.link(entity, matched_rules) {
         should_redraw = true;
     }
 
@@ -533,6 +636,10 @@ fn link_style_data(
     // Background
     if style.background_color.link(entity, matched_rules) {
         should_redraw = true;
+        // The background of a text span is drawn as part of the paragraph of its parent.
+        if style.text_span.get(entity).copied().unwrap_or_default() {
+            should_reflow = true;
+        }
     }
 
     if style.background_image.link(entity, matched_rules) {
@@ -549,6 +656,10 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.font_gradient.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.font_size.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
@@ -585,6 +696,41 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.font_feature_settings.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.line_height.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.letter_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.word_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.text_transform.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.text_shadow.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
     if style.text_wrap.link(entity, matched_rules) {
         should_redraw = true;
         should_relayout = true;
@@ -610,6 +756,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.selection_text_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.caret_color.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -702,6 +852,23 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    // Animations
+    let mut should_animate = false;
+
+    should_animate |= style.animation_name.link(entity, matched_rules);
+    should_animate |= style.animation_duration.link(entity, matched_rules);
+    should_animate |= style.animation_delay.link(entity, matched_rules);
+    should_animate |= style.animation_timing_function.link(entity, matched_rules);
+    should_animate |= style.animation_iteration_count.link(entity, matched_rules);
+    should_animate |= style.animation_direction.link(entity, matched_rules);
+    should_animate |= style.animation_fill_mode.link(entity, matched_rules);
+    should_animate |= style.animation_play_state.link(entity, matched_rules);
+
+    if should_animate {
+        style.update_declared_animation(entity);
+        should_redraw = true;
+    }
+
     // Transform
     if style.transform.link(entity, matched_rules) {
         should_redraw = true;
@@ -747,7 +914,30 @@ fn link_style_data(
     }
 }
 
+/// Links an entity to the rules matched by its pseudo-elements, rebuilding its text if they changed.
+fn link_pseudo_element_rules(
+    style: &mut Style,
+    entity: Entity,
+    redraw_entities: &mut Vec<Entity>,
+    rules: PseudoElementRules,
+) {
+    if style.pseudo_elements.get(entity).map_or(rules.is_empty(), |linked| *linked == rules) {
+        return;
+    }
+
+    if rules.is_empty() {
+        style.pseudo_elements.remove(entity);
+    } else {
+        style.pseudo_elements.insert(entity, rules);
+    }
+
+    style.needs_text_update(entity);
+    redraw_entities.push(entity);
+}
+
 /// Compute a list of matching style rules for a given entity.
+///
+/// This includes any `::selection` rules, which style the selected text of the entity.
 pub(crate) fn compute_matched_rules(
     entity: Entity,
     store: &Style,
@@ -766,10 +956,20 @@ pub(crate) fn compute_matched_rules(
         MatchingForInvalidation::No,
     );
 
-    let node = Node { entity, store, tree };
+    let node = Node::new(entity, store, tree);
 
     for (rule_id, rule) in store.rules.iter() {
-        let matches = matches_selector(&rule.selector, 0, Some(&rule.hashes), &node, &mut context);
+        let matches = match rule.selector.pseudo_element() {
+            None => matches_selector(&rule.selector, 0, Some(&rule.hashes), &node, &mut context),
+
+            Some(pseudo_element @ PseudoElement::Selection) => {
+                let node = Node { pseudo_element: Some(pseudo_element.clone()), ..node.clone() };
+                matches_selector(&rule.selector, 0, Some(&rule.hashes), &node, &mut context)
+            }
+
+            // Other pseudo-elements are matched by `compute_pseudo_element_rules`.
+            _ => false,
+        };
 
         if matches {
             matched_rules.push((*rule_id, rule.selector.specificity()));
@@ -781,6 +981,60 @@ pub(crate) fn compute_matched_rules(
     matched_rules
 }
 
+/// Compute the style rules matched by the `::before`, `::after` and `::placeholder` pseudo-elements
+/// of a given entity.
+pub(crate) fn compute_pseudo_element_rules(
+    entity: Entity,
+    store: &Style,
+    tree: &Tree<Entity>,
+    bloom: &BloomFilter,
+) -> PseudoElementRules {
+    let mut before = Vec::new();
+    let mut after = Vec::new();
+    let mut placeholder = Vec::new();
+
+    let mut cache = SelectorCaches::default();
+    let mut context = MatchingContext::new(
+        MatchingMode::Normal,
+        Some(bloom),
+        &mut cache,
+        QuirksMode::NoQuirks,
+        NeedsSelectorFlags::Yes,
+        MatchingForInvalidation::No,
+    );
+
+    for rule_id in store.pseudo_element_rules.iter() {
+        let Some(rule) = store.rules.get(rule_id) else { continue };
+
+        let matched_rules = match rule.selector.pseudo_element() {
+            Some(PseudoElement::Before) => &mut before,
+            Some(PseudoElement::After) => &mut after,
+            Some(PseudoElement::Placeholder) => &mut placeholder,
+            _ => continue,
+        };
+
+        let node = Node {
+            pseudo_element: rule.selector.pseudo_element().cloned(),
+            ..Node::new(entity, store, tree)
+        };
+
+        if matches_selector(&rule.selector, 0, Some(&rule.hashes), &node, &mut context) {
+            matched_rules.push((*rule_id, rule.selector.specificity()));
+        }
+    }
+
+    let sorted = |mut matched_rules: Vec<(Rule, u32)>| {
+        matched_rules.sort_by_key(|(_, s)| *s);
+        matched_rules.into_iter().rev().map(|(rule, _)| rule).collect()
+    };
+
+    PseudoElementRules {
+        before: sorted(before),
+        after: sorted(after),
+        placeholder: sorted(placeholder),
+    }
+}
+
 fn has_same_selector(style: &Style, entity1: Entity, entity2: Entity) -> bool {
     if let Some(element1) = style.element.get(entity1) {
         if let Some(element2) = style.element.get(entity2) {
@@ -813,6 +1067,25 @@ fn has_same_selector(style: &Style, entity1: Entity, entity2: Entity) -> bool {
         }
     }
 
+    if style.attributes.get(entity1).filter(|attributes| !attributes.is_empty())
+        != style.attributes.get(entity2).filter(|attributes| !attributes.is_empty())
+    {
+        return false;
+    }
+
+    if style.language.get(entity1) != style.language.get(entity2)
+        || style.direction.get(entity1) != style.direction.get(entity2)
+    {
+        return false;
+    }
+
+    let empty_states = HashSet::new();
+    let states1 = style.custom_states.get(entity1).unwrap_or(&empty_states);
+    let states2 = style.custom_states.get(entity2).unwrap_or(&empty_states);
+    if states1 != states2 {
+        return false;
+    }
+
     true
 }
 
@@ -829,12 +1102,16 @@ fn has_nth_child_rule(style: &Style, rules: &[(Rule, u32)]) -> bool {
     false
 }
 
+/// Fills the bloom filter with the hashes of the ancestors of an entity, replacing the hashes of the
+/// ancestors of the entity it was previously filled for.
 pub(crate) fn compute_element_hash(
     entity: Entity,
     tree: &Tree<Entity>,
     style: &Style,
     bloom: &mut BloomFilter,
 ) {
+    bloom.clear();
+
     let parent_iter = LayoutParentIterator::new(tree, entity);
 
     for ancestor in parent_iter {
@@ -851,6 +1128,12 @@ pub(crate) fn compute_element_hash(
                 bloom.insert_hash(fxhash::hash32(class));
             }
         }
+
+        if let Some(attributes) = style.attributes.get(ancestor) {
+            for name in attributes.keys() {
+                bloom.insert_hash(fxhash::hash32(name));
+            }
+        }
     }
 }
 
@@ -974,6 +1257,8 @@ pub(crate) fn style_system(cx: &mut Context) {
         .filter(|e| cx.style.restyle.contains(*e))
         .collect::<Vec<_>>();
 
+    cx.profiler.current.restyled += entities.len();
+
     let matched_rules = {
         #[cfg(feature = "rayon")]
         {
@@ -986,23 +1271,119 @@ pub(crate) fn style_system(cx: &mut Context) {
     };
 
     //  Apply matched rules to entities
-    for entity in entities {
-        if let Some(matched_rules) = matched_rules.get(&entity) {
+    for entity in entities.iter() {
+        if let Some(matched_rules) = matched_rules.get(entity) {
             link_style_data(
                 &mut cx.style,
                 &mut cx.cache,
                 &cx.tree,
-                entity,
+                *entity,
                 &mut redraw_entities,
                 matched_rules,
             );
         }
     }
+
+    // Pseudo-element rules are only matched when a stylesheet declares some.
+    if !cx.style.pseudo_element_rules.is_empty() || !cx.style.pseudo_elements.is_empty() {
+        let filter = &mut BloomFilter::default();
+        for entity in entities.iter().copied() {
+            compute_element_hash(entity, &cx.tree, &cx.style, filter);
+            let rules = compute_pseudo_element_rules(entity, &cx.style, &cx.tree, filter);
+            link_pseudo_element_rules(&mut cx.style, entity, &mut redraw_entities, rules);
+        }
+    }
+
     cx.style.restyle.clear();
 
+    // Views which show the style of other views, such as the inspector, are told which were restyled.
+    cx.event_queue.push_back(
+        Event::new(WindowEvent::StyleChanged(entities))
+            .target(Entity::root())
+            .propagate(Propagation::Direct),
+    );
+
     shared_inheritance_system(cx, &mut redraw_entities);
 
     for entity in redraw_entities {
         cx.needs_redraw(entity);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::Element;
+
+    /// Adds the stylesheet and restyles every view.
+    fn restyle(cx: &mut Context, stylesheet: &'static str) {
+        cx.add_stylesheet(stylesheet).unwrap();
+        style_system(cx);
+    }
+
+    #[test]
+    fn custom_state_matches_pseudo_class() {
+        let cx = &mut Context::default();
+        let armed = Element::new(cx).custom_state("armed", true).entity();
+        let unarmed = Element::new(cx).custom_state("armed", false).entity();
+
+        restyle(cx, "element:armed { background-color: red; }");
+        assert_eq!(cx.style.background_color.get(armed), Some(&Color::red()));
+        assert_eq!(cx.style.background_color.get(unarmed), None);
+
+        // Removing the state restyles the view without the rule.
+        if cx.style.set_custom_state(armed, "armed", false) {
+            cx.style.needs_restyle(armed);
+        }
+        style_system(cx);
+        assert_eq!(cx.style.background_color.get(armed), None);
+    }
+
+    #[test]
+    fn attributes_match_attribute_selectors() {
+        let cx = &mut Context::default();
+        let level = Element::new(cx).attr("data-level", 3).entity();
+        let kind = Element::new(cx).attr("kind", "large primary").entity();
+        let locale = Element::new(cx).attr("locale", "en-GB").entity();
+        let other = Element::new(cx).attr("data-level", 4).entity();
+
+        restyle(
+            cx,
+            r#"
+            element[data-level="3"] { background-color: red; }
+            element[kind~="primary"] { background-color: green; }
+            element[locale|="en"] { background-color: blue; }
+            "#,
+        );
+        assert_eq!(cx.style.background_color.get(level), Some(&Color::red()));
+        assert_eq!(cx.style.background_color.get(kind), Some(&Color::green()));
+        assert_eq!(cx.style.background_color.get(locale), Some(&Color::blue()));
+        assert_eq!(cx.style.background_color.get(other), None);
+    }
+
+    #[test]
+    fn language_and_direction_match_lang_and_dir() {
+        let cx = &mut Context::default();
+        let mut child = Entity::null();
+        let parent = Element::new(cx).lang(langid!("fr-CA")).entity();
+        cx.with_current(parent, |cx| child = Element::new(cx).entity());
+        let arabic = Element::new(cx).lang(langid!("ar")).entity();
+        let ltr_arabic = Element::new(cx).lang(langid!("ar")).direction(Direction::Ltr).entity();
+
+        restyle(
+            cx,
+            r#"
+            element:lang(fr) { background-color: red; }
+            element:dir(rtl) { background-color: green; }
+            "#,
+        );
+
+        // The language range matches more specific languages, which are inherited.
+        assert_eq!(cx.style.background_color.get(parent), Some(&Color::red()));
+        assert_eq!(cx.style.background_color.get(child), Some(&Color::red()));
+
+        // The direction follows the language unless it is set.
+        assert_eq!(cx.style.background_color.get(arabic), Some(&Color::green()));
+        assert_eq!(cx.style.background_color.get(ltr_arabic), None);
+    }
+}
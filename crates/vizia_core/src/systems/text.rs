@@ -1,14 +1,22 @@
+use std::borrow::Cow;
+
 use skia_safe::{
     font_arguments::VariationPosition,
     textlayout::{
         FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, RectHeightStyle,
-        RectWidthStyle, TextStyle,
+        RectWidthStyle, TextAlign, TextShadow, TextStyle,
     },
     BlendMode, FontArguments, FontStyle, Paint,
 };
+use unicode_segmentation::UnicodeSegmentation;
 use vizia_storage::{LayoutChildIterator, LayoutTreeIterator};
 
-use crate::{cache::CachedData, prelude::*};
+use crate::{
+    cache::CachedData,
+    prelude::*,
+    style::PseudoClassFlags,
+    text::{GeneratedContent, OffsetMap, TextContext, TextDocument, TokenKind},
+};
 
 pub(crate) fn text_system(cx: &mut Context) {
     let iterator = LayoutTreeIterator::full(&cx.tree);
@@ -17,16 +25,60 @@ pub(crate) fn text_system(cx: &mut Context) {
             continue;
         }
 
-        if cx.style.text.contains(entity)
-            && cx.style.display.get(entity).copied().unwrap_or_default() != Display::None
-        {
-            if let Some(paragraph) =
-                build_paragraph(entity, &mut cx.style, &cx.tree, cx.text_context.font_collection())
-            {
-                cx.text_context.text_paragraphs.insert(entity, paragraph);
-                cx.style.needs_relayout();
-                cx.style.needs_text_layout(entity);
+        let displayed = cx.style.display.get(entity).copied().unwrap_or_default() != Display::None;
+
+        // The text of documents is laid out line by line, so it has no generated content.
+        let (before, after) = match cx.style.pseudo_elements.get(entity) {
+            Some(rules) if displayed && !cx.text_context.text_documents.contains(entity) => (
+                build_generated_content(&cx.style, entity, &rules.before),
+                build_generated_content(&cx.style, entity, &rules.after),
+            ),
+            _ => (None, None),
+        };
+
+        let has_generated_content = before.is_some() || after.is_some();
+
+        for (generated, content) in [
+            (&mut cx.text_context.before_content, before),
+            (&mut cx.text_context.after_content, after),
+        ] {
+            match content {
+                Some(content) => generated.insert(entity, content),
+                None => {
+                    generated.remove(entity);
+                }
+            }
+        }
+
+        if (cx.style.text.contains(entity) || has_generated_content) && displayed {
+            let TextContext { font_collection, before_content, after_content, .. } =
+                &cx.text_context;
+            let (paragraph, offset_map) = build_paragraph_with_content(
+                entity,
+                &mut cx.style,
+                &cx.tree,
+                font_collection,
+                before_content.get(entity),
+                after_content.get(entity),
+            );
+            cx.text_context.text_paragraphs.insert(entity, paragraph);
+            match offset_map {
+                Some(offset_map) => cx.text_context.offset_maps.insert(entity, offset_map),
+                None => {
+                    cx.text_context.offset_maps.remove(entity);
+                }
             }
+            cx.style.needs_relayout();
+            cx.style.needs_text_layout(entity);
+        } else if let Some(document) = cx.text_context.text_documents.get_mut(entity) {
+            document.discard_stale_paragraphs();
+            cx.style.needs_text_layout(entity);
+        } else if !cx.style.text.contains(entity)
+            && cx.text_context.text_paragraphs.remove(entity).is_some()
+        {
+            // The paragraph only held generated content which has since been removed.
+            cx.text_context.offset_maps.remove(entity);
+            cx.style.needs_relayout();
         }
     }
 
@@ -82,7 +134,16 @@ pub(crate) fn text_layout_system(cx: &mut Context) {
                 }
             }
 
-            layout_span(&cx.style, &mut cx.cache, &cx.tree, entity, paragraph, bounds);
+            let origin = paragraph_origin(&cx.style, entity, bounds, paragraph);
+            layout_span(&cx.style, &mut cx.cache, &cx.tree, entity, paragraph, origin);
+
+            redraw_entities.push(entity);
+        } else if cx.text_context.text_documents.contains(entity) {
+            let bounds = cx.cache.get_bounds(entity);
+            let TextContext { font_collection, text_documents, .. } = &mut cx.text_context;
+            if let Some(document) = text_documents.get_mut(entity) {
+                layout_document(&cx.style, &cx.tree, font_collection, entity, document, bounds);
+            }
 
             redraw_entities.push(entity);
         }
@@ -93,55 +154,97 @@ pub(crate) fn text_layout_system(cx: &mut Context) {
     cx.style.text_layout.clear();
 }
 
+/// Computes the bounds of the text spans of a paragraph, and of each line of the spans, from the glyphs they cover.
+///
+/// Returns the rects of the lines covered by the entity and its child spans, in window coordinates.
 pub fn layout_span(
     style: &Style,
     cache: &mut CachedData,
     tree: &Tree<Entity>,
     entity: Entity,
     paragraph: &Paragraph,
-    paragraph_bounds: BoundingBox,
-) -> BoundingBox {
-    let mut bounds = BoundingBox::default();
-    if style.text_span.get(entity).copied().unwrap_or_default() {
+    origin: (f32, f32),
+) -> Vec<BoundingBox> {
+    let is_text_span = style.text_span.get(entity).copied().unwrap_or_default();
+
+    let mut rects = Vec::new();
+    if is_text_span {
         if let Some(range) = style.text_range.get(entity) {
-            let rects = paragraph.get_rects_for_range(
-                range.clone(),
-                RectHeightStyle::Tight,
-                RectWidthStyle::Tight,
+            rects.extend(
+                paragraph
+                    .get_rects_for_range(
+                        range.clone(),
+                        RectHeightStyle::Tight,
+                        RectWidthStyle::Tight,
+                    )
+                    .iter()
+                    .map(|text_box| {
+                        BoundingBox::from_min_max(
+                            origin.0 + text_box.rect.left(),
+                            origin.1 + text_box.rect.top(),
+                            origin.0 + text_box.rect.right(),
+                            origin.1 + text_box.rect.bottom(),
+                        )
+                    }),
             );
-
-            let min_x = rects.iter().fold(1000000.0f32, |min, item| min.min(item.rect.x()));
-            let min_y = rects.iter().fold(1000000.0f32, |min, item| min.min(item.rect.y()));
-            let max_x = rects.iter().fold(0.0f32, |max, item| max.max(item.rect.right()));
-            let max_y = rects.iter().fold(0.0f32, |max, item| max.max(item.rect.bottom()));
-
-            bounds = BoundingBox::from_min_max(min_x, min_y, max_x, max_y);
         }
     }
 
     let iter = LayoutChildIterator::new(tree, entity);
     for child in iter {
-        if bounds.width() == 0.0 && bounds.height() == 0.0 {
-            bounds = layout_span(style, cache, tree, child, paragraph, paragraph_bounds);
-        } else {
-            bounds =
-                bounds.union(&layout_span(style, cache, tree, child, paragraph, paragraph_bounds));
+        if style.text_span.get(child).copied().unwrap_or_default() {
+            rects.extend(layout_span(style, cache, tree, child, paragraph, origin));
         }
     }
 
-    if style.text_span.get(entity).copied().unwrap_or_default() {
-        cache.bounds.insert(
-            entity,
-            BoundingBox::from_min_max(
-                paragraph_bounds.x + bounds.x,
-                paragraph_bounds.y + bounds.y,
-                paragraph_bounds.x + bounds.right(),
-                paragraph_bounds.y + bounds.bottom(),
-            ),
-        );
+    if is_text_span {
+        let bounds = rects
+            .iter()
+            .skip(1)
+            .fold(rects.first().copied().unwrap_or_default(), |bounds, rect| bounds.union(rect));
+        cache.bounds.insert(entity, bounds);
+        cache.text_span_bounds.insert(entity, rects.clone());
     }
 
-    bounds
+    rects
+}
+
+/// Returns the position at which the paragraph of an entity is painted, i.e. the top-left corner of its
+/// bounds offset by its pixel padding and by the vertical alignment of the text.
+pub(crate) fn paragraph_origin(
+    style: &Style,
+    entity: Entity,
+    bounds: BoundingBox,
+    paragraph: &Paragraph,
+) -> (f32, f32) {
+    let scale_factor = style.scale_factor();
+    let padding = |padding: Option<&Units>| match padding {
+        Some(Units::Pixels(val)) => *val * scale_factor,
+        _ => 0.0,
+    };
+
+    let padding_left = padding(style.padding_left.get(entity));
+    let padding_top = padding(style.padding_top.get(entity));
+    let padding_bottom = padding(style.padding_bottom.get(entity));
+
+    let top = match style.alignment.get(entity).copied().unwrap_or_default() {
+        Alignment::TopLeft | Alignment::TopCenter | Alignment::TopRight => 0.0,
+        Alignment::Left | Alignment::Center | Alignment::Right => 0.5,
+        Alignment::BottomLeft | Alignment::BottomCenter | Alignment::BottomRight => 1.0,
+    } * (bounds.height() - padding_top - padding_bottom - paragraph.height());
+
+    ((bounds.x + padding_left).round(), (bounds.y + padding_top + top).round())
+}
+
+/// Returns the plain text of the paragraph of an entity, i.e. its text followed by the text of its spans.
+pub(crate) fn paragraph_text(style: &Style, tree: &Tree<Entity>, entity: Entity) -> String {
+    let mut text = style.text.get(entity).cloned().unwrap_or_default();
+    for child in LayoutChildIterator::new(tree, entity) {
+        if style.text_span.get(child).copied().unwrap_or_default() {
+            text.push_str(&paragraph_text(style, tree, child));
+        }
+    }
+    text
 }
 
 pub fn build_paragraph(
@@ -150,6 +253,166 @@ pub fn build_paragraph(
     tree: &Tree<Entity>,
     font_collection: &FontCollection,
 ) -> Option<Paragraph> {
+    Some(build_paragraph_with_content(entity, style, tree, font_collection, None, None).0)
+}
+
+/// Builds the paragraph of an entity, with the content generated by its `::before` and `::after`
+/// pseudo-elements laid out inline before and after its text. The text ranges of the entity and its
+/// spans start after the content generated before them.
+///
+/// The offset map of the paragraph is also returned if its text changed length when it was masked or
+/// transformed.
+fn build_paragraph_with_content(
+    entity: Entity,
+    style: &mut Style,
+    tree: &Tree<Entity>,
+    font_collection: &FontCollection,
+    before: Option<&GeneratedContent>,
+    after: Option<&GeneratedContent>,
+) -> (Paragraph, Option<OffsetMap>) {
+    let paragraph_style = build_paragraph_style(style, entity);
+    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+
+    let mut cursor = ParagraphCursor::default();
+    if let Some(before) = before {
+        before.add_to(&mut paragraph_builder);
+        cursor.index += before.len();
+    }
+
+    add_block(style, tree, entity, &mut paragraph_builder, &mut cursor);
+    cursor.offset_map.push(cursor.offset, cursor.index);
+
+    if let Some(after) = after {
+        after.add_to(&mut paragraph_builder);
+    }
+
+    paragraph_builder.add_text("\u{200B}");
+    (paragraph_builder.build(), cursor.mapped.then_some(cursor.offset_map))
+}
+
+/// Returns the bounds of an entity shrunk by its padding.
+pub(crate) fn padded_bounds(style: &Style, entity: Entity, bounds: BoundingBox) -> BoundingBox {
+    let scale_factor = style.scale_factor();
+    let padding = |padding: Option<&Units>, size: f32| {
+        padding.copied().unwrap_or_default().to_px(size, 0.0) * scale_factor
+    };
+
+    bounds.shrink_sides(
+        padding(style.padding_left.get(entity), bounds.width()),
+        padding(style.padding_top.get(entity), bounds.height()),
+        padding(style.padding_right.get(entity), bounds.width()),
+        padding(style.padding_bottom.get(entity), bounds.height()),
+    )
+}
+
+/// Returns the bounds of the text of a document within the bounds of an entity, i.e. the bounds shrunk
+/// by the padding and by the gutter reserved for line numbers.
+pub(crate) fn document_bounds(
+    style: &Style,
+    entity: Entity,
+    bounds: BoundingBox,
+    document: &TextDocument,
+) -> BoundingBox {
+    padded_bounds(style, entity, bounds).shrink_sides(document.gutter_width(), 0.0, 0.0, 0.0)
+}
+
+/// Lays out the lines of a document which are within, or close to, its viewport.
+///
+/// Each line of the document is built as a separate paragraph using the text properties of the entity.
+/// The highlighted tokens of a line use the text properties of the child text span of the entity with
+/// the class of their kind, if it has one.
+pub(crate) fn layout_document(
+    style: &Style,
+    tree: &Tree<Entity>,
+    font_collection: &FontCollection,
+    entity: Entity,
+    document: &mut TextDocument,
+    bounds: BoundingBox,
+) {
+    let paragraph_style = build_paragraph_style(style, entity);
+    let text_style = build_text_style(style, entity);
+    let token_styles = LayoutChildIterator::new(tree, entity)
+        .filter(|child| style.text_span.get(*child).copied().unwrap_or_default())
+        .filter_map(|child| {
+            let classes = style.classes.get(child)?;
+            let kind = TokenKind::ALL.into_iter().find(|kind| classes.contains(kind.class()))?;
+            Some((kind, build_text_style(style, child)))
+        })
+        .collect::<Vec<_>>();
+
+    if document.line_numbers() {
+        // Reserve space for the widest line number, followed by a gap of one em.
+        let digits = document.line_count().to_string().len().max(2);
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+        paragraph_builder.push_style(&text_style);
+        paragraph_builder.add_text("0".repeat(digits));
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(f32::MAX);
+        document.set_gutter_width(paragraph.max_intrinsic_width() + text_style.font_size());
+    }
+
+    let document_bounds = document_bounds(style, entity, bounds, document);
+    let width = if style.text_wrap.get(entity).copied().unwrap_or(true) {
+        document_bounds.width()
+    } else {
+        f32::MAX
+    };
+
+    document.layout(width, document_bounds.height(), |text, tokens| {
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+        paragraph_builder.push_style(&text_style);
+
+        let mut offset = 0;
+        for token in tokens {
+            let Some((_, token_style)) = token_styles.iter().find(|(kind, _)| *kind == token.kind)
+            else {
+                continue;
+            };
+
+            let start = token.range.start.max(offset);
+            let Some(token_text) = text.get(start..token.range.end) else {
+                continue;
+            };
+
+            paragraph_builder.add_text(&text[offset..start]);
+            paragraph_builder.push_style(token_style);
+            paragraph_builder.add_text(token_text);
+            paragraph_builder.pop();
+            offset = token.range.end;
+        }
+
+        paragraph_builder.add_text(&text[offset..]);
+        paragraph_builder.add_text("\u{200B}");
+        paragraph_builder.build()
+    });
+}
+
+/// Builds the paragraph of a line number of a document, aligned to the right of the given width.
+pub(crate) fn build_line_number(
+    style: &Style,
+    font_collection: &FontCollection,
+    entity: Entity,
+    number: usize,
+    width: f32,
+    color: Color,
+) -> Paragraph {
+    let mut paragraph_style = build_paragraph_style(style, entity);
+    paragraph_style.set_text_align(TextAlign::Right);
+    let mut text_style = build_text_style(style, entity);
+    let mut paint = text_style.foreground();
+    paint.set_color(color);
+    text_style.set_foreground_paint(&paint);
+
+    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+    paragraph_builder.push_style(&text_style);
+    paragraph_builder.add_text(number.to_string());
+    let mut paragraph = paragraph_builder.build();
+    paragraph.layout(width);
+    paragraph
+}
+
+/// Builds the paragraph style of an entity from its text layout properties.
+fn build_paragraph_style(style: &Style, entity: Entity) -> ParagraphStyle {
     let mut paragraph_style = ParagraphStyle::default();
     // paragraph_style.turn_hinting_off();
 
@@ -197,112 +460,435 @@ pub fn build_paragraph(
         .into(),
     );
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+    paragraph_style
+}
 
-    add_block(style, tree, entity, &mut paragraph_builder, &mut 0);
+/// Builds the text style of an entity from its font and text properties.
+fn build_text_style(style: &Style, entity: Entity) -> TextStyle {
+    let mut text_style = TextStyle::new();
 
-    paragraph_builder.add_text("\u{200B}");
-    paragraph_builder.build().into()
+    let font_color = style.font_color.get(entity).cloned().unwrap_or_default();
+
+    if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
+        text_style.set_decoration_type(text_decoration_line.into());
+        text_style.set_decoration_color(font_color);
+    }
+
+    // Font Families
+    text_style.set_font_families(
+        style
+            .font_family
+            .get(entity)
+            .map(Vec::as_slice)
+            .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]),
+    );
+
+    let mut paint = Paint::default();
+    // Font Color
+    if let Some(font_color) = style.font_color.get(entity) {
+        paint.set_color(*font_color);
+        paint.set_anti_alias(false);
+        paint.set_blend_mode(BlendMode::SrcOver);
+    }
+
+    if let Some(text_stroke) = style.text_stroke_width.get(entity) {
+        paint.set_stroke_width(text_stroke.to_px().unwrap_or(0.0));
+        paint.set_style(
+            (*style.text_stroke_style.get(entity).unwrap_or(&TextStrokeStyle::default())).into(),
+        );
+    }
+
+    text_style.set_foreground_paint(&paint);
+
+    if let Some(background_color) = style.background_color.get(entity) {
+        if style.text_span.get(entity).is_some() {
+            let mut paint = Paint::default();
+            paint.set_color(*background_color);
+            paint.set_anti_alias(false);
+            paint.set_blend_mode(BlendMode::SrcOver);
+            text_style.set_background_paint(&paint);
+        }
+    }
+
+    // Font Size
+    let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
+    text_style.set_font_size(font_size * style.scale_factor());
+
+    // Font Style
+    match (
+        style.font_weight.get(entity),
+        style.font_width.get(entity),
+        style.font_slant.get(entity),
+    ) {
+        (None, None, None) => {}
+        (weight, width, slant) => {
+            text_style.set_font_style(FontStyle::new(
+                weight.copied().unwrap_or_default().into(),
+                width.copied().unwrap_or_default().into(),
+                slant.copied().unwrap_or_default().into(),
+            ));
+        }
+    }
+
+    // Font Variations
+    if let Some(coordinates) = style.font_variation_settings.get(entity) {
+        let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
+        text_style.set_font_arguments(
+            &FontArguments::new()
+                .set_variation_design_position(VariationPosition { coordinates: &coordinates }),
+        );
+    }
+
+    // Font Features
+    if let Some(features) = style.font_feature_settings.get(entity) {
+        for feature in features {
+            text_style.add_font_feature(feature.name(), feature.value as i32);
+        }
+    }
+
+    // Line Height
+    if let Some(factor) =
+        style.line_height.get(entity).and_then(|line_height| line_height.to_factor(font_size, 1.0))
+    {
+        text_style.set_height(factor);
+        text_style.set_height_override(true);
+    }
+
+    // Letter and Word Spacing
+    let scale_factor = style.scale_factor();
+    if let Some(letter_spacing) = style.letter_spacing.get(entity) {
+        text_style
+            .set_letter_spacing(letter_spacing.to_pixels(font_size * scale_factor, scale_factor));
+    }
+
+    if let Some(word_spacing) = style.word_spacing.get(entity) {
+        text_style.set_word_spacing(word_spacing.to_pixels(font_size * scale_factor, scale_factor));
+    }
+
+    // Text Shadows
+    if let Some(shadows) = style.text_shadow.get(entity) {
+        for shadow in shadows.iter().filter(|shadow| !shadow.inset) {
+            let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+            let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+            let sigma = shadow
+                .blur_radius
+                .as_ref()
+                .and_then(|blur_radius| blur_radius.to_px())
+                .unwrap_or_default()
+                * scale_factor
+                / 2.0;
+            let color = shadow.color.unwrap_or(font_color);
+            text_style.add_shadow(TextShadow::new(color, (x, y), sigma as f64));
+        }
+    }
+
+    text_style
 }
 
-fn add_block(
-    style: &mut Style,
-    tree: &Tree<Entity>,
+/// Builds the content generated by a `::before` or `::after` pseudo-element of an entity from the
+/// rules matched by the pseudo-element.
+fn build_generated_content(
+    style: &Style,
     entity: Entity,
-    paragraph_builder: &mut ParagraphBuilder,
-    current: &mut usize,
-) {
-    // let mut new_current = current;
-
-    if let Some(text) = style.text.get(entity) {
-        if !text.is_empty() {
-            // Text Style
+    rules: &[Rule],
+) -> Option<GeneratedContent> {
+    let content = rules.iter().find_map(|rule| style.content.get_shared(*rule))?;
+    let text_style = build_pseudo_element_text_style(style, entity, rules);
+
+    match content {
+        Content::None => None,
+
+        Content::Text(text) => Some(GeneratedContent::Text { text: text.clone(), text_style }),
+
+        Content::Url(name) => Some(GeneratedContent::Image {
+            name: name.clone(),
+            size: text_style.font_size(),
+            fill: rules
+                .iter()
+                .find_map(|rule| style.fill.get_shared(*rule))
+                .or_else(|| style.fill.get(entity))
+                .copied(),
+        }),
+    }
+}
 
-            let mut text_style = TextStyle::new();
+/// Builds the text style of a pseudo-element of an entity. The font properties declared by the rules
+/// matched by the pseudo-element override those of the entity.
+fn build_pseudo_element_text_style(style: &Style, entity: Entity, rules: &[Rule]) -> TextStyle {
+    let mut text_style = build_text_style(style, entity);
+
+    let font_color = rules.iter().find_map(|rule| style.font_color.get_shared(*rule));
+    if let Some(font_color) = font_color {
+        let mut paint = text_style.foreground();
+        paint.set_color(*font_color);
+        text_style.set_foreground_paint(&paint);
+        text_style.set_decoration_color(*font_color);
+    }
 
-            let font_color = style.font_color.get(entity).cloned().unwrap_or_default();
+    if let Some(font_size) = rules.iter().find_map(|rule| style.font_size.get_shared(*rule)) {
+        text_style.set_font_size(font_size.0 * style.scale_factor());
+    }
 
-            if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
-                text_style.set_decoration_type(text_decoration_line.into());
-                text_style.set_decoration_color(font_color);
-            }
+    if let Some(font_family) = rules.iter().find_map(|rule| style.font_family.get_shared(*rule)) {
+        text_style.set_font_families(font_family);
+    }
 
-            // Font Families
-            text_style.set_font_families(
-                style
-                    .font_family
-                    .get(entity)
-                    .map(Vec::as_slice)
-                    .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]),
-            );
+    let font_weight = rules.iter().find_map(|rule| style.font_weight.get_shared(*rule));
+    let font_slant = rules.iter().find_map(|rule| style.font_slant.get_shared(*rule));
+    if font_weight.is_some() || font_slant.is_some() {
+        let font_style = text_style.font_style();
+        text_style.set_font_style(FontStyle::new(
+            font_weight.map_or(font_style.weight(), |font_weight| (*font_weight).into()),
+            font_style.width(),
+            font_slant.map_or(font_style.slant(), |font_slant| (*font_slant).into()),
+        ));
+    }
 
-            let mut paint = Paint::default();
-            // Font Color
-            if let Some(font_color) = style.font_color.get(entity) {
-                paint.set_color(*font_color);
-                paint.set_anti_alias(false);
-                paint.set_blend_mode(BlendMode::SrcOver);
-            }
+    if let Some(text_decoration_line) =
+        rules.iter().find_map(|rule| style.text_decoration_line.get_shared(*rule))
+    {
+        let color = text_style.foreground().color();
+        text_style.set_decoration_type((*text_decoration_line).into());
+        text_style.set_decoration_color(color);
+    }
 
-            if let Some(text_stroke) = style.text_stroke_width.get(entity) {
-                paint.set_stroke_width(text_stroke.to_px().unwrap_or(0.0));
-                paint.set_style(
-                    (*style.text_stroke_style.get(entity).unwrap_or(&TextStrokeStyle::default()))
-                        .into(),
-                );
-            }
+    text_style
+}
 
-            text_style.set_foreground_paint(&paint);
+/// The position reached while adding the text of an entity and its spans to a paragraph.
+#[derive(Default)]
+struct ParagraphCursor {
+    /// The byte offset into the text of the paragraph, i.e. the text of the entity followed by the text
+    /// of its spans.
+    offset: usize,
+    /// The index into the paragraph, in UTF-16 code units as used by skia.
+    index: usize,
+    /// The start of each character, or segment of masked or transformed text, which has been added.
+    offset_map: OffsetMap,
+    /// Whether any masked or transformed text changed length.
+    mapped: bool,
+}
 
-            if let Some(background_color) = style.background_color.get(entity) {
-                if style.text_span.get(entity).is_some() {
-                    let mut paint = Paint::default();
-                    paint.set_color(*background_color);
-                    paint.set_anti_alias(false);
-                    paint.set_blend_mode(BlendMode::SrcOver);
-                    text_style.set_background_paint(&paint);
+impl ParagraphCursor {
+    /// Moves the cursor past the given text, which is displayed as the given segments if it is masked
+    /// or transformed. Each segment replaces the text from its byte offset up to the next segment.
+    fn advance(&mut self, text: &str, segments: Option<&[(usize, String)]>) {
+        match segments {
+            Some(segments) => {
+                for (i, (offset, segment)) in segments.iter().enumerate() {
+                    let end = segments.get(i + 1).map_or(text.len(), |(end, _)| *end);
+                    let len = segment.encode_utf16().count();
+                    self.mapped |= len != text[*offset..end].encode_utf16().count();
+                    self.offset_map.push(self.offset + offset, self.index);
+                    self.index += len;
                 }
             }
 
-            // Font Size
-            let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
-            text_style.set_font_size(font_size * style.scale_factor());
-
-            // Font Style
-            match (
-                style.font_weight.get(entity),
-                style.font_width.get(entity),
-                style.font_slant.get(entity),
-            ) {
-                (None, None, None) => {}
-                (weight, width, slant) => {
-                    text_style.set_font_style(FontStyle::new(
-                        weight.copied().unwrap_or_default().into(),
-                        width.copied().unwrap_or_default().into(),
-                        slant.copied().unwrap_or_default().into(),
-                    ));
+            None => {
+                for (offset, c) in text.char_indices() {
+                    self.offset_map.push(self.offset + offset, self.index);
+                    self.index += c.len_utf16();
                 }
             }
+        }
 
-            // Font Variations
-            if let Some(coordinates) = style.font_variation_settings.get(entity) {
-                let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
-                text_style.set_font_arguments(&FontArguments::new().set_variation_design_position(
-                    VariationPosition { coordinates: &coordinates },
-                ));
-            }
+        self.offset += text.len();
+    }
+}
+
+fn add_block(
+    style: &mut Style,
+    tree: &Tree<Entity>,
+    entity: Entity,
+    paragraph_builder: &mut ParagraphBuilder,
+    cursor: &mut ParagraphCursor,
+) {
+    if let Some(text) = style.text.get(entity) {
+        if !text.is_empty() {
+            let placeholder_shown = style
+                .pseudo_classes
+                .get(entity)
+                .is_some_and(|flags| flags.contains(PseudoClassFlags::PLACEHOLDER_SHOWN));
 
+            // A shown placeholder is styled by the `::placeholder` rules of the entity.
+            let text_style = match style.pseudo_elements.get(entity) {
+                Some(rules) if placeholder_shown && !rules.placeholder.is_empty() => {
+                    build_pseudo_element_text_style(style, entity, &rules.placeholder)
+                }
+                _ => build_text_style(style, entity),
+            };
+
+            // Masked text, such as a password, is displayed as one mask character per grapheme.
+            // Placeholders are not masked. Otherwise the text is displayed with its transform, which
+            // can change its length, e.g. `ß` is `SS` in uppercase.
+            let segments = match style.text_mask.get(entity).filter(|_| !placeholder_shown) {
+                Some(mask) => Some(
+                    text.grapheme_indices(true)
+                        .map(|(offset, _)| (offset, mask.to_string()))
+                        .collect::<Vec<_>>(),
+                ),
+                None => style
+                    .text_transform
+                    .get(entity)
+                    .filter(|text_transform| **text_transform != TextTransform::None)
+                    .map(|text_transform| text_transform.apply_chars(text)),
+            };
+
+            let text = text.clone();
+            let displayed = match &segments {
+                Some(segments) => Cow::Owned(segments.iter().map(|(_, s)| s.as_str()).collect()),
+                None => Cow::Borrowed(text.as_str()),
+            };
+
+            // The range of the text within the paragraph is in UTF-16 code units, as used by skia.
+            let start = cursor.index;
             paragraph_builder.push_style(&text_style);
-            style.text_range.insert(entity, *current..*current + text.len());
-            paragraph_builder.add_text(text.as_str());
-            *current += text.len();
+            paragraph_builder.add_text(&displayed);
+            cursor.advance(&text, segments.as_deref());
+            style.text_range.insert(entity, start..cursor.index);
+        } else {
+            style.text_range.remove(entity);
         }
     }
 
     let iter = LayoutChildIterator::new(tree, entity);
     for child in iter {
         if style.text_span.get(child).copied().unwrap_or_default() {
-            add_block(style, tree, child, paragraph_builder, current);
+            add_block(style, tree, child, paragraph_builder, cursor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_range_counts_utf16_code_units() {
+        let cx = &mut Context::default();
+        let label = Label::rich(cx, "héllo 😀 ", |cx| {
+            TextSpan::new(cx, "wörld", |_| {});
+        })
+        .entity();
+        let span = cx.tree.get_first_child(label).unwrap();
+
+        let font_collection = cx.text_context.font_collection().clone();
+        build_paragraph(label, &mut cx.style, &cx.tree, &font_collection);
+
+        // The emoji is two UTF-16 code units and four UTF-8 bytes, and the accented letters are
+        // one code unit and two bytes.
+        assert_eq!(cx.style.text_range.get(label), Some(&(0..9)));
+        assert_eq!(cx.style.text_range.get(span), Some(&(9..14)));
+    }
+
+    #[test]
+    fn generated_content_is_laid_out_around_text() {
+        let cx = &mut Context::default();
+        let label = Label::rich(cx, "text", |cx| {
+            TextSpan::new(cx, "span", |_| {});
+        })
+        .entity();
+        let span = cx.tree.get_first_child(label).unwrap();
+
+        let before = GeneratedContent::Text {
+            text: "» ".to_string(),
+            text_style: build_text_style(&cx.style, label),
+        };
+        let after = GeneratedContent::Image { name: "icon".to_string(), size: 16.0, fill: None };
+
+        let font_collection = cx.text_context.font_collection().clone();
+        let (mut paragraph, _) = build_paragraph_with_content(
+            label,
+            &mut cx.style,
+            &cx.tree,
+            &font_collection,
+            Some(&before),
+            Some(&after),
+        );
+        paragraph.layout(f32::MAX);
+
+        // The text ranges start after the generated text, and the image takes up a placeholder.
+        assert_eq!(cx.style.text_range.get(label), Some(&(2..6)));
+        assert_eq!(cx.style.text_range.get(span), Some(&(6..10)));
+        let placeholders = paragraph.get_rects_for_placeholders();
+        assert_eq!(placeholders.len(), 1);
+        assert_eq!(placeholders[0].rect.width(), 16.0);
+    }
+
+    #[test]
+    fn transformed_text_maps_offsets_into_the_paragraph() {
+        let cx = &mut Context::default();
+        let plain = Label::new(cx, "héllo").entity();
+        let label = Label::new(cx, "aßc").text_transform(TextTransform::Uppercase).entity();
+
+        let font_collection = cx.text_context.font_collection().clone();
+        let (_, offset_map) = build_paragraph_with_content(
+            plain,
+            &mut cx.style,
+            &cx.tree,
+            &font_collection,
+            None,
+            None,
+        );
+        assert_eq!(offset_map, None);
+
+        let (_, offset_map) = build_paragraph_with_content(
+            label,
+            &mut cx.style,
+            &cx.tree,
+            &font_collection,
+            None,
+            None,
+        );
+        let offset_map = offset_map.unwrap();
+
+        // The text is displayed as "ASSC", where the two bytes of "ß" become two code units.
+        assert_eq!(cx.style.text_range.get(label), Some(&(0..4)));
+        assert_eq!(offset_map.index(1), 1);
+        assert_eq!(offset_map.index(3), 3);
+        assert_eq!(offset_map.index(4), 4);
+        // An index within "SS" maps to the start of "ß".
+        assert_eq!(offset_map.offset(2), 1);
+        assert_eq!(offset_map.offset(3), 3);
+    }
+
+    #[test]
+    fn layout_span_splits_wrapped_span_into_lines() {
+        let cx = &mut Context::default();
+        // The bundled font provides glyphs for the span to cover whatever fonts are installed.
+        cx.add_font_mem(std::fs::read(crate::text::TEST_FONT).unwrap());
+
+        let label = Label::rich(cx, "Visit ", |cx| {
+            TextSpan::link(cx, "https://github.com/vizia/vizia", |cx| {
+                TextSpan::new(cx, "the documentation of the project", |_| {});
+            });
+        })
+        .entity();
+        let link = cx.tree.get_first_child(label).unwrap();
+        for entity in label.branch_iter(&cx.tree).collect::<Vec<_>>() {
+            cx.style
+                .font_family
+                .insert(entity, vec![FamilyOwned::Named(String::from("DejaVu Sans Mono"))]);
+        }
+
+        let font_collection = cx.text_context.font_collection().clone();
+        let mut paragraph =
+            build_paragraph(label, &mut cx.style, &cx.tree, &font_collection).unwrap();
+        paragraph.layout(80.0);
+        assert!(paragraph.line_number() > 2);
+
+        let origin = (10.0, 20.0);
+        layout_span(&cx.style, &mut cx.cache, &cx.tree, label, &paragraph, origin);
+
+        // The link has a rect on each line it covers, and its bounds contain all of them.
+        let rects = cx.cache.text_span_bounds.get(link).unwrap();
+        assert!(rects.len() >= 2);
+        assert!(rects.first().unwrap().y < rects.last().unwrap().y);
+        let bounds = *cx.cache.bounds.get(link).unwrap();
+        for rect in rects {
+            assert!(rect.x >= origin.0 && rect.y >= origin.1);
+            assert!(rect.left() >= bounds.left() && rect.right() <= bounds.right() + 0.01);
+            assert!(rect.top() >= bounds.top() && rect.bottom() <= bounds.bottom() + 0.01);
         }
     }
 }
@@ -0,0 +1,573 @@
+use std::{borrow::Cow, ops::Range};
+
+use hashbrown::HashMap;
+use ropey::Rope;
+use skia_safe::textlayout::{LineMetrics, Paragraph, RectHeightStyle, RectWidthStyle};
+
+use super::{line_break_len, CodeToken, EditableText, HighlightState, Highlighter};
+use crate::layout::BoundingBox;
+
+/// A single line of a [`TextDocument`], laid out as its own paragraph.
+#[derive(Default)]
+struct DocumentLine {
+    paragraph: Option<Paragraph>,
+    laid_out: bool,
+    /// The measured height of the line, or `None` if the line has never been laid out.
+    height: Option<f32>,
+    top: f32,
+    /// The highlight state at the end of the line, or `None` if the line has never been highlighted.
+    state: Option<HighlightState>,
+}
+
+/// A large text document backed by a [`Rope`].
+///
+/// Each line of the document is shaped and laid out as a separate paragraph. Editing the document
+/// only discards the paragraphs of the lines which were touched by the edit, and only the lines
+/// within, or close to, the viewport are shaped at all. Lines which have never been laid out are
+/// assumed to be as tall as an empty line.
+///
+/// A document can be given a [`Highlighter`] to split the text of each line into tokens. Lines are
+/// highlighted when they are shaped, after every line above them has been highlighted.
+pub struct TextDocument {
+    text: Rope,
+    lines: Vec<DocumentLine>,
+    /// Whether the paragraphs which have not been discarded by an edit are still valid.
+    incremental: bool,
+    width: f32,
+    scroll_y: f32,
+    viewport_height: f32,
+    line_height: f32,
+    content_width: f32,
+    height: f32,
+    highlighter: Option<Box<dyn Highlighter>>,
+    /// The number of lines, from the start of the document, whose highlight state is up to date.
+    highlighted: usize,
+    line_numbers: bool,
+    gutter_width: f32,
+    /// The paragraphs of the line numbers drawn in the gutter, keyed by line and by whether the
+    /// line is the current line.
+    line_number_paragraphs: HashMap<(usize, bool), Paragraph>,
+}
+
+impl TextDocument {
+    /// Creates a new document from the given text.
+    pub fn new(text: Rope) -> Self {
+        let lines = (0..text.len_lines()).map(|_| DocumentLine::default()).collect();
+        Self {
+            text,
+            lines,
+            incremental: false,
+            width: 0.0,
+            scroll_y: 0.0,
+            viewport_height: 0.0,
+            line_height: 0.0,
+            content_width: 0.0,
+            height: 0.0,
+            highlighter: None,
+            highlighted: 0,
+            line_numbers: false,
+            gutter_width: 0.0,
+            line_number_paragraphs: HashMap::new(),
+        }
+    }
+
+    /// Returns the text of the document.
+    pub fn text(&self) -> &Rope {
+        &self.text
+    }
+
+    /// Replaces the text of the document, discarding the layout of every line.
+    pub fn set_text(&mut self, text: Rope) {
+        self.lines = (0..text.len_lines()).map(|_| DocumentLine::default()).collect();
+        self.text = text;
+        self.incremental = false;
+        self.highlighted = 0;
+    }
+
+    /// Sets the highlighter used to style the text of each line, discarding the layout of every line.
+    pub fn set_highlighter(&mut self, highlighter: Option<Box<dyn Highlighter>>) {
+        self.highlighter = highlighter;
+        for line in self.lines.iter_mut() {
+            line.state = None;
+        }
+        self.highlighted = 0;
+        self.incremental = false;
+    }
+
+    /// Returns whether space is reserved to the left of the text for line numbers.
+    pub fn line_numbers(&self) -> bool {
+        self.line_numbers
+    }
+
+    /// Sets whether space is reserved to the left of the text for line numbers.
+    pub fn set_line_numbers(&mut self, line_numbers: bool) {
+        self.line_numbers = line_numbers;
+        if !line_numbers {
+            self.gutter_width = 0.0;
+            self.line_number_paragraphs.clear();
+        }
+    }
+
+    /// Returns the width of the space reserved for line numbers, in physical pixels.
+    pub fn gutter_width(&self) -> f32 {
+        self.gutter_width
+    }
+
+    /// Replaces the given byte range of the document with new text.
+    ///
+    /// Only the lines touched by the edit need to be laid out again.
+    pub fn edit(&mut self, range: Range<usize>, new: &str) {
+        // Start from the line before the edit in case the edit joins a carriage return and a line feed.
+        let start_line = self.text.byte_to_line(range.start.saturating_sub(1));
+        let end_line = self.text.byte_to_line(range.end);
+        let end_state = self.lines[end_line].state;
+
+        self.text.edit(range.clone(), new);
+
+        let new_end_line = self.text.byte_to_line(range.start + new.len());
+        self.lines.splice(
+            start_line..=end_line,
+            (start_line..=new_end_line).map(|_| DocumentLine::default()),
+        );
+
+        // Keep the state which the following line was highlighted with, so that the following line
+        // is only highlighted again if the edit changes it.
+        self.lines[new_end_line].state = end_state;
+        self.highlighted = self.highlighted.min(start_line);
+        self.incremental = true;
+    }
+
+    /// Returns the number of lines in the document.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the index of the line containing the given byte offset.
+    pub fn line_at_offset(&self, offset: usize) -> usize {
+        self.text.byte_to_line(offset.min(self.text.len_bytes()))
+    }
+
+    /// Returns the byte range of a line, excluding its line break.
+    pub fn line_range(&self, line: usize) -> Range<usize> {
+        let start = self.text.line_to_byte(line);
+        let line_slice = self.text.line(line);
+        start..start + line_slice.len_bytes() - line_break_len(line_slice)
+    }
+
+    /// Returns the text of a line, excluding its line break.
+    pub fn line_text(&self, line: usize) -> Cow<str> {
+        let range = self.line_range(line);
+        self.text.byte_slice(range).into()
+    }
+
+    /// Returns the index of the line at the given vertical position, in physical pixels from the
+    /// top of the document.
+    pub fn line_at_y(&self, y: f32) -> usize {
+        let line = self.lines.partition_point(|line| line.top + self.height_of(line) <= y);
+        line.min(self.lines.len() - 1)
+    }
+
+    /// Returns the vertical position and height of a line, in physical pixels.
+    pub fn line_bounds(&self, line: usize) -> (f32, f32) {
+        let line = &self.lines[line];
+        (line.top, self.height_of(line))
+    }
+
+    /// Returns the laid out paragraph of a line, if the line is within the laid out region.
+    pub fn paragraph(&self, line: usize) -> Option<&Paragraph> {
+        self.lines.get(line).filter(|line| line.laid_out).and_then(|line| line.paragraph.as_ref())
+    }
+
+    /// Returns the total height of the document, in physical pixels.
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns the width of the widest line laid out so far, in physical pixels.
+    pub fn content_width(&self) -> f32 {
+        self.content_width
+    }
+
+    /// Sets the vertical scroll position of the viewport, in physical pixels.
+    pub fn set_scroll_y(&mut self, scroll_y: f32) {
+        self.scroll_y = scroll_y;
+    }
+
+    /// Returns the range of lines which intersect the given vertical range.
+    pub fn lines_between(&self, top: f32, bottom: f32) -> Range<usize> {
+        self.line_at_y(top)..self.line_at_y(bottom) + 1
+    }
+
+    /// Returns the byte offset closest to the given position, in physical pixels relative to the
+    /// top-left corner of the document.
+    pub fn hit_test(&self, x: f32, y: f32) -> usize {
+        let line = self.line_at_y(y);
+        let range = self.line_range(line);
+        if let Some(paragraph) = self.paragraph(line) {
+            let position =
+                paragraph.get_glyph_position_at_coordinate((x, y - self.lines[line].top)).position;
+            let text = self.line_text(line);
+            range.start + utf16_to_utf8(&text, position.max(0) as usize)
+        } else {
+            range.start
+        }
+    }
+
+    /// Returns the bounds of the caret at the given byte offset, in physical pixels relative to
+    /// the top-left corner of the document. The returned bounds have zero width.
+    pub fn caret_bounds(&self, offset: usize) -> BoundingBox {
+        let line = self.line_at_offset(offset);
+        let (top, height) = self.line_bounds(line);
+        if let Some(paragraph) = self.paragraph(line) {
+            let text = self.line_text(line);
+            let index = utf8_to_utf16(&text, offset - self.line_range(line).start);
+            // Every paragraph ends with a zero-width space so that the caret has a position at the end of the line.
+            let rects = paragraph.get_rects_for_range(
+                index..index + 1,
+                RectHeightStyle::Tight,
+                RectWidthStyle::Tight,
+            );
+            if let Some(rect) = rects.first() {
+                return BoundingBox {
+                    x: rect.rect.left,
+                    y: top + rect.rect.top,
+                    w: 0.0,
+                    h: rect.rect.height(),
+                };
+            }
+        }
+
+        BoundingBox { x: 0.0, y: top, w: 0.0, h: height }
+    }
+
+    /// Returns the bounds of the selected text within the laid out lines, in physical pixels
+    /// relative to the top-left corner of the document.
+    pub fn selection_bounds(&self, range: Range<usize>) -> Vec<BoundingBox> {
+        let mut bounds = Vec::new();
+        let start_line = self.line_at_offset(range.start);
+        let end_line = self.line_at_offset(range.end);
+        for line in start_line..=end_line {
+            let Some(paragraph) = self.paragraph(line) else {
+                continue;
+            };
+
+            let line_range = self.line_range(line);
+            let text = self.line_text(line);
+            let start = utf8_to_utf16(&text, range.start.max(line_range.start) - line_range.start);
+            let end = utf8_to_utf16(&text, range.end.min(line_range.end) - line_range.start);
+            let top = self.lines[line].top;
+            for rect in paragraph.get_rects_for_range(
+                start..end,
+                RectHeightStyle::Max,
+                RectWidthStyle::Tight,
+            ) {
+                bounds.push(BoundingBox {
+                    x: rect.rect.left,
+                    y: top + rect.rect.top,
+                    w: rect.rect.width(),
+                    h: rect.rect.height(),
+                });
+            }
+        }
+
+        bounds
+    }
+
+    /// Returns the byte range of the visual line, i.e. the wrapped line, containing the given offset.
+    pub fn visual_line_range(&self, offset: usize) -> Range<usize> {
+        let line = self.line_at_offset(offset);
+        let range = self.line_range(line);
+        if let Some(metrics) = self.visual_line_metrics(offset) {
+            let len = range.len();
+            let start = metrics.start_index.min(len);
+            // The last visual line ends at the end of the line rather than before any trailing whitespace.
+            let end = if metrics.end_index >= len {
+                len
+            } else {
+                metrics.end_excluding_whitespaces.clamp(start, len)
+            };
+            range.start + start..range.start + end
+        } else {
+            range
+        }
+    }
+
+    /// Returns the vertical position and height of the visual line containing the given offset,
+    /// in physical pixels.
+    pub fn visual_line_bounds(&self, offset: usize) -> (f32, f32) {
+        let line = self.line_at_offset(offset);
+        let (top, height) = self.line_bounds(line);
+        if let Some(metrics) = self.visual_line_metrics(offset) {
+            (top + (metrics.baseline - metrics.ascent) as f32, metrics.height as f32)
+        } else {
+            (top, height)
+        }
+    }
+
+    fn visual_line_metrics(&self, offset: usize) -> Option<LineMetrics> {
+        let line = self.line_at_offset(offset);
+        let paragraph = self.paragraph(line)?;
+        let number = paragraph.get_line_number_at(offset - self.line_range(line).start)?;
+        paragraph.get_line_metrics_at(number)
+    }
+
+    /// Discards the paragraphs of every line, unless the only changes since the last update were
+    /// edits, which discard the paragraphs of the lines they touch.
+    pub(crate) fn discard_stale_paragraphs(&mut self) {
+        if !self.incremental {
+            for line in self.lines.iter_mut() {
+                line.paragraph = None;
+                line.laid_out = false;
+            }
+            self.content_width = 0.0;
+            self.line_height = 0.0;
+            self.line_number_paragraphs.clear();
+        }
+
+        self.incremental = false;
+    }
+
+    /// Shapes and lays out the lines within, and one viewport height either side of, the viewport.
+    ///
+    /// Lines which have already been laid out at the given width are not laid out again.
+    pub(crate) fn layout(
+        &mut self,
+        width: f32,
+        viewport_height: f32,
+        mut build: impl FnMut(&str, &[CodeToken]) -> Paragraph,
+    ) {
+        if width != self.width {
+            for line in self.lines.iter_mut() {
+                line.laid_out = false;
+            }
+            self.width = width;
+            self.content_width = 0.0;
+        }
+        self.viewport_height = viewport_height;
+
+        // Lines which have not been laid out are assumed to be as tall as an empty line.
+        if self.line_height == 0.0 {
+            let mut paragraph = build("", &[]);
+            paragraph.layout(width);
+            self.line_height = paragraph.height();
+        }
+
+        // Laying out a line can change its height, which moves the lines below it, so repeat until
+        // the set of lines near the viewport no longer changes.
+        loop {
+            self.update_line_tops();
+
+            let lines = self.lines_between(
+                self.scroll_y - self.viewport_height,
+                self.scroll_y + 2.0 * self.viewport_height,
+            );
+
+            let mut changed = false;
+            for index in lines {
+                self.highlight_until(index);
+
+                if self.lines[index].laid_out {
+                    continue;
+                }
+
+                if self.lines[index].paragraph.is_none() {
+                    let tokens = self.highlight_line(index);
+                    let paragraph = build(&self.line_text(index), &tokens);
+                    self.lines[index].paragraph = Some(paragraph);
+                }
+
+                let line = &mut self.lines[index];
+                let Some(paragraph) = line.paragraph.as_mut() else {
+                    continue;
+                };
+                paragraph.layout(width);
+                let height = paragraph.height();
+                self.content_width = self.content_width.max(paragraph.max_intrinsic_width());
+
+                changed |= line.height != Some(height);
+                line.height = Some(height);
+                line.laid_out = true;
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn set_gutter_width(&mut self, gutter_width: f32) {
+        if gutter_width != self.gutter_width {
+            self.line_number_paragraphs.clear();
+        }
+        self.gutter_width = gutter_width;
+    }
+
+    /// Returns the paragraph of the number of a line, which is only built if it is not cached.
+    pub(crate) fn line_number_paragraph(
+        &mut self,
+        line: usize,
+        current: bool,
+        build: impl FnOnce() -> Paragraph,
+    ) -> &Paragraph {
+        self.line_number_paragraphs.entry((line, current)).or_insert_with(build)
+    }
+
+    /// Discards the cached paragraphs of the numbers of lines outside the given range.
+    pub(crate) fn retain_line_numbers(&mut self, lines: Range<usize>) {
+        self.line_number_paragraphs.retain(|(line, _), _| lines.contains(line));
+    }
+
+    /// Updates the highlight state of every line above the given line.
+    fn highlight_until(&mut self, line: usize) {
+        if self.highlighter.is_none() {
+            return;
+        }
+
+        while self.highlighted < line {
+            self.highlight_line(self.highlighted);
+        }
+    }
+
+    /// Returns the tokens of a line, whose preceding line must have an up to date state.
+    fn highlight_line(&mut self, line: usize) -> Vec<CodeToken> {
+        let Some(highlighter) = &self.highlighter else {
+            return Vec::new();
+        };
+
+        let mut state = if line == 0 { HighlightState::default() } else { self.state_of(line - 1) };
+        let tokens = highlighter.highlight_line(&self.line_text(line), &mut state);
+
+        if line == self.highlighted {
+            // The following line was shaped with the previous state, so it must be shaped again.
+            if self.lines[line].state != Some(state) {
+                if let Some(next) = self.lines.get_mut(line + 1) {
+                    next.paragraph = None;
+                    next.laid_out = false;
+                }
+            }
+
+            self.lines[line].state = Some(state);
+            self.highlighted += 1;
+        }
+
+        tokens
+    }
+
+    fn state_of(&self, line: usize) -> HighlightState {
+        self.lines[line].state.unwrap_or_default()
+    }
+
+    fn update_line_tops(&mut self) {
+        let mut top = 0.0;
+        for line in self.lines.iter_mut() {
+            line.top = top;
+            top += line.height.unwrap_or(self.line_height);
+        }
+        self.height = top;
+    }
+
+    fn height_of(&self, line: &DocumentLine) -> f32 {
+        line.height.unwrap_or(self.line_height)
+    }
+}
+
+/// Converts a UTF-8 byte offset into the text to a UTF-16 code unit offset, as used by [`Paragraph`].
+pub(crate) fn utf8_to_utf16(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].chars().map(char::len_utf16).sum()
+}
+
+/// Converts a UTF-16 code unit offset into the text to a UTF-8 byte offset.
+pub(crate) fn utf16_to_utf8(text: &str, offset: usize) -> usize {
+    let mut utf16 = 0;
+    for (index, c) in text.char_indices() {
+        if utf16 >= offset {
+            return index;
+        }
+        utf16 += c.len_utf16();
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(document: &TextDocument) -> Vec<String> {
+        (0..document.line_count()).map(|line| document.line_text(line).into_owned()).collect()
+    }
+
+    #[test]
+    fn edit_splices_lines() {
+        let mut document = TextDocument::new(Rope::from_str("one\ntwo\nthree"));
+        assert_eq!(lines(&document), ["one", "two", "three"]);
+
+        // Inserting line breaks adds lines.
+        document.edit(4..4, "x\ny\n");
+        assert_eq!(lines(&document), ["one", "x", "y", "two", "three"]);
+
+        // Deleting across lines removes them.
+        document.edit(2..9, "");
+        assert_eq!(lines(&document), ["onwo", "three"]);
+
+        // Replacing within a line keeps the number of lines.
+        document.edit(5..10, "four");
+        assert_eq!(lines(&document), ["onwo", "four"]);
+        assert_eq!(document.line_count(), document.text().len_lines());
+    }
+
+    #[test]
+    fn edit_joins_carriage_return_and_line_feed() {
+        let mut document = TextDocument::new(Rope::from_str("a\rX\nb"));
+        assert_eq!(document.line_count(), 3);
+
+        // Removing the character between a carriage return and a line feed joins them into a
+        // single line break.
+        document.edit(2..3, "");
+        assert_eq!(lines(&document), ["a", "b"]);
+        assert_eq!(document.line_range(0), 0..1);
+
+        // Splitting them apart again adds the line back.
+        document.edit(2..2, "Y");
+        assert_eq!(lines(&document), ["a", "Y", "b"]);
+        assert_eq!(document.line_count(), document.text().len_lines());
+    }
+
+    /// Enters a comment at `/*` and leaves it at `*/`.
+    fn comments(line: &str, state: &mut HighlightState) -> Vec<CodeToken> {
+        if line.contains("/*") {
+            state.0 = 1;
+        }
+        if line.contains("*/") {
+            state.0 = 0;
+        }
+        Vec::new()
+    }
+
+    fn states(document: &TextDocument) -> Vec<Option<u64>> {
+        document.lines.iter().map(|line| line.state.map(|state| state.0)).collect()
+    }
+
+    #[test]
+    fn highlight_state_invalidates_following_lines() {
+        let mut document = TextDocument::new(Rope::from_str("a\n/*\nb\nc"));
+        document.set_highlighter(Some(Box::new(comments)));
+        document.highlight_until(4);
+        assert_eq!(states(&document), [Some(0), Some(1), Some(1), Some(1)]);
+        for line in document.lines.iter_mut() {
+            line.laid_out = true;
+        }
+
+        // An edit which leaves the state at the end of the line unchanged only touches that line.
+        document.edit(5..6, "bb");
+        document.highlight_until(4);
+        assert_eq!(states(&document), [Some(0), Some(1), Some(1), Some(1)]);
+        assert!(!document.lines[2].laid_out);
+        assert!(document.lines[3].laid_out);
+
+        // Removing the start of the comment changes the state of every following line.
+        document.edit(2..4, "");
+        document.highlight_until(4);
+        assert_eq!(states(&document), [Some(0), Some(0), Some(0), Some(0)]);
+        assert!(!document.lines[3].laid_out);
+    }
+}
@@ -2,7 +2,8 @@
 
 use std::{borrow::Cow, ops::Range};
 
-use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
+use ropey::{Rope, RopeSlice};
+use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete, UnicodeSegmentation};
 
 pub trait EditableText: Sized {
     /// Replace range with new text.
@@ -191,6 +192,186 @@ impl EditableText for String {
     }
 }
 
+impl EditableText for Rope {
+    fn edit(&mut self, range: Range<usize>, new: impl Into<Self>) {
+        let start = self.byte_to_char(range.start);
+        let end = self.byte_to_char(range.end);
+        self.remove(start..end);
+
+        let new = new.into();
+        let mut index = start;
+        for chunk in new.chunks() {
+            self.insert(index, chunk);
+            index += chunk.chars().count();
+        }
+    }
+
+    fn slice(&self, range: Range<usize>) -> Option<Cow<str>> {
+        self.get_byte_slice(range).map(Cow::from)
+    }
+
+    fn len(&self) -> usize {
+        self.len_bytes()
+    }
+
+    fn prev_grapheme_offset(&self, from: usize) -> Option<usize> {
+        let (mut chunk, mut chunk_start, _, _) = self.chunk_at_byte(from);
+        let mut c = GraphemeCursor::new(from, self.len_bytes(), true);
+        loop {
+            match c.prev_boundary(chunk, chunk_start) {
+                Ok(offset) => return offset,
+                Err(GraphemeIncomplete::PrevChunk) => {
+                    (chunk, chunk_start, _, _) = self.chunk_at_byte(chunk_start - 1);
+                }
+                Err(GraphemeIncomplete::PreContext(index)) => {
+                    let (context, context_start, _, _) = self.chunk_at_byte(index - 1);
+                    c.provide_context(context, context_start);
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+
+    fn next_grapheme_offset(&self, from: usize) -> Option<usize> {
+        let (mut chunk, mut chunk_start, _, _) = self.chunk_at_byte(from);
+        let mut c = GraphemeCursor::new(from, self.len_bytes(), true);
+        loop {
+            match c.next_boundary(chunk, chunk_start) {
+                Ok(offset) => return offset,
+                Err(GraphemeIncomplete::NextChunk) => {
+                    chunk_start += chunk.len();
+                    (chunk, _, _, _) = self.chunk_at_byte(chunk_start);
+                }
+                Err(GraphemeIncomplete::PreContext(index)) => {
+                    let (context, context_start, _, _) = self.chunk_at_byte(index - 1);
+                    c.provide_context(context, context_start);
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+
+    // Counting the graphemes before the offset is linear in the offset, so the chunks of the rope
+    // are walked once rather than looking up the chunk of every grapheme.
+    fn current_grapheme_offset(&self, from: usize) -> usize {
+        let mut chunks = self.chunks();
+        let mut chunk = chunks.next().unwrap_or_default();
+        let mut chunk_start = 0;
+        let mut c = GraphemeCursor::new(0, self.len_bytes(), true);
+        let mut count = 0;
+        loop {
+            match c.next_boundary(chunk, chunk_start) {
+                Ok(Some(next)) if next <= from => count += 1,
+                Ok(_) => return count,
+                Err(GraphemeIncomplete::NextChunk) => {
+                    chunk_start += chunk.len();
+                    chunk = chunks.next().unwrap_or_default();
+                }
+                Err(GraphemeIncomplete::PreContext(index)) => {
+                    let (context, context_start, _, _) = self.chunk_at_byte(index - 1);
+                    c.provide_context(context, context_start);
+                }
+                Err(_) => return count,
+            }
+        }
+    }
+
+    fn prev_codepoint_offset(&self, current_pos: usize) -> Option<usize> {
+        if current_pos == 0 {
+            None
+        } else {
+            Some(self.char_to_byte(self.byte_to_char(current_pos - 1)))
+        }
+    }
+
+    fn next_codepoint_offset(&self, current_pos: usize) -> Option<usize> {
+        if current_pos >= self.len_bytes() {
+            None
+        } else {
+            Some(self.char_to_byte(self.byte_to_char(current_pos) + 1))
+        }
+    }
+
+    fn prev_word_offset(&self, from: usize) -> Option<usize> {
+        if from > self.len_bytes() {
+            return None;
+        }
+
+        let mut offset = from;
+        let mut passed_alphanumeric = false;
+        while let Some(prev) = self.prev_grapheme_offset(offset) {
+            let is_alphanumeric = self.char(self.byte_to_char(prev)).is_alphanumeric();
+            if is_alphanumeric {
+                passed_alphanumeric = true;
+            } else if passed_alphanumeric {
+                return Some(offset);
+            }
+            offset = prev;
+        }
+        None
+    }
+
+    fn next_word_offset(&self, from: usize) -> Option<usize> {
+        if from > self.len_bytes() {
+            return None;
+        }
+
+        let mut offset = from;
+        let mut passed_alphanumeric = false;
+        while let Some(next) = self.next_grapheme_offset(offset) {
+            let is_alphanumeric = self.char(self.byte_to_char(offset)).is_alphanumeric();
+            if is_alphanumeric {
+                passed_alphanumeric = true;
+            } else if passed_alphanumeric {
+                return Some(offset);
+            }
+            offset = next;
+        }
+        Some(self.len_bytes())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len_bytes() == 0
+    }
+
+    fn from_str(s: &str) -> Self {
+        Rope::from_str(s)
+    }
+
+    // Unlike the `String` implementation, any of the line breaks recognised by `Rope` end a line.
+    fn preceding_line_break(&self, from: usize) -> usize {
+        let from = from.min(self.len_bytes());
+        self.line_to_byte(self.byte_to_line(from))
+    }
+
+    fn next_line_break(&self, from: usize) -> usize {
+        let line = self.byte_to_line(from.min(self.len_bytes()));
+        let line_slice = self.line(line);
+        self.line_to_byte(line) + line_slice.len_bytes() - line_break_len(line_slice)
+    }
+
+    fn prev_codepoint(&self, offset: usize) -> Option<char> {
+        self.prev_codepoint_offset(offset).and_then(|prev| self.get_char(self.byte_to_char(prev)))
+    }
+}
+
+/// Returns the length in bytes of the line break at the end of a line of a [`Rope`], if any.
+pub(crate) fn line_break_len(line: RopeSlice) -> usize {
+    let len = line.len_chars();
+    let last = len.checked_sub(1).and_then(|index| line.get_char(index));
+    let second_last = len.checked_sub(2).and_then(|index| line.get_char(index));
+    match (second_last, last) {
+        (Some('\r'), Some('\n')) => 2,
+        (_, Some(c)) if is_line_break(c) => c.len_utf8(),
+        _ => 0,
+    }
+}
+
+/// Returns `true` if the character is one of the line breaks recognised by [`Rope`].
+fn is_line_break(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{000B}' | '\u{000C}' | '\u{0085}' | '\u{2028}' | '\u{2029}')
+}
+
 pub fn len_utf8_from_first_byte(b: u8) -> usize {
     match b {
         b if b < 0x80 => 1,
@@ -199,3 +380,79 @@ pub fn len_utf8_from_first_byte(b: u8) -> usize {
         _ => 4,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rope_edit() {
+        let mut rope = Rope::from_str("Hello World");
+        rope.edit(5..6, ", é ");
+        assert_eq!(rope, "Hello, é World");
+        rope.edit(7..9, "");
+        assert_eq!(rope, "Hello,  World");
+        assert_eq!(EditableText::slice(&rope, 0..5).as_deref(), Some("Hello"));
+        assert_eq!(EditableText::slice(&rope, 0..100), None);
+    }
+
+    #[test]
+    fn rope_grapheme_offsets() {
+        // A letter followed by a combining acute accent is a single grapheme of three bytes.
+        let rope = Rope::from_str("ae\u{301}b");
+        assert_eq!(rope.next_grapheme_offset(0), Some(1));
+        assert_eq!(rope.next_grapheme_offset(1), Some(4));
+        assert_eq!(rope.prev_grapheme_offset(5), Some(4));
+        assert_eq!(rope.prev_grapheme_offset(4), Some(1));
+        assert_eq!(rope.prev_grapheme_offset(0), None);
+        assert_eq!(rope.next_grapheme_offset(5), None);
+    }
+
+    #[test]
+    fn rope_current_grapheme_offset_matches_string() {
+        // Long enough to be split into several chunks, so graphemes are counted across chunks.
+        let text = "ae\u{301}\r\nb\u{1F600}c".repeat(500);
+        let rope = Rope::from_str(&text);
+        assert!(rope.chunks().count() > 1);
+        for offset in (0..=text.len()).filter(|offset| text.is_char_boundary(*offset)).step_by(7) {
+            assert_eq!(
+                rope.current_grapheme_offset(offset),
+                text.current_grapheme_offset(offset),
+                "offset {offset}"
+            );
+        }
+        assert_eq!(rope.current_grapheme_offset(text.len()), text.graphemes(true).count());
+    }
+
+    #[test]
+    fn rope_codepoint_offsets() {
+        let rope = Rope::from_str("aé\u{1F600}");
+        assert_eq!(rope.next_codepoint_offset(1), Some(3));
+        assert_eq!(rope.prev_codepoint_offset(7), Some(3));
+        assert_eq!(rope.prev_codepoint(3), Some('é'));
+        assert_eq!(rope.prev_codepoint(0), None);
+        assert_eq!(rope.next_codepoint_offset(7), None);
+    }
+
+    #[test]
+    fn rope_word_offsets() {
+        let rope = Rope::from_str("one two, three");
+        assert_eq!(rope.next_word_offset(0), Some(3));
+        assert_eq!(rope.next_word_offset(3), Some(7));
+        assert_eq!(rope.next_word_offset(9), Some(14));
+        assert_eq!(rope.prev_word_offset(14), Some(9));
+        assert_eq!(rope.prev_word_offset(7), Some(4));
+        assert_eq!(rope.prev_word_offset(3), None);
+    }
+
+    #[test]
+    fn rope_line_breaks() {
+        let rope = Rope::from_str("one\r\ntwo\u{2028}three");
+        assert_eq!(rope.preceding_line_break(2), 0);
+        assert_eq!(rope.next_line_break(2), 3);
+        assert_eq!(rope.preceding_line_break(6), 5);
+        assert_eq!(rope.next_line_break(6), 8);
+        assert_eq!(rope.preceding_line_break(12), 11);
+        assert_eq!(rope.next_line_break(12), rope.len_bytes());
+    }
+}
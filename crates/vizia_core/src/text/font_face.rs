@@ -0,0 +1,311 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use skia_safe::{
+    font_arguments::{variation_position::Coordinate, VariationPosition},
+    FontArguments, FontStyle, FourByteTag, Typeface,
+};
+use vizia_style::{FontFaceRule, FontFaceSource, FontFamily, FontSlant, FontWeight};
+
+use crate::text::TextContext;
+
+/// Font formats which can be loaded from the `src` descriptor of a `@font-face` rule.
+const SUPPORTED_FORMATS: &[&str] =
+    &["truetype", "opentype", "collection", "truetype-variations", "opentype-variations"];
+
+/// The path of a font file bundled for tests which need to shape text.
+#[cfg(test)]
+pub(crate) const TEST_FONT: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/resources/test_fonts/DejaVuSansMono.ttf");
+
+/// A font declared by a `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FontFace {
+    /// The family name the font is registered under.
+    pub family: String,
+    /// The sources of the font, in order of preference.
+    pub sources: Vec<FontSource>,
+    /// The weight used for the font if it is a variable font.
+    pub weight: Option<FontWeight>,
+    /// The style used for the font if it is a variable font.
+    pub slant: Option<FontSlant>,
+}
+
+/// A source of a font declared by a `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FontSource {
+    /// A font file, resolved relative to the stylesheet which declared it.
+    File(PathBuf),
+    /// A font installed on the system.
+    Local(String),
+}
+
+/// A font face along with the location of the rule which declared it, used for diagnostics.
+#[derive(Debug, Clone)]
+pub(crate) struct FontFaceDeclaration {
+    pub font_face: FontFace,
+    pub source: Arc<str>,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A font face which has been registered with the asset provider.
+#[derive(Debug)]
+pub(crate) struct LoadedFontFace {
+    font_face: FontFace,
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+}
+
+impl FontFace {
+    /// Creates a font face from a parsed rule, resolving relative urls against `base_path`.
+    pub(crate) fn from_rule(rule: &FontFaceRule, base_path: Option<&Path>) -> Result<Self, String> {
+        let family = match rule.family() {
+            Some(FontFamily::Named(name)) => name.to_string(),
+            Some(FontFamily::Generic(_)) => {
+                return Err(String::from("@font-face rules cannot declare a generic font family"))
+            }
+            None => {
+                return Err(String::from("@font-face rule is missing a font-family descriptor"))
+            }
+        };
+
+        let sources = rule
+            .sources()
+            .iter()
+            .filter_map(|source| match source {
+                FontFaceSource::Url(url, format) => {
+                    if let Some(format) = format {
+                        if !SUPPORTED_FORMATS.iter().any(|f| f.eq_ignore_ascii_case(format)) {
+                            return None;
+                        }
+                    }
+
+                    let url = url.url.as_ref();
+                    let path = match url.strip_prefix("file://") {
+                        Some(path) => Path::new(path),
+                        // Fonts cannot be fetched from remote urls.
+                        None if url.contains("://") => return None,
+                        None => Path::new(url),
+                    };
+
+                    Some(FontSource::File(match base_path {
+                        Some(base_path) if path.is_relative() => base_path.join(path),
+                        _ => path.to_owned(),
+                    }))
+                }
+
+                FontFaceSource::Local(FontFamily::Named(name)) => {
+                    Some(FontSource::Local(name.to_string()))
+                }
+
+                FontFaceSource::Local(FontFamily::Generic(_)) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if sources.is_empty() {
+            return Err(format!("@font-face rule for '{}' has no supported sources", family));
+        }
+
+        Ok(Self { family, sources, weight: rule.weight(), slant: rule.slant() })
+    }
+
+    fn font_style(&self) -> FontStyle {
+        FontStyle::new(
+            self.weight.unwrap_or_default().into(),
+            skia_safe::font_style::Width::NORMAL,
+            self.slant.unwrap_or_default().into(),
+        )
+    }
+
+    /// Applies the weight and style descriptors to the axes of a variable font.
+    fn apply_variations(&self, typeface: Typeface) -> Typeface {
+        let Some(axes) = typeface.variation_design_parameters() else {
+            return typeface;
+        };
+
+        let has_axis = |tag: FourByteTag| axes.iter().any(|axis| axis.tag == tag);
+
+        let mut coordinates = Vec::new();
+
+        let weight_axis = FourByteTag::from(('w', 'g', 'h', 't'));
+        if let Some(weight) = self.weight.filter(|_| has_axis(weight_axis)) {
+            coordinates.push(Coordinate { axis: weight_axis, value: weight.0 as f32 });
+        }
+
+        let italic_axis = FourByteTag::from(('i', 't', 'a', 'l'));
+        if self.slant == Some(FontSlant::Italic) && has_axis(italic_axis) {
+            coordinates.push(Coordinate { axis: italic_axis, value: 1.0 });
+        }
+
+        if coordinates.is_empty() {
+            return typeface;
+        }
+
+        let arguments = FontArguments::new()
+            .set_variation_design_position(VariationPosition { coordinates: &coordinates });
+
+        typeface.clone_with_arguments(&arguments).unwrap_or(typeface)
+    }
+}
+
+impl TextContext {
+    /// Loads a font declared by a `@font-face` rule into the asset provider, trying each source in
+    /// order. Returns `true` if a typeface was registered, or `false` if the font was already loaded
+    /// and its file has not changed since.
+    pub(crate) fn load_font_face(&mut self, font_face: &FontFace) -> Result<bool, String> {
+        if let Some(loaded) =
+            self.loaded_font_faces.iter().find(|loaded| loaded.font_face == *font_face)
+        {
+            let unchanged = match &loaded.path {
+                Some(path) => modified_time(path) == loaded.modified,
+                None => true,
+            };
+
+            if unchanged {
+                return Ok(false);
+            }
+        }
+
+        let mut errors = Vec::new();
+
+        for source in font_face.sources.iter() {
+            let (typeface, path) = match source {
+                FontSource::File(path) => {
+                    let data = match std::fs::read(path) {
+                        Ok(data) => data,
+                        Err(error) => {
+                            errors.push(format!(
+                                "Failed to read font '{}': {}",
+                                path.display(),
+                                error
+                            ));
+                            continue;
+                        }
+                    };
+
+                    match self.default_font_manager.new_from_data(&data, None) {
+                        Some(typeface) => (typeface, Some(path)),
+                        None => {
+                            errors
+                                .push(format!("'{}' is not a supported font file", path.display()));
+                            continue;
+                        }
+                    }
+                }
+
+                FontSource::Local(name) => {
+                    match self.default_font_manager.match_family_style(name, font_face.font_style())
+                    {
+                        Some(typeface) => (typeface, None),
+                        None => {
+                            errors.push(format!("No local font named '{}' was found", name));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            self.asset_provider
+                .register_typeface(font_face.apply_variations(typeface), font_face.family.as_str());
+
+            self.loaded_font_faces.retain(|loaded| loaded.font_face != *font_face);
+            self.loaded_font_faces.push(LoadedFontFace {
+                font_face: font_face.clone(),
+                path: path.cloned(),
+                modified: path.and_then(|path| modified_time(path)),
+            });
+
+            return Ok(true);
+        }
+
+        Err(errors.join("; "))
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use vizia_style::{CssRule, ParserOptions, StyleSheet};
+
+    fn parse_font_face(css: &str, base_path: Option<&Path>) -> Result<FontFace, String> {
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let CssRule::FontFace(rule) = &stylesheet.rules.0[0] else {
+            panic!("expected a @font-face rule");
+        };
+
+        FontFace::from_rule(rule, base_path)
+    }
+
+    #[test]
+    fn from_rule_resolves_urls_and_skips_unsupported_sources() {
+        let css = r#"@font-face {
+            font-family: "Test";
+            src: url("fonts/a.ttf") format("truetype"),
+                url("/fonts/b.otf"),
+                url("file:///fonts/c.ttf"),
+                url("https://example.com/d.ttf"),
+                url("e.woff2") format("woff2"),
+                url("f.svg") format("svg"),
+                local(Inter);
+        }"#;
+
+        let font_face = parse_font_face(css, Some(Path::new("/styles"))).unwrap();
+        assert_eq!(font_face.family, "Test");
+        assert_eq!(
+            font_face.sources,
+            [
+                FontSource::File(PathBuf::from("/styles/fonts/a.ttf")),
+                FontSource::File(PathBuf::from("/fonts/b.otf")),
+                FontSource::File(PathBuf::from("/fonts/c.ttf")),
+                FontSource::Local(String::from("Inter")),
+            ]
+        );
+
+        // Without a base path relative urls are left as they are.
+        let font_face = parse_font_face(css, None).unwrap();
+        assert_eq!(font_face.sources[0], FontSource::File(PathBuf::from("fonts/a.ttf")));
+    }
+
+    #[test]
+    fn from_rule_requires_a_supported_source() {
+        let css = r#"@font-face {
+            font-family: "Test";
+            src: url("a.woff") format("woff"), url("https://example.com/b.ttf");
+        }"#;
+
+        assert!(parse_font_face(css, None).is_err());
+    }
+
+    #[test]
+    fn load_font_face_falls_back_to_next_source() {
+        let cx = &mut Context::default();
+        let missing = PathBuf::from(TEST_FONT).with_file_name("Missing.ttf");
+        let font_face = FontFace {
+            family: String::from("Test Mono"),
+            sources: vec![FontSource::File(missing.clone()), FontSource::File(TEST_FONT.into())],
+            weight: None,
+            slant: None,
+        };
+
+        assert_eq!(cx.text_context.load_font_face(&font_face), Ok(true));
+        assert_eq!(
+            cx.text_context.loaded_font_faces[0].path.as_deref(),
+            Some(Path::new(TEST_FONT))
+        );
+
+        // The font is not registered again while its file is unchanged.
+        assert_eq!(cx.text_context.load_font_face(&font_face), Ok(false));
+
+        // Each source is reported when none of them can be loaded.
+        let font_face = FontFace { sources: vec![FontSource::File(missing.clone())], ..font_face };
+        let error = cx.text_context.load_font_face(&font_face).unwrap_err();
+        assert!(error.contains(&missing.display().to_string()));
+    }
+}
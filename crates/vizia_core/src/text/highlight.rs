@@ -0,0 +1,169 @@
+use std::ops::Range;
+
+/// The state of a [`Highlighter`] at a line boundary, such as whether the line ends within a
+/// block comment or a multi-line string.
+///
+/// The meaning of the value is up to the highlighter. The state at the start of a document is
+/// the default state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HighlightState(pub u64);
+
+/// The kind of a token of source code.
+///
+/// The text of a token is displayed with the class of its kind, such as `keyword`, so that themes
+/// can style it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Type,
+    Function,
+    Macro,
+    Variable,
+    Constant,
+    String,
+    Number,
+    Comment,
+    Operator,
+    Punctuation,
+    Attribute,
+}
+
+impl TokenKind {
+    /// Every kind of token.
+    pub const ALL: [TokenKind; 12] = [
+        TokenKind::Keyword,
+        TokenKind::Type,
+        TokenKind::Function,
+        TokenKind::Macro,
+        TokenKind::Variable,
+        TokenKind::Constant,
+        TokenKind::String,
+        TokenKind::Number,
+        TokenKind::Comment,
+        TokenKind::Operator,
+        TokenKind::Punctuation,
+        TokenKind::Attribute,
+    ];
+
+    /// Returns the class added to the text of a token of this kind.
+    pub fn class(&self) -> &'static str {
+        match self {
+            TokenKind::Keyword => "keyword",
+            TokenKind::Type => "type",
+            TokenKind::Function => "function",
+            TokenKind::Macro => "macro",
+            TokenKind::Variable => "variable",
+            TokenKind::Constant => "constant",
+            TokenKind::String => "string",
+            TokenKind::Number => "number",
+            TokenKind::Comment => "comment",
+            TokenKind::Operator => "operator",
+            TokenKind::Punctuation => "punctuation",
+            TokenKind::Attribute => "attribute",
+        }
+    }
+}
+
+/// A range of source code with a [`TokenKind`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeToken {
+    /// The byte range of the token within the line or code which was highlighted.
+    pub range: Range<usize>,
+    pub kind: TokenKind,
+}
+
+impl CodeToken {
+    pub fn new(range: Range<usize>, kind: TokenKind) -> Self {
+        Self { range, kind }
+    }
+}
+
+/// Splits source code into tokens, to highlight the syntax of a `TextEditor` or of the code blocks of
+/// a `Markdown` view. The text of a token is styled by the rules which match the class of its kind.
+///
+/// Code is highlighted one line at a time, in order, with the state left by the previous line. When a
+/// line of a text editor is edited only that line is highlighted again, unless the edit changes the
+/// state at the end of the line, in which case the following lines are highlighted again as well.
+///
+/// A highlighter is registered for the code blocks of a language with
+/// [`Context::add_code_highlighter`](crate::context::Context::add_code_highlighter). Any function
+/// with the same signature as [`Highlighter::highlight_line`] is also a highlighter.
+pub trait Highlighter: 'static {
+    /// Returns the tokens of a line of text, excluding its line break, which should be sorted and
+    /// should not overlap. The state is updated to the state at the end of the line. Text which is
+    /// not within a token is not highlighted.
+    fn highlight_line(&self, line: &str, state: &mut HighlightState) -> Vec<CodeToken>;
+
+    /// Returns the tokens of a block of code, whose ranges are within the whole code, by
+    /// highlighting each of its lines in turn.
+    fn highlight(&self, code: &str) -> Vec<CodeToken> {
+        let mut state = HighlightState::default();
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        for line in code.split_inclusive('\n') {
+            let text = line.strip_suffix('\n').unwrap_or(line);
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            tokens.extend(
+                self.highlight_line(text, &mut state)
+                    .into_iter()
+                    .filter(|token| token.range.end <= text.len())
+                    .map(|token| {
+                        CodeToken::new(
+                            start + token.range.start..start + token.range.end,
+                            token.kind,
+                        )
+                    }),
+            );
+            start += line.len();
+        }
+
+        tokens
+    }
+}
+
+impl<F> Highlighter for F
+where
+    F: 'static + Fn(&str, &mut HighlightState) -> Vec<CodeToken>,
+{
+    fn highlight_line(&self, line: &str, state: &mut HighlightState) -> Vec<CodeToken> {
+        (self)(line, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Finds the words of each line, which are comments after a `/*` until the next `*/`.
+    fn words(line: &str, state: &mut HighlightState) -> Vec<CodeToken> {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        for word in line.split(' ') {
+            match word {
+                "/*" => state.0 = 1,
+                "*/" => state.0 = 0,
+                _ => {
+                    let kind = if state.0 == 1 { TokenKind::Comment } else { TokenKind::Variable };
+                    tokens.push(CodeToken::new(start..start + word.len(), kind));
+                }
+            }
+            start += word.len() + 1;
+        }
+
+        // A token which is not within the line.
+        tokens.push(CodeToken::new(line.len()..line.len() + 1, TokenKind::Punctuation));
+        tokens
+    }
+
+    #[test]
+    fn highlight_offsets_lines_and_keeps_state() {
+        assert_eq!(
+            words.highlight("a /*\r\nb\n*/ c"),
+            [
+                CodeToken::new(0..1, TokenKind::Variable),
+                CodeToken::new(6..7, TokenKind::Comment),
+                CodeToken::new(11..12, TokenKind::Variable),
+            ]
+        );
+    }
+}
@@ -7,9 +7,21 @@ pub(crate) use scrolling::*;
 pub(crate) mod text_context;
 pub(crate) use text_context::*;
 
+pub(crate) mod font_face;
+pub(crate) use font_face::*;
+
 pub mod editable_text;
 pub use editable_text::*;
 
+pub mod document;
+pub use document::*;
+
+pub mod highlight;
+pub use highlight::*;
+
+pub mod rich_text;
+pub use rich_text::*;
+
 pub mod selection;
 pub use selection::*;
 
@@ -0,0 +1,353 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::prelude::*;
+
+/// The style of a run of [`RichText`].
+#[derive(Debug, Clone, Default, PartialEq, Data)]
+pub struct RunStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// The color of the text, or `None` to use the color of the view.
+    pub color: Option<Color>,
+    /// The URL which the text links to.
+    pub link: Option<String>,
+}
+
+impl RunStyle {
+    /// Returns whether the style has the given attribute.
+    pub fn has(&self, attribute: RunAttribute) -> bool {
+        match attribute {
+            RunAttribute::Bold => self.bold,
+            RunAttribute::Italic => self.italic,
+            RunAttribute::Underline => self.underline,
+        }
+    }
+
+    /// Adds or removes the given attribute.
+    pub fn set(&mut self, attribute: RunAttribute, flag: bool) {
+        match attribute {
+            RunAttribute::Bold => self.bold = flag,
+            RunAttribute::Italic => self.italic = flag,
+            RunAttribute::Underline => self.underline = flag,
+        }
+    }
+}
+
+/// An attribute of a [`RunStyle`] which can be toggled on and off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunAttribute {
+    Bold,
+    Italic,
+    Underline,
+}
+
+#[derive(Debug, Clone, PartialEq, Data)]
+struct TextRun {
+    len: usize,
+    style: RunStyle,
+}
+
+/// A document of text made up of runs with different styles.
+///
+/// The runs always cover the whole text, and adjacent runs always have different styles. Offsets
+/// and ranges are byte offsets into the plain text of the document.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let text = RichText::from("Hello ")
+///     .with("World", RunStyle { bold: true, ..Default::default() });
+///
+/// assert_eq!(text.text(), "Hello World");
+/// assert_eq!(text.to_html(), "Hello <b>World</b>");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Data)]
+pub struct RichText {
+    text: String,
+    runs: Vec<TextRun>,
+}
+
+impl RichText {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the document with some styled text appended to it.
+    pub fn with(mut self, text: &str, style: RunStyle) -> Self {
+        self.push(text, style);
+        self
+    }
+
+    /// Appends some styled text to the document.
+    pub fn push(&mut self, text: &str, style: RunStyle) {
+        let end = self.text.len();
+        self.edit(end..end, text, style);
+    }
+
+    /// Returns the plain text of the document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the length of the plain text of the document in bytes.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns `true` if the document contains no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns an iterator over the range and style of each run of the document.
+    pub fn runs(&self) -> impl Iterator<Item = (Range<usize>, &RunStyle)> {
+        self.runs.iter().scan(0, |start, run| {
+            let range = *start..*start + run.len;
+            *start = range.end;
+            Some((range, &run.style))
+        })
+    }
+
+    /// Returns the style of text inserted at the given offset, which continues the style of the
+    /// preceding text.
+    pub fn style_at(&self, offset: usize) -> RunStyle {
+        self.runs()
+            .find(|(range, _)| offset > range.start && offset <= range.end)
+            .or_else(|| self.runs().next())
+            .map(|(_, style)| style.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replaces a range of the document with some styled text.
+    pub fn edit(&mut self, range: Range<usize>, text: &str, style: RunStyle) {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        self.runs.splice(start..end, [TextRun { len: text.len(), style }]);
+        self.text.replace_range(range, text);
+        self.normalize();
+    }
+
+    /// Applies a function to the style of the text within a range of the document.
+    pub fn update_style(&mut self, range: Range<usize>, f: impl Fn(&mut RunStyle)) {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for run in &mut self.runs[start..end] {
+            f(&mut run.style);
+        }
+        self.normalize();
+    }
+
+    /// Returns `true` if all of the text within a range of the document has the given attribute.
+    ///
+    /// For an empty range this is the attribute of the text which would be inserted at the range.
+    pub fn has_attribute(&self, range: Range<usize>, attribute: RunAttribute) -> bool {
+        if range.is_empty() {
+            return self.style_at(range.start).has(attribute);
+        }
+
+        self.runs()
+            .filter(|(run, _)| run.start < range.end && run.end > range.start)
+            .all(|(_, style)| style.has(attribute))
+    }
+
+    /// Removes an attribute from a range of the document if all of the text within the range has
+    /// the attribute, otherwise adds the attribute to all of the text within the range.
+    pub fn toggle_attribute(&mut self, range: Range<usize>, attribute: RunAttribute) {
+        let flag = !self.has_attribute(range.clone(), attribute);
+        self.update_style(range, |style| style.set(attribute, flag));
+    }
+
+    /// Returns a copy of a range of the document.
+    pub fn slice(&self, range: Range<usize>) -> RichText {
+        let mut slice = RichText::new();
+        for (run, style) in self.runs() {
+            let start = run.start.max(range.start);
+            let end = run.end.min(range.end);
+            if start < end {
+                slice.push(&self.text[start..end], style.clone());
+            }
+        }
+        slice
+    }
+
+    /// Converts the document to HTML, using `<b>`, `<i>`, `<u>` and `<a>` elements and inline
+    /// color styles.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        for (range, style) in self.runs() {
+            if let Some(link) = &style.link {
+                let _ = write!(html, "<a href=\"{}\">", escape_html(link));
+            }
+            if let Some(color) = style.color {
+                let _ = write!(html, "<span style=\"color: {}\">", css_color(color));
+            }
+            if style.bold {
+                html.push_str("<b>");
+            }
+            if style.italic {
+                html.push_str("<i>");
+            }
+            if style.underline {
+                html.push_str("<u>");
+            }
+
+            html.push_str(&escape_html(&self.text[range]).replace('\n', "<br>"));
+
+            if style.underline {
+                html.push_str("</u>");
+            }
+            if style.italic {
+                html.push_str("</i>");
+            }
+            if style.bold {
+                html.push_str("</b>");
+            }
+            if style.color.is_some() {
+                html.push_str("</span>");
+            }
+            if style.link.is_some() {
+                html.push_str("</a>");
+            }
+        }
+        html
+    }
+
+    /// Splits the run containing the offset so that a run starts at the offset, and returns the
+    /// index of that run, or the number of runs if the offset is the end of the document.
+    fn split_at(&mut self, offset: usize) -> usize {
+        let mut start = 0;
+        for index in 0..self.runs.len() {
+            let len = self.runs[index].len;
+            if offset == start {
+                return index;
+            }
+
+            if offset < start + len {
+                let style = self.runs[index].style.clone();
+                self.runs[index].len = offset - start;
+                self.runs.insert(index + 1, TextRun { len: start + len - offset, style });
+                return index + 1;
+            }
+
+            start += len;
+        }
+
+        self.runs.len()
+    }
+
+    /// Removes empty runs and merges adjacent runs with the same style.
+    fn normalize(&mut self) {
+        self.runs.retain(|run| run.len > 0);
+        self.runs.dedup_by(|run, previous| {
+            if run.style == previous.style {
+                previous.len += run.len;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        RichText::new().with(text, RunStyle::default())
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        RichText::from(text.as_str())
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn css_color(color: Color) -> String {
+    if color.a() == 255 {
+        format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", color.r(), color.g(), color.b(), color.a())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> RunStyle {
+        RunStyle { bold: true, ..Default::default() }
+    }
+
+    fn runs(text: &RichText) -> Vec<(&str, bool)> {
+        text.runs().map(|(range, style)| (&text.text()[range], style.bold)).collect()
+    }
+
+    #[test]
+    fn edit_merges_runs() {
+        let mut text = RichText::from("Hello World");
+        text.edit(5..6, "_", bold());
+        assert_eq!(runs(&text), [("Hello", false), ("_", true), ("World", false)]);
+
+        text.edit(5..6, " ", RunStyle::default());
+        assert_eq!(runs(&text), [("Hello World", false)]);
+
+        text.edit(0..11, "", RunStyle::default());
+        assert!(text.is_empty());
+        assert_eq!(text.runs().count(), 0);
+    }
+
+    #[test]
+    fn style_at_continues_preceding_text() {
+        let text = RichText::new().with("ab", bold()).with("cd", RunStyle::default());
+        assert!(text.style_at(0).bold);
+        assert!(text.style_at(2).bold);
+        assert!(!text.style_at(3).bold);
+    }
+
+    #[test]
+    fn toggle_attribute() {
+        let mut text = RichText::new().with("ab", bold()).with("cd", RunStyle::default());
+        text.toggle_attribute(1..3, RunAttribute::Bold);
+        assert_eq!(runs(&text), [("abc", true), ("d", false)]);
+
+        text.toggle_attribute(0..3, RunAttribute::Bold);
+        assert_eq!(runs(&text), [("abcd", false)]);
+    }
+
+    #[test]
+    fn html() {
+        let text = RichText::new().with("a < b", bold()).with("\n", RunStyle::default()).with(
+            "link",
+            RunStyle {
+                italic: true,
+                color: Some(Color::rgb(255, 0, 0)),
+                link: Some(String::from("https://example.com?a=1&b=2")),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            text.to_html(),
+            "<b>a &lt; b</b><br><a href=\"https://example.com?a=1&amp;b=2\">\
+             <span style=\"color: #ff0000\"><i>link</i></span></a>"
+        );
+        assert_eq!(text.slice(2..7).text(), "< b\nl");
+        assert_eq!(text.slice(0..5).to_html(), "<b>a &lt; b</b>");
+    }
+}
@@ -1,8 +1,87 @@
-use skia_safe::textlayout::{Paragraph, TypefaceFontProvider};
+use skia_safe::textlayout::{
+    Paragraph, ParagraphBuilder, PlaceholderAlignment, PlaceholderStyle, TextBaseline, TextStyle,
+    TypefaceFontProvider,
+};
 use skia_safe::{textlayout::FontCollection, FontMgr};
 use vizia_storage::SparseSet;
 
-use crate::{entity::Entity, layout::BoundingBox};
+use crate::{
+    entity::Entity,
+    layout::BoundingBox,
+    style::Color,
+    text::{utf16_to_utf8, utf8_to_utf16, LoadedFontFace, TextDocument},
+};
+
+/// The content generated by a `::before` or `::after` pseudo-element. Generated content is laid out
+/// inline, at the start or end of the paragraph of its entity.
+pub(crate) enum GeneratedContent {
+    /// A run of text with the text style of the pseudo-element.
+    Text { text: String, text_style: TextStyle },
+    /// An image, laid out as a placeholder the size of the font of the pseudo-element, with an
+    /// optional fill color for SVGs.
+    Image { name: String, size: f32, fill: Option<Color> },
+}
+
+impl GeneratedContent {
+    /// Returns the length of the content within the paragraph in UTF-16 code units. A placeholder is
+    /// a single object replacement character.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            GeneratedContent::Text { text, .. } => text.encode_utf16().count(),
+            GeneratedContent::Image { .. } => 1,
+        }
+    }
+
+    /// Adds the content to a paragraph.
+    pub(crate) fn add_to(&self, paragraph_builder: &mut ParagraphBuilder) {
+        match self {
+            GeneratedContent::Text { text, text_style } => {
+                paragraph_builder.push_style(text_style);
+                paragraph_builder.add_text(text);
+                paragraph_builder.pop();
+            }
+
+            GeneratedContent::Image { size, .. } => {
+                paragraph_builder.add_placeholder(&PlaceholderStyle::new(
+                    *size,
+                    *size,
+                    PlaceholderAlignment::Middle,
+                    TextBaseline::Alphabetic,
+                    0.0,
+                ));
+            }
+        }
+    }
+}
+
+/// Maps between byte offsets into the text of a paragraph, i.e. the text of an entity followed by the
+/// text of its spans, and UTF-16 indices into the paragraph. Only paragraphs with text which changed
+/// length when it was masked or transformed, e.g. `ß` which is `SS` in uppercase, have a map.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct OffsetMap {
+    /// The byte offset and index of the start of each displayed segment of the text, followed by the
+    /// end of the text, in ascending order.
+    points: Vec<(usize, usize)>,
+}
+
+impl OffsetMap {
+    /// Adds the start of a segment of the text.
+    pub(crate) fn push(&mut self, offset: usize, index: usize) {
+        self.points.push((offset, index));
+    }
+
+    /// Returns the index of the segment containing the given byte offset.
+    pub(crate) fn index(&self, offset: usize) -> usize {
+        let point = self.points.partition_point(|(start, _)| *start <= offset);
+        self.points.get(point.saturating_sub(1)).map_or(0, |(_, index)| *index)
+    }
+
+    /// Returns the byte offset of the segment containing the given index.
+    pub(crate) fn offset(&self, index: usize) -> usize {
+        let point = self.points.partition_point(|(_, start)| *start <= index);
+        self.points.get(point.saturating_sub(1)).map_or(0, |(offset, _)| *offset)
+    }
+}
 
 pub struct TextContext {
     pub font_collection: FontCollection,
@@ -10,6 +89,16 @@ pub struct TextContext {
     pub asset_provider: TypefaceFontProvider,
     pub text_bounds: SparseSet<BoundingBox>,
     pub text_paragraphs: SparseSet<Paragraph>,
+    /// Documents which are laid out line by line, such as the text of a [`TextEditor`](crate::views::TextEditor).
+    pub text_documents: SparseSet<TextDocument>,
+    /// The content generated by the `::before` pseudo-element of entities.
+    pub(crate) before_content: SparseSet<GeneratedContent>,
+    /// The content generated by the `::after` pseudo-element of entities.
+    pub(crate) after_content: SparseSet<GeneratedContent>,
+    /// The offset maps of paragraphs with masked or transformed text.
+    pub(crate) offset_maps: SparseSet<OffsetMap>,
+    /// Fonts which have been loaded from `@font-face` rules.
+    pub(crate) loaded_font_faces: Vec<LoadedFontFace>,
 }
 
 impl TextContext {
@@ -18,6 +107,30 @@ impl TextContext {
         &self.font_collection
     }
 
+    /// Returns the offset of the text of an entity within its paragraph, in UTF-16 code units, which
+    /// is the length of the content generated by its `::before` pseudo-element.
+    pub(crate) fn text_offset(&self, entity: Entity) -> usize {
+        self.before_content.get(entity).map_or(0, GeneratedContent::len)
+    }
+
+    /// Returns the UTF-16 index into the paragraph of an entity of a byte offset into the given text,
+    /// which is the text of the entity followed by the text of its spans.
+    pub(crate) fn paragraph_index(&self, entity: Entity, text: &str, offset: usize) -> usize {
+        match self.offset_maps.get(entity) {
+            Some(offset_map) => offset_map.index(offset),
+            None => self.text_offset(entity) + utf8_to_utf16(text, offset),
+        }
+    }
+
+    /// Returns the byte offset into the given text of a UTF-16 index into the paragraph of an entity.
+    /// Indices within generated content are clamped to the start or end of the text.
+    pub(crate) fn text_offset_at(&self, entity: Entity, text: &str, index: usize) -> usize {
+        match self.offset_maps.get(entity) {
+            Some(offset_map) => offset_map.offset(index).min(text.len()),
+            None => utf16_to_utf8(text, index.saturating_sub(self.text_offset(entity))),
+        }
+    }
+
     pub(crate) fn set_text_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         self.text_bounds.insert(entity, bounds);
     }
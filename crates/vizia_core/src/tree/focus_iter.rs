@@ -24,7 +24,7 @@ pub(crate) fn is_navigatable(
     }
 
     // Skip non-displayed widgets
-    if style.display.get(node).copied().unwrap_or_default() == Display::None {
+    if !is_displayed(style, node) {
         return false;
     }
 
@@ -45,6 +45,13 @@ pub(crate) fn is_navigatable(
         .unwrap_or(false)
 }
 
+/// Is the entity displayed? Text spans are not displayed themselves but are drawn as part of the
+/// paragraph of their parent, so they can still be focused.
+fn is_displayed(style: &Style, node: Entity) -> bool {
+    style.display.get(node).copied().unwrap_or_default() != Display::None
+        || style.text_span.get(node).copied().unwrap_or_default()
+}
+
 /// Get the next entity to be focused during forward keyboard navigation.
 pub(crate) fn focus_forward(
     tree: &Tree<Entity>,
@@ -56,7 +63,7 @@ pub(crate) fn focus_forward(
         tree,
         DoubleEndedTreeTour::new(Some(node), Some(Entity::root())),
         |node| {
-            style.display.get(node).copied().unwrap_or_default() == Display::None
+            !is_displayed(style, node)
             // false
         },
     )
@@ -81,7 +88,7 @@ pub(crate) fn focus_backward(
             // Check if any ancestors are not displayed.
             // TODO: Think of a better way to do thus.
             for ancestor in node.parent_iter(tree) {
-                if style.display.get(ancestor).copied().unwrap_or_default() == Display::None {
+                if !is_displayed(style, ancestor) {
                     return true;
                 }
             }
@@ -92,3 +99,27 @@ pub(crate) fn focus_backward(
     iter.next_back();
     iter.filter(|node| is_navigatable(tree, style, *node, lock_focus_to)).next_back()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn tab_navigates_through_links_within_text() {
+        let cx = &mut Context::default();
+        let before = Element::new(cx).navigable(true).entity();
+        let label = Label::rich(cx, "Visit ", |cx| {
+            TextSpan::link(cx, "https://github.com/vizia/vizia", |_| {});
+        })
+        .entity();
+        let link = cx.tree.get_first_child(label).unwrap();
+        let after = Element::new(cx).navigable(true).entity();
+
+        let root = Entity::root();
+        assert_eq!(focus_forward(&cx.tree, &cx.style, before, root), Some(link));
+        assert_eq!(focus_forward(&cx.tree, &cx.style, link, root), Some(after));
+        assert_eq!(focus_backward(&cx.tree, &cx.style, after, root), Some(link));
+        assert_eq!(focus_backward(&cx.tree, &cx.style, link, root), Some(before));
+    }
+}
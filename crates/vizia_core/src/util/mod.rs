@@ -4,6 +4,17 @@ use std::path::{Path, PathBuf};
 pub trait IntoCssStr: 'static {
     /// Returns a string containing CSS.
     fn get_style(&self) -> Result<String, std::io::Error>;
+
+    /// Returns a name for the stylesheet, such as the path of the file it was loaded from.
+    fn source_name(&self) -> String {
+        String::from("<inline>")
+    }
+
+    /// Returns the directory that relative urls in the stylesheet, such as font sources, are
+    /// resolved against.
+    fn base_path(&self) -> Option<PathBuf> {
+        None
+    }
 }
 
 impl IntoCssStr for CSS {
@@ -14,6 +25,22 @@ impl IntoCssStr for CSS {
             CSS::String(style_string) => Ok(style_string.to_owned()),
         }
     }
+
+    fn source_name(&self) -> String {
+        match self {
+            CSS::Path(path) => path.display().to_string(),
+
+            CSS::String(_) => String::from("<inline>"),
+        }
+    }
+
+    fn base_path(&self) -> Option<PathBuf> {
+        match self {
+            CSS::Path(path) => path.parent().map(Path::to_path_buf),
+
+            CSS::String(_) => None,
+        }
+    }
 }
 
 impl IntoCssStr for &'static str {
@@ -26,12 +53,28 @@ impl IntoCssStr for PathBuf {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn source_name(&self) -> String {
+        self.display().to_string()
+    }
+
+    fn base_path(&self) -> Option<PathBuf> {
+        self.parent().map(Path::to_path_buf)
+    }
 }
 
 impl IntoCssStr for Path {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn source_name(&self) -> String {
+        self.display().to_string()
+    }
+
+    fn base_path(&self) -> Option<PathBuf> {
+        self.parent().map(Path::to_path_buf)
+    }
 }
 
 #[doc(hidden)]
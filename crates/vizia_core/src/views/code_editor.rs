@@ -0,0 +1,585 @@
+use std::{borrow::Cow, marker::PhantomData, ops::Range};
+
+use regex::{Regex, RegexBuilder};
+
+use crate::icons::{
+    ICON_ARROW_DOWN, ICON_ARROW_UP, ICON_LETTER_CASE, ICON_REGEX, ICON_REPLACE, ICON_X,
+};
+use crate::prelude::*;
+
+/// Events which control the find and replace bar of a [`CodeEditor`].
+pub enum CodeEditorEvent {
+    /// Shows the find and replace bar and focuses the search field.
+    OpenFind,
+    /// Hides the find and replace bar and returns the focus to the editor.
+    CloseFind,
+    /// Sets the text searched for, and selects the nearest match.
+    SetQuery(String),
+    /// Sets the text which replaces matches.
+    SetReplacement(String),
+    /// Toggles whether the query is a regular expression.
+    ToggleRegex,
+    /// Toggles whether the search is case sensitive.
+    ToggleCaseSensitive,
+    /// Selects the next match, wrapping around to the first match.
+    FindNext,
+    /// Selects the previous match, wrapping around to the last match.
+    FindPrevious,
+    /// Replaces the selected match and selects the next match.
+    Replace,
+    /// Replaces every match.
+    ReplaceAll,
+}
+
+/// Sent by the text editor of a [`CodeEditor`] when it has been edited.
+struct Edited(Rope);
+
+/// Sent to a [`CodeEditor`] when the bound source code changes.
+struct SourceChanged;
+
+/// A view for editing source code.
+///
+/// The code editor is a [`TextEditor`] which shows line numbers, highlights the current line and matching
+/// brackets, and indents lines with the tab key. The text can be styled by a [`Highlighter`], such as to
+/// highlight the syntax of a programming language.
+///
+/// Pressing Ctrl+F (Cmd+F on macOS) shows a find and replace bar above the editor. Matches are highlighted
+/// as the query is typed, and the query can be a regular expression, in which case the replacement can
+/// refer to capture groups, e.g. `$1`. Matches never span a line break.
+///
+/// # Example
+/// ```rust
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     source: Rope,
+/// # }
+/// #
+/// # pub enum AppEvent {
+/// #     SetSource(Rope),
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { source: Rope::from_str("fn main() {}") }.build(cx);
+/// #
+/// CodeEditor::new(cx, AppData::source)
+///     .indent(Indent::Spaces(2))
+///     .on_edit(|cx, source| cx.emit(AppEvent::SetSource(source)));
+/// ```
+#[derive(Lens)]
+pub struct CodeEditor<L: Lens> {
+    p: PhantomData<L>,
+    editor: Entity,
+    query_box: Entity,
+    find_open: bool,
+    query: String,
+    replacement: String,
+    regex: bool,
+    case_sensitive: bool,
+    invalid_query: bool,
+    matches: Vec<Range<usize>>,
+    /// The text which the matches were found in.
+    searched: Option<Rope>,
+    current_match: Option<usize>,
+    /// The offset from which to select the next match once the document has been edited by a replacement.
+    replaced_to: Option<usize>,
+    status: String,
+    on_edit: Option<Box<dyn Fn(&mut EventContext, Rope) + Send + Sync>>,
+}
+
+impl<L> CodeEditor<L>
+where
+    L: Lens<Target = Rope>,
+{
+    /// Creates a new code editor for the source code bound by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        let mut editor = Entity::null();
+        let mut query_box = Entity::null();
+
+        Self {
+            p: PhantomData,
+            editor: Entity::null(),
+            query_box: Entity::null(),
+            find_open: false,
+            query: String::new(),
+            replacement: String::new(),
+            regex: false,
+            case_sensitive: false,
+            invalid_query: false,
+            matches: Vec::new(),
+            searched: None,
+            current_match: None,
+            replaced_to: None,
+            status: String::new(),
+            on_edit: None,
+        }
+        .build(cx, |cx| {
+            VStack::new(cx, |cx| {
+                HStack::new(cx, |cx| {
+                    query_box = Textbox::new(cx, Self::query)
+                        .placeholder("Find")
+                        .on_edit(|cx, text| cx.emit(CodeEditorEvent::SetQuery(text)))
+                        .on_submit(|cx, _, enter| {
+                            if enter {
+                                cx.emit(CodeEditorEvent::FindNext);
+                            }
+                        })
+                        .on_cancel(|cx| cx.emit(CodeEditorEvent::CloseFind))
+                        .toggle_class("invalid", Self::invalid_query)
+                        .entity();
+
+                    ToggleButton::new(cx, Self::case_sensitive, |cx| {
+                        Svg::new(cx, ICON_LETTER_CASE)
+                    })
+                    .on_toggle(|cx| cx.emit(CodeEditorEvent::ToggleCaseSensitive))
+                    .name("Match case");
+
+                    ToggleButton::new(cx, Self::regex, |cx| Svg::new(cx, ICON_REGEX))
+                        .on_toggle(|cx| cx.emit(CodeEditorEvent::ToggleRegex))
+                        .name("Use regular expression");
+
+                    Label::new(cx, Self::status).class("status");
+
+                    Button::new(cx, |cx| Svg::new(cx, ICON_ARROW_UP))
+                        .variant(ButtonVariant::Text)
+                        .on_press(|cx| cx.emit(CodeEditorEvent::FindPrevious))
+                        .name("Previous match");
+
+                    Button::new(cx, |cx| Svg::new(cx, ICON_ARROW_DOWN))
+                        .variant(ButtonVariant::Text)
+                        .on_press(|cx| cx.emit(CodeEditorEvent::FindNext))
+                        .name("Next match");
+
+                    Button::new(cx, |cx| Svg::new(cx, ICON_X))
+                        .variant(ButtonVariant::Text)
+                        .on_press(|cx| cx.emit(CodeEditorEvent::CloseFind))
+                        .name("Close");
+                })
+                .class("find-row");
+
+                HStack::new(cx, |cx| {
+                    Textbox::new(cx, Self::replacement)
+                        .placeholder("Replace")
+                        .on_edit(|cx, text| cx.emit(CodeEditorEvent::SetReplacement(text)))
+                        .on_submit(|cx, _, enter| {
+                            if enter {
+                                cx.emit(CodeEditorEvent::Replace);
+                            }
+                        })
+                        .on_cancel(|cx| cx.emit(CodeEditorEvent::CloseFind));
+
+                    Button::new(cx, |cx| Svg::new(cx, ICON_REPLACE))
+                        .variant(ButtonVariant::Text)
+                        .on_press(|cx| cx.emit(CodeEditorEvent::Replace))
+                        .name("Replace");
+
+                    Button::new(cx, |cx| Label::new(cx, "All"))
+                        .variant(ButtonVariant::Text)
+                        .on_press(|cx| cx.emit(CodeEditorEvent::ReplaceAll))
+                        .name("Replace all");
+                })
+                .class("replace-row");
+            })
+            .class("find-bar")
+            .display(Self::find_open);
+
+            editor = TextEditor::new(cx, lens)
+                .indent(Indent::Spaces(4))
+                .line_numbers(true)
+                .highlight_current_line(true)
+                .match_brackets(true)
+                .on_edit(|cx, text| cx.emit(Edited(text)))
+                .entity();
+        })
+        .modify(|code_editor| {
+            code_editor.editor = editor;
+            code_editor.query_box = query_box;
+        })
+        .bind(lens, |mut handle, _| {
+            let entity = handle.entity();
+            handle.context().emit_to(entity, SourceChanged);
+        })
+    }
+
+    /// Searches the document for the query and highlights the matches in the editor.
+    ///
+    /// The current match becomes the first match at or after the given offset, or else the start of the
+    /// previous current match. If `reveal` is true the current match is also selected.
+    fn search(&mut self, cx: &mut EventContext, from: Option<usize>, reveal: bool) {
+        let from = from
+            .or_else(|| {
+                self.current_match.and_then(|index| self.matches.get(index)).map(|m| m.start)
+            })
+            .unwrap_or_default();
+
+        self.matches.clear();
+        self.searched = None;
+        self.invalid_query = false;
+        if self.find_open && !self.query.is_empty() {
+            match build_regex(&self.query, self.regex, self.case_sensitive) {
+                Ok(regex) => {
+                    if let Some(document) = cx.text_context.text_documents.get(self.editor) {
+                        self.searched = Some(document.text().clone());
+                        self.matches = find_matches(&regex, document.text());
+                    }
+                }
+
+                Err(_) => self.invalid_query = true,
+            }
+        }
+
+        self.current_match = nearest_match(&self.matches, from);
+
+        cx.emit_to(self.editor, TextEditorEvent::SetMatches(self.matches.clone()));
+        if reveal {
+            self.reveal_current_match(cx);
+        }
+        self.update_status();
+    }
+
+    /// Moves to the next or previous match, wrapping around at either end of the document.
+    fn step(&mut self, cx: &mut EventContext, forward: bool) {
+        if let Some(current) = self.current_match {
+            self.current_match = Some(step_match(current, self.matches.len(), forward));
+            self.reveal_current_match(cx);
+            self.update_status();
+        }
+    }
+
+    fn reveal_current_match(&self, cx: &mut EventContext) {
+        if let Some(range) = self.current_match.and_then(|index| self.matches.get(index)) {
+            cx.emit_to(self.editor, TextEditorEvent::Select(range.clone()));
+        }
+    }
+
+    /// Replaces the current match, or every match if `all` is true.
+    fn replace(&mut self, cx: &mut EventContext, all: bool) {
+        let Ok(regex) = build_regex(&self.query, self.regex, self.case_sensitive) else {
+            return;
+        };
+        let Some(document) = cx.text_context.text_documents.get(self.editor) else {
+            return;
+        };
+
+        let ranges = if all {
+            self.matches.clone()
+        } else {
+            self.current_match
+                .and_then(|index| self.matches.get(index))
+                .cloned()
+                .into_iter()
+                .collect()
+        };
+
+        let replacements = ranges
+            .into_iter()
+            .map(|range| {
+                let replacement = if self.regex {
+                    expand_replacement(&regex, document.text(), range.start, &self.replacement)
+                } else {
+                    self.replacement.clone()
+                };
+
+                (range, replacement)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some((range, replacement)) = replacements.first() {
+            if !all {
+                self.replaced_to = Some(range.start + replacement.len());
+            }
+
+            cx.emit_to(self.editor, TextEditorEvent::Replace(replacements));
+        }
+    }
+
+    fn update_status(&mut self) {
+        self.status = match self.current_match {
+            Some(index) => format!("{} of {}", index + 1, self.matches.len()),
+            None if self.query.is_empty() => String::new(),
+            None => String::from("No results"),
+        };
+    }
+}
+
+/// Builds the regex which searches for a query, which is escaped unless it is a regular expression.
+fn build_regex(query: &str, regex: bool, case_sensitive: bool) -> Result<Regex, regex::Error> {
+    let pattern = if regex { Cow::Borrowed(query) } else { Cow::Owned(regex::escape(query)) };
+
+    RegexBuilder::new(&pattern).case_insensitive(!case_sensitive).multi_line(true).build()
+}
+
+/// Returns the byte ranges of the non-empty matches of a regex in the text.
+///
+/// The text is searched line by line so that it is not copied into a single string, except for lines
+/// which span chunks of the rope. Matches therefore never span a line break.
+fn find_matches(regex: &Regex, text: &Rope) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    let mut offset = 0;
+    for line in text.lines() {
+        let line = Cow::<str>::from(line);
+        matches.extend(
+            regex
+                .find_iter(&line)
+                .filter(|m| !m.is_empty())
+                .map(|m| offset + m.start()..offset + m.end()),
+        );
+        offset += line.len();
+    }
+
+    matches
+}
+
+/// Returns the replacement of the match of a regex at the given offset, with references to capture
+/// groups, such as `$1`, expanded.
+fn expand_replacement(regex: &Regex, text: &Rope, offset: usize, replacement: &str) -> String {
+    let line = text.byte_to_line(offset);
+    let line_start = text.line_to_byte(line);
+    let line_text = Cow::<str>::from(text.line(line));
+
+    let mut expanded = String::new();
+    match regex.captures_at(&line_text, offset - line_start) {
+        Some(captures) => captures.expand(replacement, &mut expanded),
+        None => expanded.push_str(replacement),
+    }
+    expanded
+}
+
+/// Returns the index of the first match which starts at or after the given offset, wrapping around to
+/// the first match.
+fn nearest_match(matches: &[Range<usize>], from: usize) -> Option<usize> {
+    if matches.is_empty() {
+        return None;
+    }
+
+    Some(matches.partition_point(|m| m.start < from) % matches.len())
+}
+
+/// Returns the index of the next or previous match, wrapping around at either end of the document.
+fn step_match(current: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (current + 1) % len
+    } else {
+        (current + len - 1) % len
+    }
+}
+
+impl<L: Lens<Target = Rope>> Handle<'_, CodeEditor<L>> {
+    /// Sets the callback triggered when the code editor is edited, i.e. text is inserted/deleted.
+    ///
+    /// Callback provides the edited source code.
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Rope) + Send + Sync,
+    {
+        self.modify(|code_editor: &mut CodeEditor<L>| {
+            code_editor.on_edit = Some(Box::new(callback))
+        })
+    }
+
+    /// Sets the highlighter used to style the source code.
+    pub fn highlighter(self, highlighter: impl Highlighter) -> Self {
+        self.text_editor(|text_editor| {
+            text_editor.highlighter(highlighter);
+        })
+    }
+
+    /// Sets the indentation inserted by the tab key. Defaults to four spaces.
+    pub fn indent(self, indent: Indent) -> Self {
+        self.text_editor(|text_editor| {
+            text_editor.indent(indent);
+        })
+    }
+
+    /// Applies modifiers to the text editor of the code editor.
+    fn text_editor(mut self, f: impl FnOnce(Handle<TextEditor<L>>)) -> Self {
+        let editor = self
+            .cx
+            .views
+            .get(&self.entity)
+            .and_then(|view| view.downcast_ref::<CodeEditor<L>>())
+            .map(|code_editor| code_editor.editor);
+
+        if let Some(editor) = editor {
+            (f)(Handle {
+                current: editor,
+                entity: editor,
+                p: Default::default(),
+                cx: self.context(),
+            });
+        }
+
+        self
+    }
+}
+
+impl<L> View for CodeEditor<L>
+where
+    L: Lens<Target = Rope>,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("code-editor")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(code, _) => {
+                #[cfg(target_os = "macos")]
+                let modifier = Modifiers::SUPER;
+                #[cfg(not(target_os = "macos"))]
+                let modifier = Modifiers::CTRL;
+
+                match code {
+                    Code::KeyF | Code::KeyH if *cx.modifiers == modifier => {
+                        cx.emit(CodeEditorEvent::OpenFind);
+                        meta.consume();
+                    }
+
+                    Code::F3 if self.find_open => {
+                        if cx.modifiers.shift() {
+                            cx.emit(CodeEditorEvent::FindPrevious);
+                        } else {
+                            cx.emit(CodeEditorEvent::FindNext);
+                        }
+                        meta.consume();
+                    }
+
+                    Code::Escape if self.find_open => {
+                        cx.emit(CodeEditorEvent::CloseFind);
+                    }
+
+                    _ => {}
+                }
+            }
+
+            _ => {}
+        });
+
+        event.map(|code_editor_event, _| match code_editor_event {
+            CodeEditorEvent::OpenFind => {
+                self.find_open = true;
+                cx.emit_to(self.query_box, TextEvent::StartEdit);
+                cx.emit_to(self.query_box, TextEvent::SelectAll);
+                self.search(cx, None, true);
+            }
+
+            CodeEditorEvent::CloseFind => {
+                if self.find_open {
+                    self.find_open = false;
+                    self.search(cx, None, false);
+                    cx.emit_to(self.editor, TextEvent::StartEdit);
+                }
+            }
+
+            CodeEditorEvent::SetQuery(query) => {
+                self.query = query.clone();
+                self.search(cx, None, true);
+            }
+
+            CodeEditorEvent::SetReplacement(replacement) => {
+                self.replacement = replacement.clone();
+            }
+
+            CodeEditorEvent::ToggleRegex => {
+                self.regex ^= true;
+                self.search(cx, None, true);
+            }
+
+            CodeEditorEvent::ToggleCaseSensitive => {
+                self.case_sensitive ^= true;
+                self.search(cx, None, true);
+            }
+
+            CodeEditorEvent::FindNext => self.step(cx, true),
+
+            CodeEditorEvent::FindPrevious => self.step(cx, false),
+
+            CodeEditorEvent::Replace => self.replace(cx, false),
+
+            CodeEditorEvent::ReplaceAll => self.replace(cx, true),
+        });
+
+        event.map(|Edited(text): &Edited, meta| {
+            if let Some(callback) = &self.on_edit {
+                (callback)(cx, text.clone());
+            }
+
+            if self.find_open {
+                let replaced_to = self.replaced_to.take();
+                self.search(cx, replaced_to, replaced_to.is_some());
+            }
+
+            meta.consume();
+        });
+
+        event.map(|SourceChanged: &SourceChanged, meta| {
+            // Edits made in the editor have already been searched, so only text which was changed
+            // elsewhere is searched again.
+            if let (Some(searched), Some(document)) =
+                (&self.searched, cx.text_context.text_documents.get(self.editor))
+            {
+                if !searched.is_instance(document.text()) {
+                    self.search(cx, None, false);
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_matches_returns_byte_ranges_of_each_line() {
+        let text = Rope::from_str("let a = 1;\nlet é = a;\n");
+        let regex = build_regex("a", false, true).unwrap();
+        assert_eq!(find_matches(&regex, &text), vec![4..5, 20..21]);
+
+        // The query is escaped unless it is a regular expression.
+        let regex = build_regex("a.", false, true).unwrap();
+        assert!(find_matches(&regex, &text).is_empty());
+        let regex = build_regex("^let", true, true).unwrap();
+        assert_eq!(find_matches(&regex, &text), vec![0..3, 11..14]);
+    }
+
+    #[test]
+    fn find_matches_is_case_insensitive_by_default() {
+        let text = Rope::from_str("Foo foo FOO");
+        let regex = build_regex("foo", false, false).unwrap();
+        assert_eq!(find_matches(&regex, &text), vec![0..3, 4..7, 8..11]);
+        let regex = build_regex("foo", false, true).unwrap();
+        assert_eq!(find_matches(&regex, &text), vec![4..7]);
+    }
+
+    #[test]
+    fn expand_replacement_refers_to_capture_groups() {
+        let text = Rope::from_str("first line\nlet value = 42;\n");
+        let regex = build_regex(r"let (\w+) = (\d+)", true, true).unwrap();
+        let matches = find_matches(&regex, &text);
+        assert_eq!(matches, vec![11..25]);
+
+        let replacement = expand_replacement(&regex, &text, matches[0].start, "const $1: i32 = $2");
+        assert_eq!(replacement, "const value: i32 = 42");
+    }
+
+    #[test]
+    fn matches_wrap_around() {
+        let matches = vec![0..1, 5..6, 10..11];
+        assert_eq!(nearest_match(&matches, 0), Some(0));
+        assert_eq!(nearest_match(&matches, 6), Some(2));
+        // Past the last match the search wraps around to the first match.
+        assert_eq!(nearest_match(&matches, 11), Some(0));
+        assert_eq!(nearest_match(&[], 0), None);
+
+        assert_eq!(step_match(2, 3, true), 0);
+        assert_eq!(step_match(0, 3, false), 2);
+        assert_eq!(step_match(1, 3, true), 2);
+    }
+}
@@ -0,0 +1,416 @@
+use vizia_storage::TreeIterator;
+use vizia_style::{selectors::bloom::BloomFilter, ToCss};
+
+use crate::prelude::*;
+use crate::systems::{compute_element_hash, compute_matched_rules};
+
+/// Events used by the [Inspector] view.
+pub enum InspectorEvent {
+    /// Rebuilds the list of views in the tree.
+    Refresh,
+    /// Selects the view at the given row of the tree list.
+    SelectRow(usize),
+    /// Selects the given entity.
+    Select(Entity),
+    /// Toggles picking a view by hovering over it.
+    TogglePicking,
+    /// Selects the currently hovered view if picking is enabled.
+    PickHovered,
+}
+
+/// A row in the tree list of the [Inspector].
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct InspectorNode {
+    /// The entity of the view.
+    pub entity: Entity,
+    /// The depth of the view in the tree, ignoring bindings.
+    pub depth: usize,
+    /// A description of the view including its element name, id, and classes.
+    pub label: String,
+}
+
+/// A style rule which matches the entity selected in the [Inspector].
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct InspectorRule {
+    /// The selector of the rule.
+    pub selector: String,
+    /// The name of the stylesheet the rule was parsed from.
+    pub source: String,
+}
+
+/// A computed style property of the entity selected in the [Inspector].
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct InspectorProperty {
+    /// The name of the property.
+    pub name: String,
+    /// The computed value of the property.
+    pub value: String,
+}
+
+/// A developer tool which shows the view tree of the application, along with the matched style rules,
+/// computed style, and bounds of the selected view.
+///
+/// Views can be selected from the tree list, or picked by enabling picking and hovering over them in any
+/// window of the application. Clicking while picking finishes the pick.
+///
+/// The inspector is usually shown in its own window.
+///
+/// # Example
+/// ```ignore
+/// Window::new(cx, |cx| {
+///     Inspector::new(cx);
+/// })
+/// .title("Inspector");
+/// ```
+#[derive(Lens)]
+pub struct Inspector {
+    nodes: Vec<InspectorNode>,
+    selected_row: Vec<usize>,
+    selected: Option<Entity>,
+    picking: bool,
+    title: String,
+    bounds: String,
+    rules: Vec<InspectorRule>,
+    computed: Vec<InspectorProperty>,
+}
+
+impl Inspector {
+    /// Creates a new [Inspector] view.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self {
+            nodes: Vec::new(),
+            selected_row: Vec::new(),
+            selected: None,
+            picking: false,
+            title: String::from("No view selected"),
+            bounds: String::new(),
+            rules: Vec::new(),
+            computed: Vec::new(),
+        }
+        .build(cx, |cx| {
+            cx.add_listener(|inspector: &mut Self, cx, event| {
+                event.map(|window_event, meta| match window_event {
+                    // The rules and computed style of the selected view change when it is restyled.
+                    WindowEvent::StyleChanged(entities) => {
+                        if let Some(selected) =
+                            inspector.selected.filter(|selected| entities.contains(selected))
+                        {
+                            inspector.select(cx, selected);
+                        }
+                    }
+
+                    // Hover is updated after listeners are called so the pick is deferred.
+                    WindowEvent::MouseMove(_, _) if inspector.picking => {
+                        cx.emit(InspectorEvent::PickHovered)
+                    }
+
+                    WindowEvent::MouseDown(MouseButton::Left) if inspector.picking => {
+                        if !inspector.is_inspector_view(cx, cx.hovered()) {
+                            inspector.picking = false;
+                            meta.consume();
+                        }
+                    }
+
+                    _ => {}
+                });
+            });
+
+            HStack::new(cx, |cx| {
+                ToggleButton::new(cx, Inspector::picking, |cx| Label::new(cx, "Pick"))
+                    .on_toggle(|cx| cx.emit(InspectorEvent::TogglePicking));
+                Button::new(cx, |cx| Label::new(cx, "Refresh"))
+                    .on_press(|cx| cx.emit(InspectorEvent::Refresh));
+            })
+            .class("inspector-toolbar");
+
+            HStack::new(cx, |cx| {
+                ScrollView::new(cx, |cx| {
+                    List::new(cx, Inspector::nodes, |cx, _, node| {
+                        let node = node.get(cx);
+                        Label::new(cx, node.label)
+                            .padding_left(Pixels(node.depth as f32 * 12.0))
+                            .hoverable(false);
+                    })
+                    .selectable(Selectable::Single)
+                    .selected(Inspector::selected_row)
+                    .on_select(|cx, index| cx.emit(InspectorEvent::SelectRow(index)));
+                })
+                .class("inspector-tree");
+
+                ScrollView::new(cx, |cx| {
+                    Label::new(cx, Inspector::title).class("inspector-title");
+                    Label::new(cx, Inspector::bounds);
+
+                    Label::new(cx, "Matched rules").class("inspector-heading");
+                    List::new(cx, Inspector::rules, |cx, _, rule| {
+                        let rule = rule.get(cx);
+                        HStack::new(cx, |cx| {
+                            Label::new(cx, rule.selector).class("inspector-selector");
+                            Label::new(cx, rule.source).class("inspector-source");
+                        })
+                        .class("inspector-row");
+                    });
+
+                    Label::new(cx, "Computed style").class("inspector-heading");
+                    List::new(cx, Inspector::computed, |cx, _, property| {
+                        let property = property.get(cx);
+                        HStack::new(cx, |cx| {
+                            Label::new(cx, property.name).class("inspector-property");
+                            Label::new(cx, property.value);
+                        })
+                        .class("inspector-row");
+                    });
+                })
+                .class("inspector-details");
+            })
+            .class("inspector-panels");
+
+            cx.emit(InspectorEvent::Refresh);
+        })
+    }
+
+    /// Returns true if the entity is part of the inspector, or of the window containing it.
+    fn is_inspector_view(&self, cx: &EventContext, entity: Entity) -> bool {
+        let inspector = cx.current();
+        let window = cx.tree.get_parent_window(inspector).unwrap_or(Entity::root());
+
+        entity == inspector
+            || entity.is_descendant_of(cx.tree, inspector)
+            || (window != Entity::root() && cx.tree.get_parent_window(entity) == Some(window))
+    }
+
+    fn refresh(&mut self, cx: &EventContext) {
+        self.nodes = TreeIterator::full(cx.tree)
+            .filter(|entity| !cx.tree.is_ignored(*entity) && !self.is_inspector_view(cx, *entity))
+            .map(|entity| InspectorNode {
+                entity,
+                depth: entity
+                    .parent_iter(cx.tree)
+                    .skip(1)
+                    .filter(|parent| !cx.tree.is_ignored(*parent))
+                    .count(),
+                label: describe(cx, entity),
+            })
+            .collect();
+
+        match self.selected {
+            Some(entity) if self.nodes.iter().any(|node| node.entity == entity) => {
+                self.select(cx, entity)
+            }
+            _ => self.clear_selection(),
+        }
+    }
+
+    fn clear_selection(&mut self) {
+        self.selected = None;
+        self.selected_row.clear();
+        self.title = String::from("No view selected");
+        self.bounds.clear();
+        self.rules.clear();
+        self.computed.clear();
+    }
+
+    fn select(&mut self, cx: &EventContext, entity: Entity) {
+        self.selected = Some(entity);
+        self.selected_row =
+            self.nodes.iter().position(|node| node.entity == entity).into_iter().collect();
+
+        self.title = describe(cx, entity);
+
+        let bounds = cx.cache.get_bounds(entity);
+        self.bounds = format!("x: {} y: {} w: {} h: {}", bounds.x, bounds.y, bounds.w, bounds.h);
+
+        let mut filter = BloomFilter::default();
+        compute_element_hash(entity, cx.tree, cx.style, &mut filter);
+        self.rules = compute_matched_rules(entity, cx.style, cx.tree, &filter)
+            .into_iter()
+            .filter_map(|(rule, _)| cx.style.rules.get(&rule))
+            .map(|style_rule| InspectorRule {
+                selector: style_rule.selector.to_css_string(),
+                source: style_rule.source.to_string(),
+            })
+            .collect();
+
+        self.computed = computed_style(cx.style, entity);
+    }
+}
+
+impl View for Inspector {
+    fn element(&self) -> Option<&'static str> {
+        Some("inspector")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|inspector_event, meta| match inspector_event {
+            InspectorEvent::Refresh => {
+                self.refresh(cx);
+                meta.consume();
+            }
+
+            InspectorEvent::SelectRow(index) => {
+                if let Some(node) = self.nodes.get(*index) {
+                    let entity = node.entity;
+                    self.select(cx, entity);
+                }
+                meta.consume();
+            }
+
+            InspectorEvent::Select(entity) => {
+                if cx.views.contains_key(entity) {
+                    self.select(cx, *entity);
+                }
+                meta.consume();
+            }
+
+            InspectorEvent::TogglePicking => {
+                self.picking = !self.picking;
+                meta.consume();
+            }
+
+            InspectorEvent::PickHovered => {
+                let hovered = cx.hovered();
+                if self.picking
+                    && self.selected != Some(hovered)
+                    && !self.is_inspector_view(cx, hovered)
+                {
+                    // The tree may have changed since the list was last built.
+                    if !self.nodes.iter().any(|node| node.entity == hovered) {
+                        self.refresh(cx);
+                    }
+                    self.select(cx, hovered);
+                }
+                meta.consume();
+            }
+        });
+    }
+}
+
+/// Returns a description of an entity in the form `element#id.class [entity]`.
+fn describe(cx: &EventContext, entity: Entity) -> String {
+    let mut label =
+        cx.views.get(&entity).and_then(|view| view.element()).unwrap_or("view").to_string();
+
+    if let Some(id) = cx.style.ids.get(entity) {
+        label += &format!("#{}", id);
+    }
+
+    if let Some(classes) = cx.style.classes.get(entity) {
+        let mut classes = classes.iter().collect::<Vec<_>>();
+        classes.sort();
+        for class in classes {
+            label += &format!(".{}", class);
+        }
+    }
+
+    label + &format!(" [{}]", entity)
+}
+
+macro_rules! computed_properties {
+    ($style:expr, $entity:expr, [$($name:ident),* $(,)?]) => {{
+        let mut properties = Vec::new();
+        $(
+            if let Some(value) = $style.$name.get($entity) {
+                properties.push(InspectorProperty {
+                    name: stringify!($name).replace('_', "-"),
+                    value: format!("{:?}", value),
+                });
+            }
+        )*
+        properties
+    }};
+}
+
+/// Returns the computed values of the style properties which are set on an entity.
+fn computed_style(style: &Style, entity: Entity) -> Vec<InspectorProperty> {
+    computed_properties!(
+        style,
+        entity,
+        [
+            display,
+            visibility,
+            opacity,
+            z_index,
+            overflowx,
+            overflowy,
+            layout_type,
+            position_type,
+            alignment,
+            left,
+            right,
+            top,
+            bottom,
+            width,
+            height,
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+            padding_left,
+            padding_right,
+            padding_top,
+            padding_bottom,
+            horizontal_gap,
+            vertical_gap,
+            background_color,
+            border_top_width,
+            border_right_width,
+            border_bottom_width,
+            border_left_width,
+            border_top_color,
+            border_right_color,
+            border_bottom_color,
+            border_left_color,
+            border_top_style,
+            border_right_style,
+            border_bottom_style,
+            border_left_style,
+            corner_top_left_radius,
+            corner_top_right_radius,
+            corner_bottom_left_radius,
+            corner_bottom_right_radius,
+            outline_width,
+            outline_color,
+            outline_offset,
+            font_color,
+            font_size,
+            font_weight,
+            text_align,
+            transform,
+            translate,
+            rotate,
+            scale,
+            cursor,
+            pointer_events,
+        ]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use crate::systems::style_system;
+
+    fn inspector<'a>(cx: &'a Context, entity: Entity) -> &'a Inspector {
+        cx.views.get(&entity).and_then(|view| view.downcast_ref::<Inspector>()).unwrap()
+    }
+
+    #[test]
+    fn selected_view_is_updated_when_restyled() {
+        let cx = &mut Context::default();
+        let element = Element::new(cx).class("target").entity();
+        let entity = Inspector::new(cx).entity();
+        cx.emit_to(entity, InspectorEvent::Select(element));
+        EventManager::new().flush_events(cx, |_| {});
+        assert!(inspector(cx, entity).rules.iter().all(|rule| rule.selector != "element.target"));
+
+        cx.add_stylesheet("element.target { background-color: red; }").unwrap();
+        style_system(cx);
+        EventManager::new().flush_events(cx, |_| {});
+
+        // Selectors are shown as CSS.
+        let inspector = inspector(cx, entity);
+        assert!(inspector.rules.iter().any(|rule| rule.selector == "element.target"));
+        assert!(inspector.computed.iter().any(|property| property.name == "background-color"));
+    }
+}
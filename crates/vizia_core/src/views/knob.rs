@@ -317,7 +317,13 @@ impl View for ArcTrack {
         }
 
         let mut paint = vg::Paint::default();
-        paint.set_color(foreground_color);
+        if let Some(shader) =
+            cx.font_gradient().and_then(|gradient| cx.gradient_shader(gradient, bounds))
+        {
+            paint.set_shader(shader);
+        } else {
+            paint.set_color(foreground_color);
+        }
         paint.set_stroke_width(span);
         paint.set_stroke_cap(vg::PaintCap::Round);
         paint.set_style(vg::PaintStyle::Stroke);
@@ -1,4 +1,9 @@
 use crate::prelude::*;
+use crate::systems::{paragraph_origin, paragraph_text};
+use crate::text::{apply_movement, Direction, Movement, Selection};
+use log::warn;
+use skia_safe::textlayout::{RectHeightStyle, RectWidthStyle};
+use skia_safe::{Paint, PaintStyle, Rect};
 
 /// A label used to display text.
 ///
@@ -85,6 +90,8 @@ use crate::prelude::*;
 /// ```
 pub struct Label {
     describing: Option<String>,
+    selectable: bool,
+    selection: Selection,
 }
 
 impl Label {
@@ -103,7 +110,11 @@ impl Label {
     where
         T: ToStringLocalized,
     {
-        Self { describing: None }.build(cx, |_| {}).text(text.clone()).role(Role::Label).name(text)
+        Self { describing: None, selectable: false, selection: Selection::caret(0) }
+            .build(cx, |_| {})
+            .text(text.clone())
+            .role(Role::Label)
+            .name(text)
     }
 
     /// Creates a new rich [Label] view.
@@ -115,7 +126,7 @@ impl Label {
     where
         T: ToStringLocalized,
     {
-        Self { describing: None }
+        Self { describing: None, selectable: false, selection: Selection::caret(0) }
             .build(cx, |cx| {
                 children(cx);
             })
@@ -123,6 +134,85 @@ impl Label {
             .role(Role::Label)
             .name(text)
     }
+
+    /// Returns the selection snapped to the character boundaries of the given text. The text of the
+    /// label, or of its spans, may have changed since the selection was made.
+    fn snapped_selection(&self, text: &str) -> Selection {
+        let snap = |offset: usize| {
+            let mut offset = offset.min(text.len());
+            while !text.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            offset
+        };
+
+        Selection::new(snap(self.selection.anchor), snap(self.selection.active))
+    }
+
+    fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let text = paragraph_text(cx.style, cx.tree, cx.current);
+            let current = self.snapped_selection(&text);
+            self.selection = apply_movement(movement, current, &text, paragraph, selection);
+            cx.needs_redraw();
+        }
+    }
+
+    /// This function takes window-global physical coordinates.
+    fn hit(&mut self, cx: &mut EventContext, x: f32, y: f32, selection: bool) {
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let (origin_x, origin_y) =
+                paragraph_origin(cx.style, cx.current, cx.bounds(), paragraph);
+            let position = paragraph.get_glyph_position_at_coordinate((x - origin_x, y - origin_y));
+            let text = paragraph_text(cx.style, cx.tree, cx.current);
+            let offset = cx.text_context.text_offset_at(
+                cx.current,
+                &text,
+                position.position.max(0) as usize,
+            );
+            if selection {
+                self.selection = self.snapped_selection(&text);
+                self.selection.active = offset;
+            } else {
+                self.selection = Selection::caret(offset);
+            }
+
+            cx.needs_redraw();
+        }
+    }
+
+    /// Draws the background of the selected text, returning the rects which were drawn.
+    fn draw_selection(&self, cx: &mut DrawContext, canvas: &Canvas) -> Vec<Rect> {
+        let mut rects = Vec::new();
+        if self.selection.is_caret() {
+            return rects;
+        }
+
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let text = paragraph_text(cx.style, cx.tree, cx.current);
+            let origin = paragraph_origin(cx.style, cx.current, cx.bounds(), paragraph);
+            let selection = self.snapped_selection(&text);
+            let start = cx.text_context.paragraph_index(cx.current, &text, selection.min());
+            let end = cx.text_context.paragraph_index(cx.current, &text, selection.max());
+
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_style(PaintStyle::Fill);
+            paint.set_color(cx.selection_color());
+
+            for rect in paragraph.get_rects_for_range(
+                start..end,
+                RectHeightStyle::Max,
+                RectWidthStyle::Tight,
+            ) {
+                let rect = rect.rect.with_offset(origin);
+                canvas.draw_rect(rect, &paint);
+                rects.push(rect);
+            }
+        }
+
+        rects
+    }
 }
 
 impl Handle<'_, Label> {
@@ -158,6 +248,26 @@ impl Handle<'_, Label> {
         }
         self.modify(|label| label.describing = Some(identifier)).class("describing").hidden(true)
     }
+
+    /// Sets whether the text of the label, including the text of its spans, can be selected with the
+    /// mouse and copied to the clipboard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// Label::new(cx, "Error: file not found").selectable(true);
+    /// ```
+    pub fn selectable(self, flag: bool) -> Self {
+        self.modify(|label| {
+            label.selectable = flag;
+            label.selection = Selection::caret(0);
+        })
+        .toggle_class("selectable", flag)
+    }
 }
 
 impl View for Label {
@@ -187,8 +297,131 @@ impl View for Label {
                     }
                 }
             }
+            WindowEvent::MouseDown(MouseButton::Left) if self.selectable => {
+                // Pressing an interactive span, such as a link, does not start a selection.
+                if meta.target == cx.current() {
+                    cx.focus_with_visibility(false);
+                    cx.capture();
+                    cx.lock_cursor_icon();
+                    cx.emit(TextEvent::Hit(
+                        cx.mouse.cursor_x,
+                        cx.mouse.cursor_y,
+                        cx.modifiers.shift(),
+                    ));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) if self.selectable => {
+                cx.unlock_cursor_icon();
+                cx.release();
+            }
+
+            WindowEvent::MouseMove(x, y) if self.selectable => {
+                if cx.mouse.left.state == MouseButtonState::Pressed
+                    && cx.mouse.left.pressed == cx.current
+                    && (cx.mouse.left.pos_down.0 != *x || cx.mouse.left.pos_down.1 != *y)
+                {
+                    cx.emit(TextEvent::Drag(cx.mouse.cursor_x, cx.mouse.cursor_y));
+                }
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) if self.selectable => {
+                cx.emit(TextEvent::SelectWord);
+            }
+
+            WindowEvent::MouseTripleClick(MouseButton::Left) if self.selectable => {
+                cx.emit(TextEvent::SelectParagraph);
+            }
+
+            WindowEvent::FocusOut if self.selectable => {
+                self.selection = Selection::caret(0);
+                cx.needs_redraw();
+            }
+
+            WindowEvent::KeyDown(code, _) if self.selectable => {
+                #[cfg(target_os = "macos")]
+                let modifier = Modifiers::SUPER;
+                #[cfg(not(target_os = "macos"))]
+                let modifier = Modifiers::CTRL;
+
+                if cx.modifiers == &modifier {
+                    match code {
+                        Code::KeyA => cx.emit(TextEvent::SelectAll),
+                        Code::KeyC => cx.emit(TextEvent::Copy),
+                        _ => {}
+                    }
+                }
+            }
+
             _ => {}
         });
+
+        if !self.selectable {
+            return;
+        }
+
+        event.map(|text_event, meta| {
+            match text_event {
+                TextEvent::Hit(x, y, selection) => self.hit(cx, *x, *y, *selection),
+
+                TextEvent::Drag(x, y) => self.hit(cx, *x, *y, true),
+
+                TextEvent::SelectWord => {
+                    self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
+                    self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
+                }
+
+                TextEvent::SelectParagraph => {
+                    self.move_cursor(cx, Movement::ParagraphStart, false);
+                    self.move_cursor(cx, Movement::ParagraphEnd, true);
+                }
+
+                TextEvent::SelectAll => {
+                    let text = paragraph_text(cx.style, cx.tree, cx.current);
+                    self.selection = Selection::new(0, text.len());
+                    cx.needs_redraw();
+                }
+
+                TextEvent::Copy =>
+                {
+                    #[cfg(feature = "clipboard")]
+                    if !self.selection.is_caret() {
+                        let text = paragraph_text(cx.style, cx.tree, cx.current);
+                        let selection = self.snapped_selection(&text);
+                        if let Some(selected_text) = text.get(selection.range()) {
+                            cx.set_clipboard(selected_text.to_owned())
+                                .expect("Failed to add text to clipboard");
+                        }
+                    }
+                }
+
+                _ => return,
+            }
+
+            // Prevent the event reaching an ancestor view, such as a textbox.
+            meta.consume();
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_shadows(canvas);
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+
+        let selection = if self.selectable { self.draw_selection(cx, canvas) } else { Vec::new() };
+
+        cx.draw_text(canvas);
+
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let origin = paragraph_origin(cx.style, cx.current, cx.bounds(), paragraph);
+            cx.draw_selected_text(canvas, paragraph, origin, &selection);
+        }
     }
 }
 
@@ -211,6 +444,41 @@ impl TextSpan {
             .display(Display::None)
             .pointer_events(PointerEvents::None)
     }
+
+    /// Create a new [TextSpan] which links to a URL.
+    ///
+    /// The link can be hovered, focused with the keyboard, and opens the URL with the default
+    /// program, such as a web browser, when pressed. The text of the link is made up of the spans
+    /// built by the `children` closure.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Label::rich(cx, "Visit ", |cx| {
+    ///     TextSpan::link(cx, "https://github.com/vizia/vizia", |cx| {
+    ///         TextSpan::new(cx, "the repository", |_| {});
+    ///     });
+    /// });
+    /// ```
+    pub fn link<'a>(
+        cx: &'a mut Context,
+        url: impl Into<String>,
+        children: impl Fn(&mut Context),
+    ) -> Handle<'a, Self> {
+        let url = url.into();
+        Self::new(cx, "", children)
+            .class("link")
+            .role(Role::Link)
+            .cursor(CursorIcon::Hand)
+            .pointer_events(PointerEvents::Auto)
+            .navigable(true)
+            .on_press(move |_| {
+                if let Err(err) = open::that(url.as_str()) {
+                    warn!("Failed to open link {}: {}", url, err);
+                }
+            })
+    }
 }
 
 impl View for TextSpan {
@@ -218,3 +486,24 @@ impl View for TextSpan {
         Some("text-span")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::utf8_to_utf16;
+
+    #[test]
+    fn selection_snaps_to_changed_text() {
+        let label = Label { describing: None, selectable: true, selection: Selection::new(0, 1) };
+
+        // The selection of "a" ends within the first character of "é".
+        let selection = label.snapped_selection("é");
+        assert_eq!(selection.range(), 0..0);
+        assert_eq!(utf8_to_utf16("é", selection.max()), 0);
+
+        // A selection past the end of shortened text ends at the end of the text.
+        let label = Label { describing: None, selectable: true, selection: Selection::new(4, 1) };
+        assert_eq!(label.snapped_selection("ab").range(), 1..2);
+        assert_eq!(label.snapped_selection("aé").range(), 1..3);
+    }
+}
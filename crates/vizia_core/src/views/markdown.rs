@@ -1,35 +1,128 @@
 #![cfg(feature = "markdown")]
 
-use std::cell::RefCell;
+use std::rc::Rc;
 
-use comrak::nodes::{Ast, NodeValue};
+use comrak::nodes::{AstNode, NodeValue};
 use comrak::{parse_document, Arena, Options};
 
+#[cfg(feature = "clipboard")]
+use crate::icons::ICON_COPY;
+use crate::icons::{ICON_SQUARE, ICON_SQUARE_CHECK};
 use crate::prelude::*;
 
+/// A function which overrides how a node of a markdown document is rendered, returning `true` if it
+/// rendered the node or `false` to render the node as usual.
+type NodeRenderer = dyn Fn(&mut Context, &AstNode<'_>) -> bool;
+
 /// A view which parses and displays markdown as rich text.
-pub struct Markdown {}
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Markdown::new(cx, "# Heading\n\nSome *emphasized* text with a [link](https://github.com/vizia/vizia).");
+/// ```
+#[derive(Lens)]
+pub struct Markdown {
+    source: String,
+    selectable: bool,
+    #[lens(ignore)]
+    render_node: Option<Rc<NodeRenderer>>,
+    /// Incremented whenever the document, or how it is rendered, changes.
+    revision: usize,
+}
 
 impl Markdown {
     /// Create a new [Markdown] view.
-    pub fn new<'a>(cx: &'a mut Context, document: &str) -> Handle<'a, Self> {
-        Self {}
+    ///
+    /// The document can be a string, or a lens to a string, in which case the document is rendered
+    /// again whenever the bound data changes.
+    pub fn new<T: ToString>(cx: &mut Context, document: impl Res<T>) -> Handle<Self> {
+        Self { source: String::new(), selectable: false, render_node: None, revision: 0 }
             .build(cx, |cx| {
-                // The returned nodes are created in the supplied Arena, and are bound by its lifetime.
-                let arena = Arena::new();
+                // The document is rendered when bindings are next updated, so that it is rendered
+                // once after the modifiers of the view have been applied.
+                let entity = cx.current();
+                Binding::new(cx, Markdown::revision, move |cx, _| Markdown::render(cx, entity));
+            })
+            .height(Auto)
+            .bind(document, |handle, document| {
+                let source = document.get(&handle).to_string();
+                handle.rerender(|markdown| markdown.source = source);
+            })
+    }
 
-                let mut options = Options::default();
-                options.extension.strikethrough = true;
-                options.extension.table = true;
+    /// Renders the document of the markdown view into the current view.
+    fn render(cx: &mut Context, entity: Entity) {
+        let Some(markdown) = cx.views.get(&entity).and_then(|view| view.downcast_ref::<Markdown>())
+        else {
+            return;
+        };
 
-                // Parse the document into a root `AstNode`
-                let root = parse_document(&arena, document, &options);
+        if markdown.source.is_empty() {
+            return;
+        }
 
-                for node in root.children() {
-                    parse_node(cx, node, 0);
-                }
-            })
-            .height(Auto)
+        let source = markdown.source.clone();
+        let renderer =
+            Renderer { selectable: markdown.selectable, render_node: markdown.render_node.clone() };
+
+        // The returned nodes are created in the supplied Arena, and are bound by its lifetime.
+        let arena = Arena::new();
+
+        let mut options = Options::default();
+        options.extension.strikethrough = true;
+        options.extension.table = true;
+        options.extension.tasklist = true;
+        options.extension.footnotes = true;
+
+        // Parse the document into a root `AstNode`
+        let root = parse_document(&arena, &source, &options);
+
+        renderer.render_children(cx, root, 0);
+    }
+}
+
+impl Handle<'_, Markdown> {
+    /// Sets whether the text of the document can be selected with the mouse and copied to the
+    /// clipboard. Text can be selected within each block of the document, such as a paragraph.
+    pub fn selectable(self, flag: bool) -> Self {
+        self.rerender(|markdown| markdown.selectable = flag)
+    }
+
+    /// Overrides how the nodes of the document are rendered.
+    ///
+    /// The callback is called for each node of the document before it is rendered. If the callback
+    /// builds views for the node and returns `true` then the node, and its children, are not
+    /// rendered as usual.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::markdown::NodeValue;
+    /// # let cx = &mut Context::default();
+    /// Markdown::new(cx, "Some text\n\n---\n\nMore text").render_node(|cx, node| {
+    ///     if matches!(node.data.borrow().value, NodeValue::ThematicBreak) {
+    ///         Label::new(cx, "* * *").class("separator");
+    ///         true
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    /// ```
+    pub fn render_node<F>(self, render_node: F) -> Self
+    where
+        F: 'static + Fn(&mut Context, &AstNode<'_>) -> bool,
+    {
+        self.rerender(|markdown| markdown.render_node = Some(Rc::new(render_node)))
+    }
+
+    /// Modifies the view and marks the document to be rendered again.
+    fn rerender(self, f: impl FnOnce(&mut Markdown)) -> Self {
+        self.modify(|markdown| {
+            f(markdown);
+            markdown.revision += 1;
+        })
     }
 }
 
@@ -39,165 +132,482 @@ impl View for Markdown {
     }
 }
 
-fn parse_node<'a>(
-    cx: &mut Context,
-    node: &'a comrak::arena_tree::Node<'a, RefCell<Ast>>,
-    list_level: usize,
-) {
-    match &node.data.borrow().value {
-        NodeValue::Paragraph => {
-            Label::rich(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            })
-            .class("p");
-        }
+/// Builds the views for the nodes of a markdown document.
+struct Renderer {
+    selectable: bool,
+    render_node: Option<Rc<NodeRenderer>>,
+}
 
-        NodeValue::Heading(heading) => {
-            Label::rich(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            })
-            .class(match heading.level {
-                1 => "h1",
-                2 => "h2",
-                3 => "h3",
-                4 => "h4",
-                5 => "h5",
-                6 => "h6",
-                _ => "h6",
-            });
+impl Renderer {
+    fn render_children<'a>(&self, cx: &mut Context, node: &'a AstNode<'a>, list_level: usize) {
+        for child in node.children() {
+            self.render(cx, child, list_level);
         }
+    }
 
-        NodeValue::Text(text) => {
-            TextSpan::new(cx, text, |_| {}).class("span");
-        }
+    /// Builds a label for a run of inline nodes, such as the text of a paragraph between images.
+    fn render_inlines<'a>(&self, cx: &mut Context, nodes: &[&'a AstNode<'a>], list_level: usize) {
+        let is_blank = nodes.iter().all(|node| {
+            matches!(node.data.borrow().value, NodeValue::SoftBreak | NodeValue::LineBreak)
+        });
 
-        NodeValue::Emph => {
-            TextSpan::new(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            })
-            .class("emph");
+        if is_blank {
+            return;
         }
 
-        NodeValue::Strong => {
-            TextSpan::new(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            })
-            .class("strong");
+        Label::rich(cx, "", |cx| {
+            for &node in nodes {
+                self.render(cx, node, list_level);
+            }
+        })
+        .class("p")
+        .selectable(self.selectable);
+    }
+
+    fn render<'a>(&self, cx: &mut Context, node: &'a AstNode<'a>, list_level: usize) {
+        if let Some(render_node) = &self.render_node {
+            if (render_node)(cx, node) {
+                return;
+            }
         }
 
-        NodeValue::Strikethrough => {
-            TextSpan::new(cx, "", |cx| {
+        match &node.data.borrow().value {
+            NodeValue::Paragraph => {
+                // Images are displayed as blocks, so the text between them is split into separate labels.
+                let mut inlines = Vec::new();
                 for child in node.children() {
-                    parse_node(cx, child, list_level);
+                    if matches!(child.data.borrow().value, NodeValue::Image(_)) {
+                        self.render_inlines(cx, &inlines, list_level);
+                        inlines.clear();
+                        self.render(cx, child, list_level);
+                    } else {
+                        inlines.push(child);
+                    }
                 }
-            })
-            .class("strikethrough");
-        }
+                self.render_inlines(cx, &inlines, list_level);
+            }
 
-        NodeValue::List(_list) => {
-            VStack::new(cx, |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
+            NodeValue::Heading(heading) => {
+                Label::rich(cx, "", |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class(match heading.level {
+                    1 => "h1",
+                    2 => "h2",
+                    3 => "h3",
+                    4 => "h4",
+                    5 => "h5",
+                    6 => "h6",
+                    _ => "h6",
+                })
+                .selectable(self.selectable);
+            }
+
+            NodeValue::Text(text) => {
+                TextSpan::new(cx, text, |_| {}).class("span");
+            }
+
+            NodeValue::Emph => {
+                TextSpan::new(cx, "", |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("emph");
+            }
+
+            NodeValue::Strong => {
+                TextSpan::new(cx, "", |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("strong");
+            }
+
+            NodeValue::Strikethrough => {
+                TextSpan::new(cx, "", |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("strikethrough");
+            }
+
+            NodeValue::List(_list) => {
+                VStack::new(cx, |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .height(Auto)
+                .left(Pixels(20.0));
+            }
+
+            NodeValue::Item(_list) => {
+                HStack::new(cx, |cx| {
+                    Label::new(cx, "\u{2022} ").width(Auto);
+                    VStack::new(cx, |cx| {
+                        self.render_children(cx, node, list_level + 1);
+                    })
+                    .height(Auto);
+                })
+                .class("li")
+                .height(Auto);
+            }
+
+            NodeValue::TaskItem(symbol) => {
+                let checked = symbol.is_some();
+                HStack::new(cx, |cx| {
+                    Svg::new(cx, if checked { ICON_SQUARE_CHECK } else { ICON_SQUARE })
+                        .class("task-marker");
+                    VStack::new(cx, |cx| {
+                        self.render_children(cx, node, list_level + 1);
+                    })
+                    .height(Auto);
+                })
+                .class("li")
+                .class("task")
+                .toggle_class("checked", checked)
+                .height(Auto);
+            }
+
+            NodeValue::BlockQuote => {
+                VStack::new(cx, |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("blockquote")
+                .height(Auto);
+            }
+
+            NodeValue::ThematicBreak => {
+                Divider::horizontal(cx).class("hr");
+            }
+
+            NodeValue::Image(link) => {
+                let alt = node
+                    .descendants()
+                    .filter_map(|child| match &child.data.borrow().value {
+                        NodeValue::Text(text) => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect::<String>();
+
+                Image::new(cx, link.url.as_str()).class("image").role(Role::Image).name(alt);
+            }
+
+            NodeValue::Code(code) => {
+                TextSpan::new(cx, &code.literal.to_owned(), |_| {}).class("code");
+            }
+
+            NodeValue::CodeBlock(code_block) => {
+                let mut code = code_block.literal.to_owned();
+                if code.ends_with('\n') {
+                    code.pop();
                 }
-            })
-            .height(Auto)
-            .left(Pixels(20.0));
-        }
 
-        NodeValue::Item(_list) => {
-            HStack::new(cx, |cx| {
-                Label::new(cx, "\u{2022} ").width(Auto);
+                // The language of a fenced code block is the first word of its info string.
+                let language =
+                    code_block.info.split_whitespace().next().unwrap_or_default().to_lowercase();
+                let tokens = cx
+                    .code_highlighters
+                    .get(&language)
+                    .map(|highlighter| highlighter.highlight(&code))
+                    .unwrap_or_default();
+
+                let selectable = self.selectable;
                 VStack::new(cx, |cx| {
-                    for child in node.children() {
-                        parse_node(cx, child, list_level + 1);
-                    }
+                    let source = code.clone();
+                    ScrollView::new(cx, move |cx| {
+                        Label::rich(cx, "", |cx| build_code_spans(cx, &source, &tokens))
+                            .class("code")
+                            .selectable(selectable);
+                    })
+                    .show_vertical_scrollbar(false)
+                    .height(Auto)
+                    .width(Stretch(1.0));
+
+                    #[cfg(feature = "clipboard")]
+                    Button::new(cx, |cx| Svg::new(cx, ICON_COPY))
+                        .variant(ButtonVariant::Text)
+                        .class("copy-button")
+                        .on_press(move |cx| {
+                            cx.set_clipboard(code.clone()).expect("Failed to add text to clipboard")
+                        })
+                        .name("Copy code");
                 })
+                .class("code-block")
                 .height(Auto);
-            })
-            .class("li")
-            .height(Auto);
+            }
+
+            // Raw HTML is not rendered, so it is displayed as source code.
+            NodeValue::HtmlBlock(html_block) => {
+                let html = html_block.literal.trim_end().to_owned();
+                Label::new(cx, html).class("code").class("html").selectable(self.selectable);
+            }
+
+            NodeValue::HtmlInline(html) => {
+                TextSpan::new(cx, html, |_| {}).class("html");
+            }
+
+            NodeValue::Link(link) => {
+                TextSpan::link(cx, link.url.clone(), |cx| {
+                    self.render_children(cx, node, list_level);
+                });
+            }
+
+            NodeValue::SoftBreak => {
+                TextSpan::new(cx, "\n", |cx| {
+                    self.render_children(cx, node, list_level);
+                });
+            }
+
+            NodeValue::FootnoteReference(reference) => {
+                TextSpan::new(cx, &format!("[{}]", reference.name), |_| {})
+                    .class("footnote-reference");
+            }
+
+            NodeValue::FootnoteDefinition(definition) => {
+                HStack::new(cx, |cx| {
+                    Label::new(cx, format!("[{}]", definition.name)).width(Auto);
+                    VStack::new(cx, |cx| {
+                        self.render_children(cx, node, list_level);
+                    })
+                    .height(Auto);
+                })
+                .class("footnote")
+                .height(Auto);
+            }
+
+            NodeValue::Table(_table) => {
+                VStack::new(cx, |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("table")
+                .width(Stretch(1.0))
+                .height(Auto);
+            }
+
+            NodeValue::TableRow(headers) => {
+                HStack::new(cx, |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("table-row")
+                .toggle_class("table-headers", *headers)
+                .width(Stretch(1.0))
+                .height(Auto);
+                Divider::horizontal(cx);
+            }
+
+            NodeValue::TableCell => {
+                Label::rich(cx, "", |cx| {
+                    self.render_children(cx, node, list_level);
+                })
+                .class("table-cell")
+                .width(Stretch(1.0))
+                .selectable(self.selectable);
+            }
+
+            _ => {}
         }
+    }
+}
 
-        NodeValue::Code(code) => {
-            TextSpan::new(cx, &code.literal.to_owned(), |_| {}).class("code");
+/// Builds a span for each highlighted token of a code block, and for the text between them.
+fn build_code_spans(cx: &mut Context, code: &str, tokens: &[CodeToken]) {
+    let mut offset = 0;
+    for token in tokens {
+        // Tokens which overlap a previous token, or which are not within the code, are ignored.
+        if token.range.start < offset {
+            continue;
         }
 
-        NodeValue::CodeBlock(code_block) => {
-            let mut code = code_block.literal.to_owned();
-            code.pop().unwrap();
-            ScrollView::new(cx, |cx| {
-                Label::new(cx, code).class("code");
-            })
-            .show_vertical_scrollbar(false)
-            .height(Auto)
-            .width(Stretch(1.0));
+        let Some(text) = code.get(token.range.clone()) else {
+            continue;
+        };
+
+        if token.range.start > offset {
+            TextSpan::new(cx, &code[offset..token.range.start], |_| {});
         }
 
-        NodeValue::Link(link) => {
-            let url = link.url.clone();
-            TextSpan::new(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            })
-            .cursor(CursorIcon::Hand)
-            .pointer_events(PointerEvents::Auto)
-            .on_press(move |_| {
-                open::that(url.as_str()).unwrap();
+        TextSpan::new(cx, text, |_| {}).class(token.kind.class());
+        offset = token.range.end;
+    }
+
+    if offset < code.len() {
+        TextSpan::new(cx, &code[offset..], |_| {});
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::binding_system;
+    use std::cell::Cell;
+
+    /// Returns the views built by a markdown view which have the given class.
+    fn with_class(cx: &Context, markdown: Entity, class: &str) -> Vec<Entity> {
+        markdown
+            .branch_iter(&cx.tree)
+            .filter(|entity| {
+                cx.style.classes.get(*entity).is_some_and(|classes| classes.contains(class))
             })
-            .class("link");
-        }
+            .collect()
+    }
 
-        NodeValue::SoftBreak => {
-            TextSpan::new(cx, "\n", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
-            });
+    /// Returns the text of a view and of every view within it.
+    fn text_of(cx: &Context, entity: Entity) -> String {
+        entity
+            .branch_iter(&cx.tree)
+            .filter_map(|entity| cx.style.text.get(entity).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn code_block_without_closing_fence() {
+        let cx = &mut Context::default();
+        for (source, code) in
+            [("```", ""), ("```\n```", ""), ("```rust\nfn main() {}", "fn main() {}")]
+        {
+            let markdown = Markdown::new(cx, source).entity();
+            binding_system(cx);
+            let blocks = with_class(cx, markdown, "code-block");
+            assert_eq!(blocks.len(), 1);
+            assert_eq!(text_of(cx, blocks[0]), code);
         }
+    }
 
-        NodeValue::Table(_table) => {
-            VStack::new(cx, |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
+    #[test]
+    fn image_splits_paragraph() {
+        let cx = &mut Context::default();
+        let markdown = Markdown::new(cx, "Before ![alt text](image.png) after").entity();
+        binding_system(cx);
+        assert_eq!(with_class(cx, markdown, "image").len(), 1);
+
+        let paragraphs = with_class(cx, markdown, "p");
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(text_of(cx, paragraphs[0]), "Before ");
+        assert_eq!(text_of(cx, paragraphs[1]), " after");
+    }
+
+    #[test]
+    fn task_list_items() {
+        let cx = &mut Context::default();
+        let markdown = Markdown::new(cx, "- [x] Done\n- [ ] Todo").entity();
+        binding_system(cx);
+        let tasks = with_class(cx, markdown, "task");
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(with_class(cx, markdown, "checked"), [tasks[0]]);
+        assert_eq!(with_class(cx, markdown, "task-marker").len(), 2);
+    }
+
+    #[test]
+    fn footnotes() {
+        let cx = &mut Context::default();
+        let markdown = Markdown::new(cx, "Text[^note]\n\n[^note]: The note").entity();
+        binding_system(cx);
+        let references = with_class(cx, markdown, "footnote-reference");
+        assert_eq!(references.len(), 1);
+        assert_eq!(text_of(cx, references[0]), "[note]");
+
+        let footnotes = with_class(cx, markdown, "footnote");
+        assert_eq!(footnotes.len(), 1);
+        assert_eq!(text_of(cx, footnotes[0]), "[note]The note");
+    }
+
+    #[test]
+    fn render_node_overrides_nodes() {
+        let cx = &mut Context::default();
+        let markdown = Markdown::new(cx, "Some text\n\n---\n\nMore text")
+            .render_node(|cx, node| {
+                if matches!(node.data.borrow().value, NodeValue::ThematicBreak) {
+                    Label::new(cx, "* * *").class("separator");
+                    true
+                } else {
+                    false
                 }
             })
-            .class("table")
-            .width(Stretch(1.0))
-            .height(Auto);
-        }
+            .entity();
+        binding_system(cx);
 
-        NodeValue::TableRow(headers) => {
-            HStack::new(cx, |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
-                }
+        assert_eq!(with_class(cx, markdown, "separator").len(), 1);
+        assert!(with_class(cx, markdown, "hr").is_empty());
+        // Nodes which are not overridden are rendered as usual.
+        assert_eq!(with_class(cx, markdown, "p").len(), 2);
+    }
+
+    #[test]
+    fn code_spans_skip_overlapping_and_out_of_range_tokens() {
+        let cx = &mut Context::default();
+        let tokens = [
+            CodeToken::new(0..3, TokenKind::Keyword),
+            // Overlaps the previous token.
+            CodeToken::new(2..5, TokenKind::Variable),
+            CodeToken::new(8..9, TokenKind::Number),
+            // Extends past the end of the code.
+            CodeToken::new(9..20, TokenKind::Punctuation),
+        ];
+        let label = Label::rich(cx, "", |cx| build_code_spans(cx, "let x = 1;", &tokens)).entity();
+
+        let spans = label
+            .branch_iter(&cx.tree)
+            .skip(1)
+            .map(|span| {
+                let classes = cx.style.classes.get(span);
+                let kind = TokenKind::ALL
+                    .into_iter()
+                    .find(|kind| classes.is_some_and(|classes| classes.contains(kind.class())));
+                (cx.style.text.get(span).cloned().unwrap_or_default(), kind)
             })
-            .class("table-row")
-            .toggle_class("table-headers", *headers)
-            .width(Stretch(1.0))
-            .height(Auto);
-            Divider::horizontal(cx);
-        }
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [
+                (String::from("let"), Some(TokenKind::Keyword)),
+                (String::from(" x = "), None),
+                (String::from("1"), Some(TokenKind::Number)),
+                (String::from(";"), None),
+            ]
+        );
+    }
 
-        NodeValue::TableCell => {
-            Label::rich(cx, "", |cx| {
-                for child in node.children() {
-                    parse_node(cx, child, list_level);
+    #[test]
+    fn fenced_code_uses_registered_highlighter() {
+        let cx = &mut Context::default();
+        cx.add_code_highlighter("rust", |line: &str, _: &mut HighlightState| {
+            line.match_indices("fn")
+                .map(|(start, text)| CodeToken::new(start..start + text.len(), TokenKind::Keyword))
+                .collect::<Vec<_>>()
+        });
+
+        // The language of the info string is matched case-insensitively.
+        let markdown = Markdown::new(cx, "```Rust\nfn main() {}\n```").entity();
+        binding_system(cx);
+        let keywords = with_class(cx, markdown, "keyword");
+        assert_eq!(keywords.len(), 1);
+        assert_eq!(text_of(cx, keywords[0]), "fn");
+
+        // Code blocks of other languages are not highlighted.
+        let markdown = Markdown::new(cx, "```python\nfn main() {}\n```").entity();
+        binding_system(cx);
+        assert!(with_class(cx, markdown, "keyword").is_empty());
+        assert_eq!(text_of(cx, with_class(cx, markdown, "code-block")[0]), "fn main() {}");
+    }
+
+    #[test]
+    fn document_is_rendered_once_after_modifiers() {
+        let cx = &mut Context::default();
+        let rendered = Rc::new(Cell::new(0));
+        let counter = rendered.clone();
+        Markdown::new(cx, "Some text")
+            .selectable(true)
+            .render_node(move |_, node| {
+                if matches!(node.data.borrow().value, NodeValue::Paragraph) {
+                    counter.set(counter.get() + 1);
                 }
+                false
             })
-            .class("table-cell")
-            .width(Stretch(1.0));
-        }
+            .selectable(false);
+
+        // Nothing is rendered until bindings are updated.
+        assert_eq!(rendered.get(), 0);
+        binding_system(cx);
+        assert_eq!(rendered.get(), 1);
 
-        _ => {}
+        // The document is not rendered again while it is unchanged.
+        binding_system(cx);
+        assert_eq!(rendered.get(), 1);
     }
 }
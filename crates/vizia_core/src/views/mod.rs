@@ -5,6 +5,7 @@ mod badge;
 mod button;
 mod checkbox;
 mod chip;
+mod code_editor;
 mod collapsible;
 mod combobox;
 mod datepicker;
@@ -13,6 +14,7 @@ mod dropdown;
 mod element;
 mod grid;
 mod image;
+mod inspector;
 mod knob;
 mod label;
 mod list;
@@ -23,6 +25,8 @@ mod popup;
 mod progressbar;
 mod radio;
 mod rating;
+mod resize_handle;
+mod rich_textbox;
 mod scrollbar;
 mod scrollview;
 mod slider;
@@ -30,6 +34,7 @@ mod spinbox;
 mod stack;
 mod switch;
 mod tabview;
+mod text_editor;
 mod textbox;
 mod toggle_button;
 mod tooltip;
@@ -42,6 +47,7 @@ pub use badge::*;
 pub use button::*;
 pub use checkbox::*;
 pub use chip::*;
+pub use code_editor::*;
 pub use collapsible::*;
 pub use combobox::*;
 pub use datepicker::*;
@@ -50,6 +56,7 @@ pub use dropdown::*;
 pub use element::*;
 pub use grid::*;
 pub use image::*;
+pub use inspector::*;
 pub use knob::*;
 pub use label::*;
 pub use list::*;
@@ -61,6 +68,8 @@ pub use popup::*;
 pub use progressbar::*;
 pub use radio::*;
 pub use rating::*;
+pub use resize_handle::*;
+pub use rich_textbox::*;
 pub use scrollbar::*;
 pub use scrollview::*;
 pub use slider::*;
@@ -68,6 +77,7 @@ pub use spinbox::*;
 pub use stack::*;
 pub use switch::*;
 pub use tabview::*;
+pub use text_editor::*;
 pub use textbox::*;
 pub use toggle_button::*;
 pub use tooltip::*;
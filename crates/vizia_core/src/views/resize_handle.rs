@@ -0,0 +1,112 @@
+use crate::icons::ICON_ARROWS_DIAGONAL_2;
+use crate::prelude::*;
+
+/// A handle, usually placed in the bottom-right corner of a window, which resizes the window when dragged.
+///
+/// Dragging the handle emits a [`WindowEvent::SetSize`] event to the parent window of the handle.
+/// The requested size is in logical units, so when the window contents are scaled to fit the window
+/// the new size of the window is derived from the size of the window on screen rather than the size
+/// of its contents.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// ZStack::new(cx, |cx| {
+///     // Window content here
+///     ResizeHandle::new(cx);
+/// });
+/// ```
+pub struct ResizeHandle {
+    drag_start: Option<DragStart>,
+    min_size: WindowSize,
+    on_resize: Option<Box<dyn Fn(&mut EventContext, WindowSize)>>,
+}
+
+#[derive(Clone, Copy)]
+struct DragStart {
+    cursor: (f32, f32),
+    window_size: (f32, f32),
+}
+
+impl ResizeHandle {
+    /// Creates a new [ResizeHandle] view.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self { drag_start: None, min_size: WindowSize::new(1, 1), on_resize: None }
+            .build(cx, |cx| {
+                Svg::new(cx, ICON_ARROWS_DIAGONAL_2).hoverable(false).class("icon");
+            })
+            .navigable(false)
+    }
+}
+
+impl View for ResizeHandle {
+    fn element(&self) -> Option<&'static str> {
+        Some("resize-handle")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                let window = cx.parent_window();
+                let mouse = cx.mouse();
+                self.drag_start = Some(DragStart {
+                    cursor: (mouse.cursor_x, mouse.cursor_y),
+                    window_size: (cx.cache.get_width(window), cx.cache.get_height(window)),
+                });
+
+                cx.capture();
+                cx.set_active(true);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some(drag_start) = self.drag_start {
+                    let scale_factor = cx.scale_factor();
+
+                    let physical_width = drag_start.window_size.0 + (*x - drag_start.cursor.0);
+                    let physical_height = drag_start.window_size.1 + (*y - drag_start.cursor.1);
+
+                    let size = WindowSize::new(
+                        ((physical_width / scale_factor).round() as u32).max(self.min_size.width),
+                        ((physical_height / scale_factor).round() as u32).max(self.min_size.height),
+                    );
+
+                    let window = cx.parent_window();
+                    cx.emit_to(window, WindowEvent::SetSize(size));
+
+                    if let Some(callback) = &self.on_resize {
+                        (callback)(cx, size);
+                    }
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_start.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, ResizeHandle> {
+    /// Sets the minimum logical size of the window which can be requested by dragging the handle.
+    pub fn min_size(self, size: impl Into<WindowSize>) -> Self {
+        let size = size.into();
+        self.modify(|resize_handle| resize_handle.min_size = size)
+    }
+
+    /// Sets the callback triggered when the handle requests a new window size.
+    pub fn on_resize<F: Fn(&mut EventContext, WindowSize) + 'static>(self, callback: F) -> Self {
+        self.modify(|resize_handle| resize_handle.on_resize = Some(Box::new(callback)))
+    }
+}
@@ -0,0 +1,806 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+use crate::prelude::*;
+
+use crate::systems::padded_bounds;
+use crate::text::{
+    apply_movement, ensure_visible, offset_for_delete_backwards, Direction, Movement, Selection,
+    VerticalMovement,
+};
+use skia_safe::textlayout::{Paragraph, RectHeightStyle, RectWidthStyle};
+use skia_safe::{ClipOp, Paint, PaintStyle, Rect};
+
+use super::scrollview::SCROLL_SENSITIVITY;
+
+/// Events which can be sent to a [`RichTextbox`] to change the style of its text.
+pub enum RichTextEvent {
+    /// Toggles an attribute of the selected text, or of the text typed next if nothing is selected.
+    ToggleAttribute(RunAttribute),
+    /// Sets the color of the selected text, or of the text typed next if nothing is selected.
+    SetColor(Option<Color>),
+    /// Sets the link of the selected text.
+    SetLink(Option<String>),
+    /// Copies the HTML source of the selected text to the clipboard. The clipboard only holds plain
+    /// text, so the source is pasted as markup rather than as formatted text.
+    CopyHtmlSource,
+}
+
+/// A view for editing text made up of runs with different styles, such as bold, italic or
+/// underlined text, colored text and links.
+///
+/// The rich textbox binds to a [`RichText`] document. Each run of the document is displayed as a
+/// [`TextSpan`], so that the runs are shaped and laid out as a single paragraph. When the document
+/// changes only the spans of the runs which changed are updated. Links can be styled with the `link`
+/// class of the text spans.
+///
+/// The attributes of the selected text are toggled with Ctrl+B, Ctrl+I and Ctrl+U, or Cmd on macOS.
+/// When nothing is selected these toggle the attributes of the text typed next instead. Typed text
+/// otherwise continues the style of the text before the caret. Ctrl+C copies the selected text as
+/// plain text, and Ctrl+Shift+C copies its HTML source as plain text.
+///
+/// The edited document is provided to the `on_edit` callback, which should be used to update the bound data.
+///
+/// # Example
+/// ```rust
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     text: RichText,
+/// # }
+/// #
+/// # pub enum AppEvent {
+/// #     SetText(RichText),
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { text: RichText::from("Hello World") }.build(cx);
+/// #
+/// RichTextbox::new(cx, AppData::text)
+///     .on_edit(|cx, text| cx.emit(AppEvent::SetText(text)));
+/// ```
+#[derive(Lens)]
+pub struct RichTextbox<L: Lens> {
+    p: PhantomData<L>,
+    document: RichText,
+    /// The text spans which display the runs of the document, and the styles of those runs.
+    spans: Vec<(Entity, RunStyle)>,
+    edit: bool,
+    show_caret: bool,
+    caret_timer: Timer,
+    selection: Selection,
+    /// The style of the text typed next, when it has been changed with the caret in place.
+    typing_style: Option<RunStyle>,
+    /// The vertical scroll position of the text, in physical pixels.
+    scroll: Cell<f32>,
+    /// Whether the text should be scrolled to keep the caret visible when next drawn.
+    follow_caret: Cell<bool>,
+    on_edit: Option<Box<dyn Fn(&mut EventContext, RichText) + Send + Sync>>,
+}
+
+impl<L> RichTextbox<L>
+where
+    L: Lens<Target = RichText>,
+{
+    /// Creates a new rich textbox for the document bound by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        let caret_timer = cx.environment().caret_timer;
+
+        Self {
+            p: PhantomData,
+            document: RichText::new(),
+            spans: Vec::new(),
+            edit: false,
+            show_caret: true,
+            caret_timer,
+            selection: Selection::caret(0),
+            typing_style: None,
+            scroll: Cell::new(0.0),
+            follow_caret: Cell::new(false),
+            on_edit: None,
+        }
+        .build(cx, |_| {})
+        .navigable(true)
+        .role(Role::MultilineTextInput)
+        .toggle_class("caret", Self::show_caret)
+        .text("")
+        .bind(Self::document, |mut handle, document| {
+            let document = document.get(&handle);
+            let entity = handle.entity();
+            let mut spans = Vec::new();
+            handle = handle.modify(|textbox| spans = std::mem::take(&mut textbox.spans));
+            update_spans(handle.context(), entity, &mut spans, &document);
+            handle.modify(|textbox| textbox.spans = spans);
+        })
+        .bind(lens, |handle, lens| {
+            let document = lens.get(&handle);
+            handle.modify(|textbox| {
+                // The bound data is usually updated with the document produced by an edit, which
+                // the textbox already contains, so the selection is kept where it is.
+                let len = document.len();
+                textbox.document = document;
+                textbox.selection = Selection::new(
+                    textbox.selection.anchor.min(len),
+                    textbox.selection.active.min(len),
+                );
+                textbox.typing_style = None;
+            });
+        })
+    }
+
+    fn insert_text(&mut self, cx: &mut EventContext, txt: &str) {
+        let range = self.selection.range();
+        let style = self.typing_style.take().unwrap_or_else(|| self.document.style_at(range.start));
+        self.document.edit(range.clone(), txt, style);
+        self.selection = Selection::caret(range.start + txt.len());
+        self.follow_caret.set(true);
+        cx.needs_redraw();
+    }
+
+    /// Deletes the selected text, or the text covered by the movement if nothing is selected,
+    /// returning `true` if any text was deleted.
+    fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) -> bool {
+        let text = self.document.text().to_owned();
+        let range = if !self.selection.is_caret() {
+            self.selection.range()
+        } else if movement == Movement::Grapheme(Direction::Upstream) {
+            offset_for_delete_backwards(&self.selection, &text)..self.selection.active
+        } else if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            apply_movement(movement, self.selection, &text, paragraph, true).range()
+        } else {
+            return false;
+        };
+
+        if range.is_empty() {
+            return false;
+        }
+
+        let style = self.document.style_at(range.start);
+        self.document.edit(range.clone(), "", style);
+        self.selection = Selection::caret(range.start);
+        self.typing_style = None;
+        self.follow_caret.set(true);
+        cx.needs_redraw();
+        true
+    }
+
+    fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let text = self.document.text().to_owned();
+            self.selection = apply_movement(movement, self.selection, &text, paragraph, selection);
+            self.typing_style = None;
+            self.follow_caret.set(true);
+            cx.needs_redraw();
+        }
+    }
+
+    fn select_all(&mut self, cx: &mut EventContext) {
+        self.selection = Selection::new(0, self.document.len());
+        self.typing_style = None;
+        cx.needs_redraw();
+    }
+
+    fn select_word(&mut self, cx: &mut EventContext) {
+        self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
+        self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
+    }
+
+    fn select_paragraph(&mut self, cx: &mut EventContext) {
+        self.move_cursor(cx, Movement::ParagraphStart, false);
+        self.move_cursor(cx, Movement::ParagraphEnd, true);
+    }
+
+    /// Toggles an attribute of the selected text, or of the text typed next if nothing is selected,
+    /// returning `true` if the document was changed.
+    fn toggle_attribute(&mut self, attribute: RunAttribute) -> bool {
+        if self.selection.is_caret() {
+            let style = self
+                .typing_style
+                .get_or_insert_with(|| self.document.style_at(self.selection.active));
+            style.set(attribute, !style.has(attribute));
+            false
+        } else {
+            self.document.toggle_attribute(self.selection.range(), attribute);
+            true
+        }
+    }
+
+    /// Applies a function to the style of the selected text, or of the text typed next if nothing is
+    /// selected, returning `true` if the document was changed.
+    fn update_style(&mut self, f: impl Fn(&mut RunStyle)) -> bool {
+        if self.selection.is_caret() {
+            f(self
+                .typing_style
+                .get_or_insert_with(|| self.document.style_at(self.selection.active)));
+            false
+        } else {
+            let old = self.document.clone();
+            self.document.update_style(self.selection.range(), f);
+            self.document != old
+        }
+    }
+
+    /// This function takes window-global physical coordinates.
+    fn hit(&mut self, cx: &mut EventContext, x: f32, y: f32, selection: bool) {
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let bounds = padded_bounds(cx.style, cx.current, cx.bounds());
+            let position = paragraph
+                .get_glyph_position_at_coordinate((x - bounds.x, y - bounds.y + self.scroll.get()));
+            let text = self.document.text();
+            let offset =
+                cx.text_context.text_offset_at(cx.current, text, position.position.max(0) as usize);
+            if selection {
+                self.selection.active = offset;
+            } else {
+                self.selection = Selection::caret(offset);
+            }
+
+            self.typing_style = None;
+            self.follow_caret.set(true);
+            cx.needs_redraw();
+        }
+    }
+
+    /// Scrolls the text by the given physical distance, returning `true` if the scroll position changed.
+    fn scroll_by(&mut self, cx: &mut EventContext, y: f32) -> bool {
+        if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+            let bounds = padded_bounds(cx.style, cx.current, cx.bounds());
+            let max_y = (paragraph.height() - bounds.height()).max(0.0);
+            let scroll = (self.scroll.get() + y).clamp(0.0, max_y);
+            if scroll != self.scroll.get() {
+                self.scroll.set(scroll);
+                cx.needs_redraw();
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn emit_edit(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_edit {
+            (callback)(cx, self.document.clone());
+        }
+    }
+
+    fn reset_caret_timer(&mut self, cx: &mut EventContext) {
+        cx.stop_timer(self.caret_timer);
+        if !cx.is_read_only() {
+            self.show_caret = true;
+            cx.start_timer(self.caret_timer);
+        }
+    }
+}
+
+/// Updates the text spans of a rich textbox to display the runs of a document.
+///
+/// The spans are matched to the runs in order, and only the spans whose text or style differs from
+/// their run are changed. Spans are added or removed at the end when the number of runs changes.
+fn update_spans(
+    cx: &mut Context,
+    entity: Entity,
+    spans: &mut Vec<(Entity, RunStyle)>,
+    document: &RichText,
+) {
+    let mut count = 0;
+    let mut changed = false;
+    for (range, style) in document.runs() {
+        let text = &document.text()[range];
+        if let Some((span, span_style)) = spans.get_mut(count) {
+            let span = *span;
+            if cx.style.text.get(span).map(String::as_str) != Some(text) {
+                cx.style.text.insert(span, text.to_owned());
+                changed = true;
+            }
+
+            if span_style != style {
+                span_style.clone_from(style);
+                style_run(cx, span, style);
+                changed = true;
+            }
+        } else {
+            let span = cx.with_current(entity, |cx| TextSpan::new(cx, text, |_| {}).entity());
+            style_run(cx, span, style);
+            spans.push((span, style.clone()));
+            changed = true;
+        }
+
+        count += 1;
+    }
+
+    for (span, _) in spans.drain(count..) {
+        cx.remove(span);
+        changed = true;
+    }
+
+    if changed {
+        // The spans are part of the paragraph of the textbox, which must be built again.
+        cx.style.needs_text_update(entity);
+    }
+}
+
+/// Sets the style of the text span which displays a run of a rich text document.
+fn style_run(cx: &mut Context, span: Entity, style: &RunStyle) {
+    cx.style.font_weight.remove(span);
+    cx.style.font_slant.remove(span);
+    cx.style.text_decoration_line.remove(span);
+    cx.style.font_color.remove(span);
+    cx.style.font_gradient.remove(span);
+    cx.style.needs_restyle(span);
+
+    let mut span = Handle::<TextSpan> { current: span, entity: span, p: PhantomData, cx }
+        .toggle_class("link", style.link.is_some());
+    if style.bold {
+        span = span.font_weight(FontWeightKeyword::Bold);
+    }
+    if style.italic {
+        span = span.font_slant(FontSlant::Italic);
+    }
+    if style.underline {
+        span = span.text_decoration_line(TextDecorationLine::Underline);
+    }
+    if let Some(color) = style.color {
+        span.color(color);
+    }
+}
+
+/// Returns the bounds of the caret at the given UTF-16 index into the paragraph, relative to its
+/// top-left corner.
+fn caret_bounds(paragraph: &Paragraph, index: usize) -> BoundingBox {
+    // The paragraph ends with a zero-width space, so there is a glyph after the last offset.
+    let rects = paragraph.get_rects_for_range(
+        index..index + 1,
+        RectHeightStyle::Max,
+        RectWidthStyle::Tight,
+    );
+    if let Some(rect) = rects.first() {
+        return BoundingBox { x: rect.rect.left, y: rect.rect.top, w: 1.0, h: rect.rect.height() };
+    }
+
+    // Line breaks have no glyph, so the caret is placed after the preceding text instead.
+    let rects = paragraph.get_rects_for_range(
+        index.saturating_sub(1)..index,
+        RectHeightStyle::Max,
+        RectWidthStyle::Tight,
+    );
+    rects
+        .last()
+        .map(|rect| BoundingBox {
+            x: rect.rect.right,
+            y: rect.rect.top,
+            w: 1.0,
+            h: rect.rect.height(),
+        })
+        .unwrap_or_default()
+}
+
+impl<L: Lens> Handle<'_, RichTextbox<L>> {
+    /// Sets the callback triggered when the rich textbox is edited, i.e. text is inserted/deleted or
+    /// restyled.
+    ///
+    /// Callback provides the edited document.
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, RichText) + Send + Sync,
+    {
+        self.modify(|textbox: &mut RichTextbox<L>| textbox.on_edit = Some(Box::new(callback)))
+    }
+}
+
+impl<L> View for RichTextbox<L>
+where
+    L: Lens<Target = RichText>,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("rich-textbox")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_over() && !cx.is_disabled() {
+                    cx.focus_with_visibility(false);
+                    cx.capture();
+                    cx.lock_cursor_icon();
+                    if !self.edit {
+                        cx.emit(TextEvent::StartEdit);
+                    }
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::Hit(
+                        cx.mouse.cursor_x,
+                        cx.mouse.cursor_y,
+                        cx.modifiers.shift(),
+                    ));
+                }
+            }
+
+            WindowEvent::FocusIn => {
+                cx.emit(TextEvent::StartEdit);
+            }
+
+            WindowEvent::FocusOut => {
+                cx.emit(TextEvent::EndEdit);
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                cx.emit(TextEvent::SelectWord);
+            }
+
+            WindowEvent::MouseTripleClick(MouseButton::Left) => {
+                cx.emit(TextEvent::SelectParagraph);
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                self.reset_caret_timer(cx);
+                cx.unlock_cursor_icon();
+                cx.release();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if cx.mouse.left.state == MouseButtonState::Pressed
+                    && cx.mouse.left.pressed == cx.current
+                    && (cx.mouse.left.pos_down.0 != *x || cx.mouse.left.pos_down.1 != *y)
+                {
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::Drag(cx.mouse.cursor_x, cx.mouse.cursor_y));
+                }
+            }
+
+            WindowEvent::MouseScroll(_, y) => {
+                if self.scroll_by(cx, -*y * SCROLL_SENSITIVITY * cx.scale_factor()) {
+                    // Prevent event propagating to ancestor scrollviews.
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::CharInput(c) => {
+                if *c != '\u{1b}' && // Escape
+                    *c != '\u{8}' && // Backspace
+                    *c != '\u{9}' && // Tab
+                    *c != '\u{7f}' && // Delete
+                    *c != '\u{0d}' && // Carriage return
+                    !cx.modifiers.ctrl() &&
+                    !cx.modifiers.logo() &&
+                    self.edit &&
+                    !cx.is_read_only()
+                {
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::InsertText(String::from(*c)));
+                }
+            }
+
+            WindowEvent::KeyDown(code, _) if self.edit => match code {
+                Code::Enter | Code::NumpadEnter => {
+                    if !cx.is_read_only() {
+                        self.reset_caret_timer(cx);
+                        cx.emit(TextEvent::InsertText(String::from("\n")));
+                    }
+                }
+
+                Code::ArrowLeft | Code::ArrowRight => {
+                    self.reset_caret_timer(cx);
+                    let direction =
+                        if *code == Code::ArrowLeft { Direction::Left } else { Direction::Right };
+                    let movement = if cx.modifiers.ctrl() {
+                        Movement::Word(direction)
+                    } else {
+                        Movement::Grapheme(direction)
+                    };
+
+                    cx.emit(TextEvent::MoveCursor(movement, cx.modifiers.shift()));
+                }
+
+                Code::ArrowUp | Code::ArrowDown => {
+                    self.reset_caret_timer(cx);
+                    let movement = if *code == Code::ArrowUp {
+                        VerticalMovement::LineUp
+                    } else {
+                        VerticalMovement::LineDown
+                    };
+
+                    cx.emit(TextEvent::MoveCursor(
+                        Movement::Vertical(movement),
+                        cx.modifiers.shift(),
+                    ));
+                }
+
+                Code::Backspace | Code::Delete => {
+                    self.reset_caret_timer(cx);
+                    if !cx.is_read_only() {
+                        let direction = if *code == Code::Backspace {
+                            Direction::Upstream
+                        } else {
+                            Direction::Downstream
+                        };
+                        let movement = if cx.modifiers.ctrl() {
+                            Movement::Word(direction)
+                        } else {
+                            Movement::Grapheme(direction)
+                        };
+
+                        cx.emit(TextEvent::DeleteText(movement));
+                    }
+                }
+
+                Code::Home | Code::End => {
+                    self.reset_caret_timer(cx);
+                    let movement =
+                        if *code == Code::Home { Movement::LineStart } else { Movement::LineEnd };
+                    cx.emit(TextEvent::MoveCursor(movement, cx.modifiers.shift()));
+                }
+
+                Code::KeyA
+                | Code::KeyB
+                | Code::KeyC
+                | Code::KeyI
+                | Code::KeyU
+                | Code::KeyV
+                | Code::KeyX => {
+                    #[cfg(target_os = "macos")]
+                    let modifier = Modifiers::SUPER;
+                    #[cfg(not(target_os = "macos"))]
+                    let modifier = Modifiers::CTRL;
+
+                    if cx.modifiers == &modifier {
+                        match code {
+                            Code::KeyA => cx.emit(TextEvent::SelectAll),
+                            Code::KeyC => cx.emit(TextEvent::Copy),
+                            Code::KeyV => cx.emit(TextEvent::Paste),
+                            _ if cx.is_read_only() => {}
+                            Code::KeyB => {
+                                cx.emit(RichTextEvent::ToggleAttribute(RunAttribute::Bold))
+                            }
+                            Code::KeyI => {
+                                cx.emit(RichTextEvent::ToggleAttribute(RunAttribute::Italic))
+                            }
+                            Code::KeyU => {
+                                cx.emit(RichTextEvent::ToggleAttribute(RunAttribute::Underline))
+                            }
+                            _ => cx.emit(TextEvent::Cut),
+                        }
+                    } else if *code == Code::KeyC && *cx.modifiers == modifier | Modifiers::SHIFT {
+                        cx.emit(RichTextEvent::CopyHtmlSource);
+                    }
+                }
+
+                _ => {}
+            },
+
+            _ => {}
+        });
+
+        event.map(|text_event, _| match text_event {
+            TextEvent::InsertText(text) => {
+                if self.edit && !cx.is_read_only() {
+                    self.insert_text(cx, text);
+                    self.emit_edit(cx);
+                }
+            }
+
+            TextEvent::DeleteText(movement) => {
+                if self.edit && !cx.is_read_only() && self.delete_text(cx, *movement) {
+                    self.emit_edit(cx);
+                }
+            }
+
+            TextEvent::MoveCursor(movement, selection) => {
+                if self.edit {
+                    self.move_cursor(cx, *movement, *selection);
+                }
+            }
+
+            TextEvent::SelectAll => self.select_all(cx),
+
+            TextEvent::SelectWord => self.select_word(cx),
+
+            TextEvent::SelectParagraph => self.select_paragraph(cx),
+
+            TextEvent::StartEdit => {
+                if !cx.is_disabled() && !self.edit {
+                    self.edit = true;
+                    cx.focus_with_visibility(false);
+                    cx.set_checked(true);
+                    self.reset_caret_timer(cx);
+                }
+            }
+
+            TextEvent::EndEdit => {
+                self.edit = false;
+                self.selection = Selection::caret(self.selection.active);
+                self.typing_style = None;
+                cx.set_checked(false);
+                cx.release();
+                cx.stop_timer(self.caret_timer);
+            }
+
+            TextEvent::Hit(x, y, selection) => self.hit(cx, *x, *y, *selection),
+
+            TextEvent::Drag(x, y) => self.hit(cx, *x, *y, true),
+
+            TextEvent::Scroll(_, y) => {
+                self.scroll_by(cx, *y);
+            }
+
+            TextEvent::Copy =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit && !self.selection.is_caret() {
+                    let selected_text = self.document.text()[self.selection.range()].to_owned();
+                    cx.set_clipboard(selected_text).expect("Failed to add text to clipboard");
+                }
+            }
+
+            TextEvent::Paste =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit {
+                    if let Ok(text) = cx.get_clipboard() {
+                        cx.emit(TextEvent::InsertText(text));
+                    }
+                }
+            }
+
+            TextEvent::Cut =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit && !self.selection.is_caret() {
+                    let selected_text = self.document.text()[self.selection.range()].to_owned();
+                    cx.set_clipboard(selected_text).expect("Failed to add text to clipboard");
+                    cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Upstream)));
+                }
+            }
+
+            TextEvent::ToggleCaret => {
+                self.show_caret ^= true;
+            }
+
+            _ => {}
+        });
+
+        event.map(|rich_text_event, _| match rich_text_event {
+            RichTextEvent::ToggleAttribute(attribute) => {
+                if !cx.is_read_only() && self.toggle_attribute(*attribute) {
+                    self.emit_edit(cx);
+                }
+            }
+
+            RichTextEvent::SetColor(color) => {
+                if !cx.is_read_only() && self.update_style(|style| style.color = *color) {
+                    self.emit_edit(cx);
+                }
+            }
+
+            RichTextEvent::SetLink(link) => {
+                if !cx.is_read_only() && self.update_style(|style| style.link.clone_from(link)) {
+                    self.emit_edit(cx);
+                }
+            }
+
+            RichTextEvent::CopyHtmlSource =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit && !self.selection.is_caret() {
+                    let html = self.document.slice(self.selection.range()).to_html();
+                    cx.set_clipboard(html).expect("Failed to add text to clipboard");
+                }
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        cx.draw_shadows(canvas);
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+
+        let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) else {
+            return;
+        };
+
+        let bounds = padded_bounds(cx.style, cx.current, cx.bounds());
+        let text = self.document.text();
+        let caret = caret_bounds(
+            paragraph,
+            cx.text_context.paragraph_index(cx.current, text, self.selection.active),
+        );
+
+        if self.follow_caret.take() {
+            let caret_box = BoundingBox { x: bounds.x + caret.x, y: bounds.y + caret.y, ..caret };
+            let (_, ty) = ensure_visible(&caret_box, &bounds, (0.0, -self.scroll.get()));
+            let max_y = (paragraph.height() - bounds.height()).max(0.0);
+            self.scroll.set((-ty).clamp(0.0, max_y));
+        }
+
+        let origin_x = bounds.x;
+        let origin_y = bounds.y - self.scroll.get();
+
+        canvas.save();
+        canvas.clip_rect(
+            Rect::new(bounds.left(), bounds.top(), bounds.right(), bounds.bottom()),
+            ClipOp::Intersect,
+            true,
+        );
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+
+        let mut selection_rects = Vec::new();
+        if !self.selection.is_caret() {
+            let start = cx.text_context.paragraph_index(cx.current, text, self.selection.min());
+            let end = cx.text_context.paragraph_index(cx.current, text, self.selection.max());
+            paint.set_color(cx.selection_color());
+            for rect in paragraph.get_rects_for_range(
+                start..end,
+                RectHeightStyle::Max,
+                RectWidthStyle::Tight,
+            ) {
+                let rect = rect.rect.with_offset((origin_x, origin_y));
+                canvas.draw_rect(rect, &paint);
+                selection_rects.push(rect);
+            }
+        }
+
+        paragraph.paint(canvas, (origin_x, origin_y));
+        cx.draw_generated_content(canvas, paragraph, (origin_x, origin_y));
+        cx.draw_selected_text(canvas, paragraph, (origin_x, origin_y), &selection_rects);
+
+        if self.edit {
+            let x = (origin_x + caret.x).round();
+            let y = (origin_y + caret.y).round();
+            paint.set_color(cx.caret_color());
+            canvas.draw_rect(Rect::from_xywh(x, y, 1.0, caret.h), &paint);
+        }
+
+        canvas.restore();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> RunStyle {
+        RunStyle { bold: true, ..Default::default() }
+    }
+
+    fn span_text(cx: &Context, span: Entity) -> Option<&str> {
+        cx.style.text.get(span).map(String::as_str)
+    }
+
+    #[test]
+    fn update_spans_only_changes_touched_runs() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).entity();
+        let mut spans = Vec::new();
+
+        update_spans(cx, entity, &mut spans, &RichText::from("Hello ").with("World", bold()));
+        assert_eq!(spans.len(), 2);
+        let (first, second) = (spans[0].0, spans[1].0);
+        assert_eq!(span_text(cx, first), Some("Hello "));
+        assert_eq!(cx.style.font_weight.get_inline(second), Some(&FontWeightKeyword::Bold.into()));
+
+        // Typing in a run keeps every span and only changes the text of that run.
+        update_spans(cx, entity, &mut spans, &RichText::from("Hello ").with("World!", bold()));
+        assert_eq!(spans.iter().map(|(span, _)| *span).collect::<Vec<_>>(), [first, second]);
+        assert_eq!(span_text(cx, first), Some("Hello "));
+        assert_eq!(span_text(cx, second), Some("World!"));
+
+        // Restyling the runs into a single run reuses the first span and removes the other.
+        update_spans(cx, entity, &mut spans, &RichText::from("Hello World!"));
+        assert_eq!(spans.iter().map(|(span, _)| *span).collect::<Vec<_>>(), [first]);
+        assert_eq!(span_text(cx, first), Some("Hello World!"));
+        assert_eq!(span_text(cx, second), None);
+
+        // Restyling a run removes the attributes it no longer has.
+        update_spans(cx, entity, &mut spans, &RichText::new().with("Hello", bold()));
+        assert_eq!(spans[0].0, first);
+        assert!(cx.style.font_weight.get_inline(first).is_some());
+        update_spans(cx, entity, &mut spans, &RichText::from("Hello"));
+        assert!(cx.style.font_weight.get_inline(first).is_none());
+    }
+}
@@ -0,0 +1,1227 @@
+use std::{cell::Cell, marker::PhantomData, ops::Range};
+
+use crate::context::paint_selected_text;
+use crate::prelude::*;
+
+use crate::systems::{build_line_number, document_bounds, layout_document};
+use crate::text::{
+    ensure_visible, offset_for_delete_backwards, Direction, EditableText, Movement, Selection,
+    TextContext, TextDocument, VerticalMovement,
+};
+use skia_safe::{ClipOp, Paint, PaintStyle, Rect};
+
+use super::scrollview::SCROLL_SENSITIVITY;
+
+/// A view for editing large, multi-line documents.
+///
+/// Unlike a multi-line [`Textbox`], the text editor binds directly to a [`Rope`] rather than converting the
+/// bound value to and from a `String`. Edits are applied to the rope in place, and only the lines touched by
+/// an edit, and only those within or near the visible region, are laid out again. This makes the text editor
+/// suitable for editing documents which are many megabytes in size, such as logs or patches.
+///
+/// The edited rope is provided to the `on_edit` callback, which should be used to update the bound data.
+/// Cloning a rope is cheap, as the clone shares its contents with the original.
+///
+/// The text editor can also be configured for editing source code, with line numbers, syntax highlighting,
+/// indentation with the tab key, and highlighting of the current line and of matching brackets. The colors
+/// of these highlights are derived from the font color and the selection color of the text editor. The
+/// tokens of the syntax highlighter are styled by the rules which match the class of their kind within
+/// the text editor, e.g. `text-editor .keyword`.
+///
+/// # Example
+/// ```rust
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     document: Rope,
+/// # }
+/// #
+/// # pub enum AppEvent {
+/// #     SetDocument(Rope),
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { document: Rope::from_str("Hello World") }.build(cx);
+/// #
+/// TextEditor::new(cx, AppData::document)
+///     .on_edit(|cx, document| cx.emit(AppEvent::SetDocument(document)));
+/// ```
+#[derive(Lens)]
+pub struct TextEditor<L: Lens> {
+    p: PhantomData<L>,
+    edit: bool,
+    show_caret: bool,
+    caret_timer: Timer,
+    selection: Selection,
+    /// The scroll position of the document, in physical pixels.
+    scroll: Cell<(f32, f32)>,
+    /// Whether the document should be scrolled to keep the caret visible when next drawn.
+    follow_caret: Cell<bool>,
+    indent: Option<Indent>,
+    highlight_current_line: bool,
+    match_brackets: bool,
+    /// Ranges of the document which are highlighted, such as the results of a search.
+    matches: Vec<Range<usize>>,
+    on_edit: Option<Box<dyn Fn(&mut EventContext, Rope) + Send + Sync>>,
+}
+
+/// The indentation inserted by the tab key in a [`TextEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// Indent with spaces, up to the next multiple of the given number of columns.
+    Spaces(usize),
+    /// Indent with tab characters.
+    Tab,
+}
+
+impl Indent {
+    /// Returns the text of a single level of indentation.
+    fn text(&self) -> String {
+        match self {
+            Indent::Spaces(width) => " ".repeat(*width),
+            Indent::Tab => String::from("\t"),
+        }
+    }
+
+    /// Returns the text which indents from the given column to the next level of indentation.
+    fn text_at(&self, column: usize) -> String {
+        match self {
+            Indent::Spaces(width) => {
+                let width = (*width).max(1);
+                " ".repeat(width - column % width)
+            }
+            Indent::Tab => String::from("\t"),
+        }
+    }
+
+    /// Returns the length in bytes of the first level of indentation at the start of the line.
+    fn leading_len(&self, line: &str) -> usize {
+        if line.starts_with('\t') {
+            return 1;
+        }
+
+        let width = match self {
+            Indent::Spaces(width) => *width,
+            Indent::Tab => 4,
+        };
+        line.bytes().take(width).take_while(|byte| *byte == b' ').count()
+    }
+}
+
+/// Events which can be sent to a [`TextEditor`] to change its selection or text.
+pub enum TextEditorEvent {
+    /// Selects the given byte range of the document and scrolls it into view.
+    Select(Range<usize>),
+    /// Highlights the given byte ranges of the document, such as the results of a search.
+    ///
+    /// The ranges must be sorted and are cleared when the document is edited.
+    SetMatches(Vec<Range<usize>>),
+    /// Replaces each of the given byte ranges with its paired text. The ranges must be sorted and must
+    /// not overlap.
+    Replace(Vec<(Range<usize>, String)>),
+}
+
+impl<L> TextEditor<L>
+where
+    L: Lens<Target = Rope>,
+{
+    /// Creates a new text editor for the document bound by the lens.
+    pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
+        let caret_timer = cx.environment().caret_timer;
+
+        Self {
+            p: PhantomData,
+            edit: false,
+            show_caret: true,
+            caret_timer,
+            selection: Selection::caret(0),
+            scroll: Cell::new((0.0, 0.0)),
+            follow_caret: Cell::new(false),
+            indent: None,
+            highlight_current_line: false,
+            match_brackets: false,
+            matches: Vec::new(),
+            on_edit: None,
+        }
+        .build(cx, |cx| {
+            // The tab key moves the focus before the event reaches the text editor, so it is
+            // handled by a listener when the text editor indents with the tab key.
+            cx.add_listener(|editor: &mut Self, cx, event| {
+                event.map(|window_event, meta| {
+                    if let WindowEvent::KeyDown(Code::Tab, _) = window_event {
+                        if editor.edit
+                            && editor.indent.is_some()
+                            && !cx.is_read_only()
+                            && (cx.modifiers.is_empty() || *cx.modifiers == Modifiers::SHIFT)
+                        {
+                            let outdent = cx.modifiers.shift();
+                            editor.reset_caret_timer(cx);
+                            if editor.indent(cx, outdent) {
+                                editor.emit_edit(cx);
+                            }
+                            meta.consume();
+                        }
+                    }
+                });
+            });
+
+            // The highlighted tokens of the document are styled by hidden text spans with the class
+            // of their kind.
+            for kind in TokenKind::ALL {
+                TextSpan::new(cx, "", |_| {}).class(kind.class());
+            }
+        })
+        .navigable(true)
+        .role(Role::MultilineTextInput)
+        .toggle_class("caret", Self::show_caret)
+        .bind(lens, |mut handle, lens| {
+            let text = lens.get(&handle);
+            let entity = handle.entity;
+            let len = text.len_bytes();
+
+            let documents = &mut handle.cx.text_context.text_documents;
+            if let Some(document) = documents.get_mut(entity) {
+                // The bound data is usually updated with the rope produced by an edit, which is a
+                // clone of the rope the document already contains.
+                if document.text().is_instance(&text) {
+                    return;
+                }
+
+                document.set_text(text);
+            } else {
+                documents.insert(entity, TextDocument::new(text));
+            }
+
+            handle.cx.style.needs_text_update(entity);
+            handle.modify(|editor| {
+                editor.selection = Selection::caret(editor.selection.active.min(len));
+                // The highlighted ranges refer to the previous text.
+                editor.matches.clear();
+            });
+        })
+    }
+
+    fn insert_text(&mut self, cx: &mut EventContext, txt: &str) {
+        if let Some(document) = cx.text_context.text_documents.get_mut(cx.current) {
+            let range = self.selection.range();
+            document.edit(range.clone(), txt);
+            self.selection = Selection::caret(range.start + txt.len());
+            self.matches.clear();
+            self.follow_caret.set(true);
+            cx.style.needs_text_update(cx.current);
+        }
+    }
+
+    /// Returns the text inserted by the enter key, which keeps the indentation of the current line
+    /// and indents further after an opening bracket.
+    fn newline(&self, cx: &EventContext) -> String {
+        let mut text = String::from("\n");
+        let (Some(indent), Some(document)) =
+            (self.indent, cx.text_context.text_documents.get(cx.current))
+        else {
+            return text;
+        };
+
+        let offset = self.selection.min();
+        let start = document.line_range(document.line_at_offset(offset)).start;
+        let before = document.text().byte_slice(start..offset).to_string();
+        text.extend(before.chars().take_while(|c| *c == ' ' || *c == '\t'));
+        if before.trim_end().ends_with(['(', '[', '{']) {
+            text.push_str(&indent.text());
+        }
+
+        text
+    }
+
+    /// Indents or outdents the selected lines, returning `true` if the text was changed.
+    ///
+    /// Indenting a selection within a single line replaces the selection with an indent instead.
+    fn indent(&mut self, cx: &mut EventContext, outdent: bool) -> bool {
+        let Some(indent) = self.indent else {
+            return false;
+        };
+        let Some(document) = cx.text_context.text_documents.get_mut(cx.current) else {
+            return false;
+        };
+
+        let start_line = document.line_at_offset(self.selection.min());
+        let mut end_line = document.line_at_offset(self.selection.max());
+        if !outdent && start_line == end_line {
+            let range = self.selection.range();
+            let line_start = document.line_range(start_line).start;
+            let column = document.text().byte_slice(line_start..range.start).len_chars();
+            let text = indent.text_at(column);
+            document.edit(range.clone(), &text);
+            self.selection = Selection::caret(range.start + text.len());
+        } else {
+            // A selection which ends at the start of a line does not include that line.
+            if end_line > start_line && document.line_range(end_line).start == self.selection.max()
+            {
+                end_line -= 1;
+            }
+
+            let text = if outdent { String::new() } else { indent.text() };
+            let mut changed = false;
+            for line in (start_line..=end_line).rev() {
+                let start = document.line_range(line).start;
+                let end = if outdent {
+                    start + indent.leading_len(&document.line_text(line))
+                } else {
+                    start
+                };
+                if start == end && text.is_empty() {
+                    continue;
+                }
+
+                document.edit(start..end, &text);
+                self.selection.anchor = shift_offset(self.selection.anchor, start..end, text.len());
+                self.selection.active = shift_offset(self.selection.active, start..end, text.len());
+                changed = true;
+            }
+
+            if !changed {
+                return false;
+            }
+
+            self.selection.h_pos = None;
+        }
+
+        self.matches.clear();
+        self.follow_caret.set(true);
+        cx.style.needs_text_update(cx.current);
+        true
+    }
+
+    /// Replaces each of the sorted, non-overlapping ranges with its paired text, leaving the caret
+    /// after the first replacement.
+    fn replace(&mut self, cx: &mut EventContext, replacements: &[(Range<usize>, String)]) -> bool {
+        let Some(document) = cx.text_context.text_documents.get_mut(cx.current) else {
+            return false;
+        };
+
+        let Some((first, text)) = replacements.first() else {
+            return false;
+        };
+
+        // Ranges found in a previous version of the text may no longer be within the document.
+        let len = document.text().len_bytes();
+        if replacements.iter().any(|(range, _)| range.start > range.end || range.end > len) {
+            return false;
+        }
+        let caret = first.start + text.len();
+
+        for (range, text) in replacements.iter().rev() {
+            document.edit(range.clone(), text);
+        }
+
+        self.selection = Selection::caret(caret);
+        self.matches.clear();
+        self.follow_caret.set(true);
+        cx.style.needs_text_update(cx.current);
+        true
+    }
+
+    /// Deletes the selected text, or the text covered by the movement if nothing is selected,
+    /// returning `true` if any text was deleted.
+    fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) -> bool {
+        let page_height = self.page_height(cx);
+        let Some(document) = cx.text_context.text_documents.get_mut(cx.current) else {
+            return false;
+        };
+
+        let range = if !self.selection.is_caret() {
+            self.selection.range()
+        } else if movement == Movement::Grapheme(Direction::Upstream) {
+            offset_for_delete_backwards(&self.selection, document.text())..self.selection.active
+        } else {
+            apply_document_movement(movement, self.selection, document, page_height, true).range()
+        };
+
+        if range.is_empty() {
+            return false;
+        }
+
+        document.edit(range.clone(), "");
+        self.selection = Selection::caret(range.start);
+        self.matches.clear();
+        self.follow_caret.set(true);
+        cx.style.needs_text_update(cx.current);
+        true
+    }
+
+    fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        let page_height = self.page_height(cx);
+        if let Some(document) = cx.text_context.text_documents.get(cx.current) {
+            self.selection =
+                apply_document_movement(movement, self.selection, document, page_height, selection);
+            self.follow_caret.set(true);
+            cx.needs_redraw();
+        }
+    }
+
+    fn select_all(&mut self, cx: &mut EventContext) {
+        if let Some(document) = cx.text_context.text_documents.get(cx.current) {
+            self.selection = Selection::new(0, document.text().len_bytes());
+            cx.needs_redraw();
+        }
+    }
+
+    fn select_word(&mut self, cx: &mut EventContext) {
+        self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
+        self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
+    }
+
+    fn select_paragraph(&mut self, cx: &mut EventContext) {
+        self.move_cursor(cx, Movement::ParagraphStart, false);
+        self.move_cursor(cx, Movement::ParagraphEnd, true);
+    }
+
+    /// Returns the bounds of the visible region of the document, in physical pixels.
+    fn text_bounds(&self, cx: &EventContext) -> BoundingBox {
+        cx.text_context
+            .text_documents
+            .get(cx.current)
+            .map(|document| document_bounds(cx.style, cx.current, cx.bounds(), document))
+            .unwrap_or_default()
+    }
+
+    /// Returns the height of the visible region of the document, in physical pixels.
+    fn page_height(&self, cx: &EventContext) -> f32 {
+        self.text_bounds(cx).height()
+    }
+
+    /// Converts window-global physical coordinates to coordinates relative to the top-left corner of the document.
+    fn coordinates_global_to_document(&self, cx: &EventContext, x: f32, y: f32) -> (f32, f32) {
+        let bounds = self.text_bounds(cx);
+        let (scroll_x, scroll_y) = self.scroll.get();
+        (x - bounds.x + scroll_x, y - bounds.y + scroll_y)
+    }
+
+    /// This function takes window-global physical coordinates.
+    fn hit(&mut self, cx: &mut EventContext, x: f32, y: f32, selection: bool) {
+        let (x, y) = self.coordinates_global_to_document(cx, x, y);
+        if let Some(document) = cx.text_context.text_documents.get(cx.current) {
+            let offset = document.hit_test(x, y);
+            if selection {
+                self.selection.active = offset;
+            } else {
+                self.selection = Selection::caret(offset);
+            }
+
+            self.follow_caret.set(true);
+            cx.needs_redraw();
+        }
+    }
+
+    /// This function takes window-global physical coordinates.
+    fn drag(&mut self, cx: &mut EventContext, x: f32, y: f32) {
+        self.hit(cx, x, y, true);
+    }
+
+    /// Scrolls the document by the given physical distance, returning `true` if the scroll position changed.
+    fn scroll_by(&mut self, cx: &mut EventContext, x: f32, y: f32) -> bool {
+        let bounds = self.text_bounds(cx);
+        if let Some(document) = cx.text_context.text_documents.get_mut(cx.current) {
+            let max_x = (document.content_width() - bounds.width()).max(0.0);
+            let max_y = (document.height() - bounds.height()).max(0.0);
+            let (scroll_x, scroll_y) = self.scroll.get();
+            let new_scroll = ((scroll_x + x).clamp(0.0, max_x), (scroll_y + y).clamp(0.0, max_y));
+            if new_scroll != (scroll_x, scroll_y) {
+                self.scroll.set(new_scroll);
+                document.set_scroll_y(new_scroll.1);
+                cx.style.needs_text_layout(cx.current);
+                cx.needs_redraw();
+                return true;
+            }
+        }
+
+        false
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn clone_selected(&self, cx: &mut EventContext) -> Option<String> {
+        cx.text_context
+            .text_documents
+            .get(cx.current)
+            .and_then(|document| EditableText::slice(document.text(), self.selection.range()))
+            .map(|text| text.into_owned())
+    }
+
+    fn emit_edit(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_edit {
+            if let Some(document) = cx.text_context.text_documents.get(cx.current) {
+                let text = document.text().clone();
+                (callback)(cx, text);
+            }
+        }
+    }
+
+    fn reset_caret_timer(&mut self, cx: &mut EventContext) {
+        cx.stop_timer(self.caret_timer);
+        if !cx.is_read_only() {
+            self.show_caret = true;
+            cx.start_timer(self.caret_timer);
+        }
+    }
+}
+
+/// Computes the result of a [`Movement`] on a [`Selection`] within a document.
+///
+/// If `modify` is true, only the active end of the selection is moved.
+fn apply_document_movement(
+    movement: Movement,
+    s: Selection,
+    document: &TextDocument,
+    page_height: f32,
+    modify: bool,
+) -> Selection {
+    let text = document.text();
+
+    let (offset, h_pos) = match movement {
+        Movement::Grapheme(Direction::Left | Direction::Upstream) => {
+            if s.is_caret() || modify {
+                (text.prev_grapheme_offset(s.active).unwrap_or(0), None)
+            } else {
+                (s.min(), None)
+            }
+        }
+        Movement::Grapheme(_) => {
+            if s.is_caret() || modify {
+                (text.next_grapheme_offset(s.active).unwrap_or(s.active), None)
+            } else {
+                (s.max(), None)
+            }
+        }
+        Movement::Word(Direction::Left | Direction::Upstream) => {
+            if s.is_caret() || modify {
+                (text.prev_word_offset(s.active).unwrap_or(0), None)
+            } else {
+                (s.min(), None)
+            }
+        }
+        Movement::Word(_) => {
+            if s.is_caret() || modify {
+                (text.next_word_offset(s.active).unwrap_or(s.active), None)
+            } else {
+                (s.max(), None)
+            }
+        }
+        Movement::LineStart => (document.visual_line_range(s.active).start, None),
+        Movement::LineEnd => (document.visual_line_range(s.active).end, None),
+        Movement::ParagraphStart => (text.preceding_line_break(s.active), None),
+        Movement::ParagraphEnd => (text.next_line_break(s.active), None),
+        Movement::Body(Direction::Left | Direction::Upstream)
+        | Movement::Vertical(VerticalMovement::DocumentStart) => (0, None),
+        Movement::Body(_) | Movement::Vertical(VerticalMovement::DocumentEnd) => {
+            (text.len_bytes(), None)
+        }
+        Movement::Line(direction) | Movement::Page(direction) => {
+            let up = matches!(direction, Direction::Left | Direction::Upstream);
+            let distance = if matches!(movement, Movement::Page(_)) { page_height } else { 0.0 };
+            move_vertically(document, s, up, distance)
+        }
+        Movement::Vertical(vertical) => {
+            let up = matches!(vertical, VerticalMovement::LineUp | VerticalMovement::PageUp);
+            let distance =
+                if matches!(vertical, VerticalMovement::PageUp | VerticalMovement::PageDown) {
+                    page_height
+                } else {
+                    0.0
+                };
+            move_vertically(document, s, up, distance)
+        }
+    };
+
+    let start = if modify { s.anchor } else { offset };
+    Selection::new(start, offset).with_h_pos(h_pos)
+}
+
+/// Returns the position of an offset after the given range is replaced with text of the given length.
+fn shift_offset(offset: usize, range: Range<usize>, len: usize) -> usize {
+    if offset >= range.end {
+        offset - range.len() + len
+    } else if offset > range.start {
+        range.start
+    } else {
+        offset
+    }
+}
+
+/// The maximum number of characters searched for the bracket matching the bracket at the caret.
+const MAX_BRACKET_DISTANCE: usize = 10_000;
+
+/// Returns the byte offsets of the bracket at the given offset, or else before it, and of its matching bracket.
+fn matching_brackets(text: &Rope, offset: usize) -> Option<(usize, usize)> {
+    let index = text.byte_to_char(offset);
+    for index in std::iter::once(index).chain(index.checked_sub(1)) {
+        let Some(bracket) = text.get_char(index) else {
+            continue;
+        };
+
+        let (partner, forward) = match bracket {
+            '(' => (')', true),
+            '[' => (']', true),
+            '{' => ('}', true),
+            ')' => ('(', false),
+            ']' => ('[', false),
+            '}' => ('{', false),
+            _ => continue,
+        };
+
+        let chars =
+            if forward { text.chars_at(index) } else { text.chars_at(index + 1).reversed() };
+        let mut depth = 0;
+        for (distance, c) in chars.enumerate().take(MAX_BRACKET_DISTANCE) {
+            if c == bracket {
+                depth += 1;
+            } else if c == partner {
+                depth -= 1;
+                if depth == 0 {
+                    let other = if forward { index + distance } else { index - distance };
+                    return Some((text.char_to_byte(index), text.char_to_byte(other)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Moves the active end of the selection up or down by the given distance, or by a single visual line
+/// if the distance is zero, keeping the caret at the same horizontal position.
+fn move_vertically(
+    document: &TextDocument,
+    s: Selection,
+    up: bool,
+    distance: f32,
+) -> (usize, Option<f32>) {
+    let h_pos = s.h_pos.unwrap_or(document.caret_bounds(s.active).x);
+    let (top, height) = document.visual_line_bounds(s.active);
+    let y = match (up, distance > 0.0) {
+        (true, false) => top - 1.0,
+        (false, false) => top + height + 1.0,
+        (true, true) => top + height / 2.0 - distance,
+        (false, true) => top + height / 2.0 + distance,
+    };
+
+    if y < 0.0 {
+        (0, Some(h_pos))
+    } else if y >= document.height() {
+        (document.text().len_bytes(), Some(h_pos))
+    } else {
+        (document.hit_test(h_pos, y), Some(h_pos))
+    }
+}
+
+impl<L: Lens> Handle<'_, TextEditor<L>> {
+    /// Sets the callback triggered when the text editor is edited, i.e. text is inserted/deleted.
+    ///
+    /// Callback provides the edited document.
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Rope) + Send + Sync,
+    {
+        self.modify(|editor: &mut TextEditor<L>| editor.on_edit = Some(Box::new(callback)))
+    }
+
+    /// Sets the indentation inserted by the tab key, which also enables indenting the selected lines
+    /// with the tab key and outdenting them with shift+tab, and keeping the indentation of the current
+    /// line when the enter key is pressed.
+    ///
+    /// By default the tab key moves the focus to the next view.
+    pub fn indent(self, indent: Indent) -> Self {
+        self.modify(|editor: &mut TextEditor<L>| editor.indent = Some(indent))
+    }
+
+    /// Sets whether the line numbers of the document are shown to the left of the text.
+    pub fn line_numbers(self, line_numbers: bool) -> Self {
+        let entity = self.entity;
+        if let Some(document) = self.cx.text_context.text_documents.get_mut(entity) {
+            document.set_line_numbers(line_numbers);
+            self.cx.style.needs_text_update(entity);
+        }
+
+        self
+    }
+
+    /// Sets the highlighter used to style the text of the document, such as to highlight the syntax of
+    /// source code.
+    pub fn highlighter(self, highlighter: impl Highlighter) -> Self {
+        let entity = self.entity;
+        if let Some(document) = self.cx.text_context.text_documents.get_mut(entity) {
+            document.set_highlighter(Some(Box::new(highlighter)));
+            self.cx.style.needs_text_update(entity);
+        }
+
+        self
+    }
+
+    /// Sets whether the line containing the caret is highlighted while editing.
+    pub fn highlight_current_line(self, highlight_current_line: bool) -> Self {
+        self.modify(|editor: &mut TextEditor<L>| {
+            editor.highlight_current_line = highlight_current_line
+        })
+    }
+
+    /// Sets whether the bracket next to the caret and its matching bracket are highlighted while editing.
+    pub fn match_brackets(self, match_brackets: bool) -> Self {
+        self.modify(|editor: &mut TextEditor<L>| editor.match_brackets = match_brackets)
+    }
+}
+
+impl<L> View for TextEditor<L>
+where
+    L: Lens<Target = Rope>,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("text-editor")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_over() && !cx.is_disabled() {
+                    cx.focus_with_visibility(false);
+                    cx.capture();
+                    cx.lock_cursor_icon();
+                    if !self.edit {
+                        cx.emit(TextEvent::StartEdit);
+                    }
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::Hit(
+                        cx.mouse.cursor_x,
+                        cx.mouse.cursor_y,
+                        cx.modifiers.shift(),
+                    ));
+                }
+            }
+
+            WindowEvent::FocusIn => {
+                cx.emit(TextEvent::StartEdit);
+            }
+
+            WindowEvent::FocusOut => {
+                cx.emit(TextEvent::EndEdit);
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                cx.emit(TextEvent::SelectWord);
+            }
+
+            WindowEvent::MouseTripleClick(MouseButton::Left) => {
+                cx.emit(TextEvent::SelectParagraph);
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                self.reset_caret_timer(cx);
+                cx.unlock_cursor_icon();
+                cx.release();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if cx.mouse.left.state == MouseButtonState::Pressed
+                    && cx.mouse.left.pressed == cx.current
+                    && (cx.mouse.left.pos_down.0 != *x || cx.mouse.left.pos_down.1 != *y)
+                {
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::Drag(cx.mouse.cursor_x, cx.mouse.cursor_y));
+                }
+            }
+
+            WindowEvent::MouseScroll(x, y) => {
+                let (x, y) = if cx.modifiers.shift() { (-*y, -*x) } else { (-*x, -*y) };
+                let scale = SCROLL_SENSITIVITY * cx.scale_factor();
+                if self.scroll_by(cx, x * scale, y * scale) {
+                    // Prevent event propagating to ancestor scrollviews.
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::CharInput(c) => {
+                if *c != '\u{1b}' && // Escape
+                    *c != '\u{8}' && // Backspace
+                    *c != '\u{9}' && // Tab
+                    *c != '\u{7f}' && // Delete
+                    *c != '\u{0d}' && // Carriage return
+                    !cx.modifiers.ctrl() &&
+                    !cx.modifiers.logo() &&
+                    self.edit &&
+                    !cx.is_read_only()
+                {
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::InsertText(String::from(*c)));
+                }
+            }
+
+            WindowEvent::KeyDown(code, _) if self.edit => match code {
+                Code::Enter | Code::NumpadEnter => {
+                    if !cx.is_read_only() {
+                        self.reset_caret_timer(cx);
+                        cx.emit(TextEvent::InsertText(self.newline(cx)));
+                    }
+                }
+
+                Code::ArrowLeft | Code::ArrowRight => {
+                    self.reset_caret_timer(cx);
+                    let direction =
+                        if *code == Code::ArrowLeft { Direction::Left } else { Direction::Right };
+                    let movement = if cx.modifiers.ctrl() {
+                        Movement::Word(direction)
+                    } else {
+                        Movement::Grapheme(direction)
+                    };
+
+                    cx.emit(TextEvent::MoveCursor(movement, cx.modifiers.shift()));
+                }
+
+                Code::ArrowUp | Code::ArrowDown => {
+                    self.reset_caret_timer(cx);
+                    let movement = if *code == Code::ArrowUp {
+                        VerticalMovement::LineUp
+                    } else {
+                        VerticalMovement::LineDown
+                    };
+
+                    cx.emit(TextEvent::MoveCursor(
+                        Movement::Vertical(movement),
+                        cx.modifiers.shift(),
+                    ));
+                }
+
+                Code::Backspace | Code::Delete => {
+                    self.reset_caret_timer(cx);
+                    if !cx.is_read_only() {
+                        let direction = if *code == Code::Backspace {
+                            Direction::Upstream
+                        } else {
+                            Direction::Downstream
+                        };
+                        let movement = if cx.modifiers.ctrl() {
+                            Movement::Word(direction)
+                        } else {
+                            Movement::Grapheme(direction)
+                        };
+
+                        cx.emit(TextEvent::DeleteText(movement));
+                    }
+                }
+
+                Code::Home | Code::End => {
+                    self.reset_caret_timer(cx);
+                    let movement = match (*code == Code::Home, cx.modifiers.ctrl()) {
+                        (true, false) => Movement::LineStart,
+                        (false, false) => Movement::LineEnd,
+                        (true, true) => Movement::Vertical(VerticalMovement::DocumentStart),
+                        (false, true) => Movement::Vertical(VerticalMovement::DocumentEnd),
+                    };
+
+                    cx.emit(TextEvent::MoveCursor(movement, cx.modifiers.shift()));
+                }
+
+                Code::PageUp | Code::PageDown => {
+                    self.reset_caret_timer(cx);
+                    let direction = if *code == Code::PageUp {
+                        Direction::Upstream
+                    } else {
+                        Direction::Downstream
+                    };
+                    cx.emit(TextEvent::MoveCursor(
+                        if cx.modifiers.ctrl() {
+                            Movement::Body(direction)
+                        } else {
+                            Movement::Page(direction)
+                        },
+                        cx.modifiers.shift(),
+                    ));
+                }
+
+                Code::KeyA | Code::KeyC | Code::KeyV | Code::KeyX => {
+                    #[cfg(target_os = "macos")]
+                    let modifier = Modifiers::SUPER;
+                    #[cfg(not(target_os = "macos"))]
+                    let modifier = Modifiers::CTRL;
+
+                    if cx.modifiers == &modifier {
+                        match code {
+                            Code::KeyA => cx.emit(TextEvent::SelectAll),
+                            Code::KeyC => cx.emit(TextEvent::Copy),
+                            Code::KeyV => cx.emit(TextEvent::Paste),
+                            _ if !cx.is_read_only() => cx.emit(TextEvent::Cut),
+                            _ => {}
+                        }
+                    }
+                }
+
+                _ => {}
+            },
+
+            _ => {}
+        });
+
+        event.map(|text_event, _| match text_event {
+            TextEvent::InsertText(text) => {
+                if self.edit && !cx.is_read_only() {
+                    self.insert_text(cx, text);
+                    self.emit_edit(cx);
+                }
+            }
+
+            TextEvent::DeleteText(movement) => {
+                if self.edit && !cx.is_read_only() && self.delete_text(cx, *movement) {
+                    self.emit_edit(cx);
+                }
+            }
+
+            TextEvent::MoveCursor(movement, selection) => {
+                if self.edit {
+                    self.move_cursor(cx, *movement, *selection);
+                }
+            }
+
+            TextEvent::SelectAll => self.select_all(cx),
+
+            TextEvent::SelectWord => self.select_word(cx),
+
+            TextEvent::SelectParagraph => self.select_paragraph(cx),
+
+            TextEvent::StartEdit => {
+                if !cx.is_disabled() && !self.edit {
+                    self.edit = true;
+                    cx.focus_with_visibility(false);
+                    cx.set_checked(true);
+                    self.reset_caret_timer(cx);
+                }
+            }
+
+            TextEvent::EndEdit => {
+                self.edit = false;
+                self.selection = Selection::caret(self.selection.active);
+                cx.set_checked(false);
+                cx.release();
+                cx.stop_timer(self.caret_timer);
+            }
+
+            TextEvent::Hit(x, y, selection) => self.hit(cx, *x, *y, *selection),
+
+            TextEvent::Drag(x, y) => self.drag(cx, *x, *y),
+
+            TextEvent::Scroll(x, y) => {
+                self.scroll_by(cx, *x, *y);
+            }
+
+            TextEvent::Copy =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit {
+                    if let Some(selected_text) = self.clone_selected(cx) {
+                        if !selected_text.is_empty() {
+                            cx.set_clipboard(selected_text)
+                                .expect("Failed to add text to clipboard");
+                        }
+                    }
+                }
+            }
+
+            TextEvent::Paste =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit {
+                    if let Ok(text) = cx.get_clipboard() {
+                        cx.emit(TextEvent::InsertText(text));
+                    }
+                }
+            }
+
+            TextEvent::Cut =>
+            {
+                #[cfg(feature = "clipboard")]
+                if self.edit {
+                    if let Some(selected_text) = self.clone_selected(cx) {
+                        if !selected_text.is_empty() {
+                            cx.set_clipboard(selected_text)
+                                .expect("Failed to add text to clipboard");
+                            cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Upstream)));
+                        }
+                    }
+                }
+            }
+
+            TextEvent::ToggleCaret => {
+                self.show_caret ^= true;
+            }
+
+            _ => {}
+        });
+
+        event.map(|text_editor_event, _| match text_editor_event {
+            TextEditorEvent::Select(range) => {
+                if let Some(document) = cx.text_context.text_documents.get(cx.current) {
+                    let len = document.text().len_bytes();
+                    self.selection = Selection::new(range.start.min(len), range.end.min(len));
+                    self.follow_caret.set(true);
+                    cx.needs_redraw();
+                }
+            }
+
+            TextEditorEvent::SetMatches(matches) => {
+                self.matches = matches.clone();
+                cx.needs_redraw();
+            }
+
+            TextEditorEvent::Replace(replacements) => {
+                if !cx.is_read_only() && self.replace(cx, replacements) {
+                    self.emit_edit(cx);
+                }
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        cx.draw_shadows(canvas);
+        cx.draw_background(canvas);
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+
+        let entity = cx.current;
+        let bounds = cx.bounds();
+        let font_size = cx.font_size();
+        let font_color = cx.font_color();
+        let selection_color = cx.selection_color();
+        let selection_text_color = cx.selection_text_color();
+        let caret_color = cx.caret_color();
+
+        let TextContext { font_collection, text_documents, .. } = &mut *cx.text_context;
+        let Some(document) = text_documents.get_mut(entity) else {
+            return;
+        };
+
+        // Scrolling to the caret can bring lines which have not been laid out into view, which
+        // can in turn move the caret, so the caret is scrolled into view a second time.
+        if self.follow_caret.take() {
+            for _ in 0..2 {
+                let document_bounds = document_bounds(cx.style, entity, bounds, document);
+                let caret = document.caret_bounds(self.selection.active);
+                let caret_box = BoundingBox {
+                    x: document_bounds.x + caret.x,
+                    y: document_bounds.y + caret.y,
+                    w: 1.0,
+                    h: caret.h,
+                };
+                let (scroll_x, scroll_y) = self.scroll.get();
+                let (tx, ty) = ensure_visible(&caret_box, &document_bounds, (-scroll_x, -scroll_y));
+                self.scroll.set((-tx, -ty));
+                document.set_scroll_y(-ty);
+                layout_document(cx.style, cx.tree, font_collection, entity, document, bounds);
+            }
+        }
+
+        let document_bounds = document_bounds(cx.style, entity, bounds, document);
+        let (scroll_x, scroll_y) = self.scroll.get();
+        let origin_x = document_bounds.x - scroll_x;
+        let origin_y = document_bounds.y - scroll_y;
+        let visible_lines = document.lines_between(scroll_y, scroll_y + document_bounds.height());
+        let visible_start = document.line_range(visible_lines.start).start;
+        let visible_end = document.line_range(visible_lines.end - 1).end;
+        let caret_line = document.line_at_offset(self.selection.active);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+
+        if document.line_numbers() {
+            let gutter_width = document.gutter_width();
+            let gutter_x = document_bounds.x - gutter_width;
+            canvas.save();
+            canvas.clip_rect(
+                Rect::new(
+                    gutter_x,
+                    document_bounds.top(),
+                    document_bounds.x,
+                    document_bounds.bottom(),
+                ),
+                ClipOp::Intersect,
+                true,
+            );
+
+            let dimmed =
+                Color::rgba(font_color.r(), font_color.g(), font_color.b(), font_color.a() / 2);
+            for line in visible_lines.clone() {
+                let current = self.edit && line == caret_line;
+                let (top, _) = document.line_bounds(line);
+                let number = document.line_number_paragraph(line, current, || {
+                    build_line_number(
+                        cx.style,
+                        font_collection,
+                        entity,
+                        line + 1,
+                        gutter_width - font_size,
+                        if current { font_color } else { dimmed },
+                    )
+                });
+                number.paint(canvas, (gutter_x, origin_y + top));
+            }
+            document.retain_line_numbers(visible_lines.clone());
+
+            canvas.restore();
+        }
+
+        canvas.save();
+        canvas.clip_rect(
+            Rect::new(
+                document_bounds.left(),
+                document_bounds.top(),
+                document_bounds.right(),
+                document_bounds.bottom(),
+            ),
+            ClipOp::Intersect,
+            true,
+        );
+
+        if self.edit && self.highlight_current_line {
+            let (top, height) = document.line_bounds(caret_line);
+            paint.set_color(Color::rgba(
+                font_color.r(),
+                font_color.g(),
+                font_color.b(),
+                font_color.a() / 16,
+            ));
+            canvas.draw_rect(
+                Rect::from_xywh(document_bounds.x, origin_y + top, document_bounds.w, height),
+                &paint,
+            );
+        }
+
+        let first_match = self.matches.partition_point(|range| range.end < visible_start);
+        let visible_matches =
+            self.matches[first_match..].iter().take_while(|range| range.start <= visible_end);
+        paint.set_color(Color::rgba(
+            selection_color.r(),
+            selection_color.g(),
+            selection_color.b(),
+            selection_color.a() / 2,
+        ));
+        for range in visible_matches {
+            for rect in document.selection_bounds(range.clone()) {
+                canvas.draw_rect(
+                    Rect::from_xywh(origin_x + rect.x, origin_y + rect.y, rect.w, rect.h),
+                    &paint,
+                );
+            }
+        }
+
+        let mut selection_rects = Vec::new();
+        if !self.selection.is_caret() {
+            let start = self.selection.min().max(visible_start);
+            let end = self.selection.max().min(visible_end);
+            if start < end {
+                paint.set_color(selection_color);
+                for rect in document.selection_bounds(start..end) {
+                    let rect =
+                        Rect::from_xywh(origin_x + rect.x, origin_y + rect.y, rect.w, rect.h);
+                    canvas.draw_rect(rect, &paint);
+                    selection_rects.push(rect);
+                }
+            }
+        }
+
+        if self.edit && self.match_brackets {
+            if let Some((bracket, other)) =
+                matching_brackets(document.text(), self.selection.active)
+            {
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(1.0);
+                paint.set_color(Color::rgba(
+                    font_color.r(),
+                    font_color.g(),
+                    font_color.b(),
+                    font_color.a() / 2,
+                ));
+                for offset in [bracket, other] {
+                    // Brackets are a single byte long.
+                    for rect in document.selection_bounds(offset..offset + 1) {
+                        canvas.draw_rect(
+                            Rect::from_xywh(
+                                origin_x + rect.x + 0.5,
+                                origin_y + rect.y + 0.5,
+                                rect.w - 1.0,
+                                rect.h - 1.0,
+                            ),
+                            &paint,
+                        );
+                    }
+                }
+                paint.set_style(PaintStyle::Fill);
+            }
+        }
+
+        for line in visible_lines {
+            if let Some(paragraph) = document.paragraph(line) {
+                let (top, _) = document.line_bounds(line);
+                paragraph.paint(canvas, (origin_x, origin_y + top));
+                if let Some(color) = selection_text_color {
+                    let origin = (origin_x, origin_y + top);
+                    paint_selected_text(canvas, paragraph, origin, &selection_rects, color);
+                }
+            }
+        }
+
+        if self.edit {
+            let caret = document.caret_bounds(self.selection.active);
+            let x = (origin_x + caret.x).round();
+            let y = (origin_y + caret.y).round();
+            paint.set_color(caret_color);
+            canvas.draw_rect(Rect::from_xywh(x, y, 1.0, caret.h), &paint);
+        }
+
+        canvas.restore();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_brackets_finds_partner() {
+        let text = Rope::from_str("f(a[b]c)");
+        assert_eq!(matching_brackets(&text, 1), Some((1, 7)));
+        assert_eq!(matching_brackets(&text, 8), Some((7, 1)));
+        assert_eq!(matching_brackets(&text, 4), Some((3, 5)));
+        // The bracket before the offset is matched when there is none at the offset.
+        assert_eq!(matching_brackets(&text, 2), Some((1, 7)));
+
+        assert_eq!(matching_brackets(&Rope::from_str("(("), 0), None);
+        assert_eq!(matching_brackets(&Rope::from_str("é(x)"), 2), Some((2, 4)));
+    }
+
+    #[test]
+    fn shift_offset_follows_replacement() {
+        assert_eq!(shift_offset(2, 5..8, 1), 2);
+        assert_eq!(shift_offset(10, 5..8, 1), 8);
+        assert_eq!(shift_offset(6, 5..8, 1), 5);
+        assert_eq!(shift_offset(5, 5..8, 4), 5);
+        assert_eq!(shift_offset(8, 5..8, 4), 9);
+    }
+
+    #[test]
+    fn indent_text_at_column() {
+        assert_eq!(Indent::Spaces(4).text_at(0), "    ");
+        assert_eq!(Indent::Spaces(4).text_at(1), "   ");
+        assert_eq!(Indent::Spaces(4).text_at(4), "    ");
+        assert_eq!(Indent::Spaces(0).text_at(3), " ");
+        assert_eq!(Indent::Tab.text_at(3), "\t");
+    }
+
+    #[test]
+    fn indent_leading_len() {
+        assert_eq!(Indent::Spaces(4).leading_len("      x"), 4);
+        assert_eq!(Indent::Spaces(4).leading_len("  x"), 2);
+        assert_eq!(Indent::Spaces(4).leading_len("\tx"), 1);
+        assert_eq!(Indent::Spaces(4).leading_len("x"), 0);
+        assert_eq!(Indent::Tab.leading_len("        x"), 4);
+    }
+}
@@ -1,9 +1,11 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::Arc;
 
 // use crate::accessibility::IntoNode;
 use crate::prelude::*;
 
+use crate::systems::paragraph_origin;
 use crate::text::{
     apply_movement, enforce_text_bounds, ensure_visible, offset_for_delete_backwards, Direction,
     EditableText, Movement, Selection, VerticalMovement,
@@ -54,6 +56,55 @@ pub enum TextEvent {
     Blur,
     /// Toggle the visibility of the text Caret.
     ToggleCaret,
+    /// Show or hide the text of a password textbox.
+    SetPasswordVisible(bool),
+}
+
+/// The character displayed in place of each character of a password.
+const PASSWORD_MASK: char = '\u{2022}';
+
+/// Determines which characters can be entered into a [`Textbox`].
+///
+/// Characters which are rejected by the filter are removed from typed and pasted text.
+#[derive(Clone)]
+pub enum InputFilter {
+    /// Only the ASCII digits `0` to `9` can be entered.
+    Digits,
+    /// Only hexadecimal digits can be entered.
+    HexDigits,
+    /// Only characters for which the predicate returns `true` can be entered.
+    Custom(Arc<dyn Fn(char) -> bool + Send + Sync>),
+}
+
+impl InputFilter {
+    /// Creates a filter which only allows characters for which the predicate returns `true`.
+    pub fn custom(predicate: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        InputFilter::Custom(Arc::new(predicate))
+    }
+
+    /// Returns `true` if the character can be entered.
+    pub fn accepts(&self, c: char) -> bool {
+        match self {
+            InputFilter::Digits => c.is_ascii_digit(),
+            InputFilter::HexDigits => c.is_ascii_hexdigit(),
+            InputFilter::Custom(predicate) => predicate(c),
+        }
+    }
+}
+
+/// Removes the characters rejected by the filter from the text, and truncates it to at most
+/// `max_length` graphemes.
+fn filter_text(txt: &str, filter: Option<&InputFilter>, max_length: Option<usize>) -> String {
+    let mut text = match filter {
+        Some(filter) => txt.chars().filter(|c| filter.accepts(*c)).collect(),
+        None => txt.to_owned(),
+    };
+
+    if let Some((index, _)) = max_length.and_then(|max| text.grapheme_indices(true).nth(max)) {
+        text.truncate(index);
+    }
+
+    text
 }
 
 /// The `Textbox` view provides an input control for editing a value as a string.
@@ -79,6 +130,10 @@ pub struct Textbox<L: Lens> {
     show_caret: bool,
     caret_timer: Timer,
     selection: Selection,
+    password: bool,
+    password_visible: bool,
+    max_length: Option<usize>,
+    filter: Option<InputFilter>,
 }
 
 // Determines whether the enter key submits the text or inserts a new line.
@@ -165,6 +220,10 @@ where
             show_caret: true,
             caret_timer,
             selection: Selection::new(0, 0),
+            password: false,
+            password_visible: false,
+            max_length: None,
+            filter: None,
         }
         .build(cx, move |cx| {
             cx.add_listener(move |textbox: &mut Self, cx, event| {
@@ -216,6 +275,49 @@ where
         })
     }
 
+    /// Removes the characters rejected by the input filter from the text to be inserted, and
+    /// truncates it so that the text does not exceed the maximum length once inserted.
+    fn filter_input(&self, cx: &EventContext, txt: &str) -> String {
+        let remaining = self.max_length.map(|max_length| {
+            let (length, selected) = match cx.style.text.get(cx.current) {
+                Some(text) if !self.show_placeholder => (
+                    text.graphemes(true).count(),
+                    text[self.selection.range()].graphemes(true).count(),
+                ),
+                _ => (0, 0),
+            };
+
+            max_length.saturating_sub(length - selected)
+        });
+
+        filter_text(txt, self.filter.as_ref(), remaining)
+    }
+
+    /// Returns the movement used in place of the given movement, as word boundaries within a
+    /// password are not revealed by moving between them.
+    fn password_movement(&self, movement: Movement) -> Movement {
+        match movement {
+            Movement::Word(Direction::Left | Direction::Upstream) if self.password => {
+                Movement::LineStart
+            }
+            Movement::Word(Direction::Right | Direction::Downstream) if self.password => {
+                Movement::LineEnd
+            }
+            _ => movement,
+        }
+    }
+
+    fn update_password_mask(&self, cx: &mut EventContext) {
+        if self.password && !self.password_visible {
+            cx.style.text_mask.insert(cx.current, PASSWORD_MASK);
+        } else {
+            cx.style.text_mask.remove(cx.current);
+        }
+
+        cx.style.needs_text_update(cx.current);
+        cx.style.needs_access_update(cx.current);
+    }
+
     fn insert_text(&mut self, cx: &mut EventContext, txt: &str) {
         if let Some(text) = cx.style.text.get_mut(cx.current) {
             if self.show_placeholder && !txt.is_empty() {
@@ -234,6 +336,8 @@ where
             return;
         }
 
+        let movement = self.password_movement(movement);
+
         if self.selection.is_caret() {
             if movement == Movement::Grapheme(Direction::Upstream) {
                 if self.selection.active == 0 {
@@ -284,6 +388,7 @@ where
     }
 
     fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        let movement = self.password_movement(movement);
         if let Some(text) = cx.style.text.get_mut(cx.current) {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 let new_selection =
@@ -309,6 +414,11 @@ where
         if self.show_placeholder {
             return;
         }
+
+        if self.password {
+            self.select_all(cx);
+            return;
+        }
         self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
         self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
     }
@@ -386,15 +496,8 @@ where
                 let y = y - self.transform.borrow().1;
                 let gp = paragraph
                     .get_glyph_position_at_coordinate(self.coordinates_global_to_text(cx, x, y));
-                let num_graphemes = text.graphemes(true).count();
-                let pos = (gp.position as usize).min(num_graphemes);
-                let mut cursor = text.len();
-                for (i, (j, _)) in text.grapheme_indices(true).enumerate() {
-                    if pos == i {
-                        cursor = j;
-                        break;
-                    }
-                }
+                let cursor =
+                    cx.text_context.text_offset_at(cx.current, text, gp.position.max(0) as usize);
 
                 if selection {
                     self.selection.active = cursor;
@@ -415,16 +518,8 @@ where
                 let y = y - self.transform.borrow().1;
                 let gp = paragraph
                     .get_glyph_position_at_coordinate(self.coordinates_global_to_text(cx, x, y));
-                let num_graphemes = text.graphemes(true).count();
-                let pos = (gp.position as usize).min(num_graphemes);
-
-                let mut cursor = text.len();
-                for (i, (j, _)) in text.grapheme_indices(true).enumerate() {
-                    if pos == i {
-                        cursor = j;
-                        break;
-                    }
-                }
+                let cursor =
+                    cx.text_context.text_offset_at(cx.current, text, gp.position.max(0) as usize);
 
                 self.selection.active = cursor;
 
@@ -470,8 +565,10 @@ where
         if !self.selection.is_caret() {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 if let Some(text) = cx.style.text.get(cx.current) {
-                    let min = text.current_grapheme_offset(self.selection.min());
-                    let max = text.current_grapheme_offset(self.selection.max());
+                    let min =
+                        cx.text_context.paragraph_index(cx.current, text, self.selection.min());
+                    let max =
+                        cx.text_context.paragraph_index(cx.current, text, self.selection.max());
 
                     let cursor_rects = paragraph.get_rects_for_range(
                         min..max,
@@ -479,6 +576,8 @@ where
                         RectWidthStyle::Tight,
                     );
 
+                    let mut selection_rects = Vec::with_capacity(cursor_rects.len());
+
                     for cursor_rect in cursor_rects {
                         let bounds = cx.bounds();
 
@@ -525,7 +624,11 @@ where
                         paint.set_color(cx.selection_color());
 
                         canvas.draw_rect(Rect::new(x, y, x2, y2), &paint);
+                        selection_rects.push(Rect::new(x, y, x2, y2));
                     }
+
+                    let origin = paragraph_origin(cx.style, cx.current, cx.bounds(), paragraph);
+                    cx.draw_selected_text(canvas, paragraph, origin, &selection_rects);
                 }
             }
         }
@@ -537,7 +640,8 @@ where
             if let Some(text) = cx.style.text.get(cx.current) {
                 let bounds = cx.bounds();
 
-                let current = text.current_grapheme_offset(self.selection.active);
+                let current =
+                    cx.text_context.paragraph_index(cx.current, text, self.selection.active);
 
                 let rects = paragraph.get_rects_for_range(
                     current..current + 1,
@@ -680,6 +784,54 @@ impl<L: Lens> Handle<'_, Textbox<L>> {
 
         self
     }
+
+    /// Sets whether the textbox is a password field.
+    ///
+    /// The text of a password field is displayed as a series of bullets, cannot be copied or cut
+    /// to the clipboard, and is reported to assistive technologies as protected.
+    pub fn password(self, flag: bool) -> Self {
+        let mut visible = false;
+        let handle = self.modify(|textbox| {
+            textbox.password = flag;
+            visible = textbox.password_visible;
+        });
+
+        if flag && !visible {
+            handle.cx.style.text_mask.insert(handle.entity, PASSWORD_MASK);
+        } else {
+            handle.cx.style.text_mask.remove(handle.entity);
+        }
+        handle.cx.style.needs_text_update(handle.entity);
+
+        handle
+            .role(if flag { Role::PasswordInput } else { Role::TextInput })
+            .toggle_class("password", flag)
+    }
+
+    /// Sets whether the text of a password field is revealed.
+    ///
+    /// Has no effect unless the textbox has been made a password field with [`password`](Self::password).
+    pub fn reveal_password(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, flag| {
+            let flag = flag.get(cx);
+            cx.emit(TextEvent::SetPasswordVisible(flag));
+        });
+
+        self
+    }
+
+    /// Sets the maximum number of characters which can be entered into the textbox.
+    ///
+    /// Typed and pasted text is truncated so that the limit is not exceeded. Bound text which is
+    /// longer than the limit is shown in full, so that the textbox always shows the bound value.
+    pub fn max_length(self, max_length: usize) -> Self {
+        self.modify(|textbox| textbox.max_length = Some(max_length))
+    }
+
+    /// Sets a filter which determines the characters that can be entered into the textbox.
+    pub fn input_filter(self, filter: InputFilter) -> Self {
+        self.modify(|textbox| textbox.filter = Some(filter))
+    }
 }
 
 impl<L> View for Textbox<L>
@@ -711,7 +863,11 @@ where
                 for line in line_metrics.iter() {
                     // We need a child node per line
                     let mut line_node = AccessNode::new_from_parent(node_id, line.line_number);
-                    line_node.set_role(Role::TextInput);
+                    line_node.set_role(if self.password {
+                        Role::PasswordInput
+                    } else {
+                        Role::TextInput
+                    });
                     line_node.set_bounds(BoundingBox {
                         x: line.left as f32,
                         y: (line.baseline - line.ascent) as f32,
@@ -1157,11 +1313,16 @@ where
         // Textbox Events
         event.map(|text_event, _| match text_event {
             TextEvent::InsertText(text) => {
+                let text = self.filter_input(cx, text);
+                if text.is_empty() {
+                    return;
+                }
+
                 if self.show_placeholder {
                     self.reset_text(cx);
                 }
 
-                self.insert_text(cx, text);
+                self.insert_text(cx, &text);
 
                 let text = self.clone_text(cx);
 
@@ -1333,7 +1494,7 @@ where
             TextEvent::Copy =>
             {
                 #[cfg(feature = "clipboard")]
-                if self.edit {
+                if self.edit && !self.password {
                     if let Some(selected_text) = self.clone_selected(cx) {
                         if !selected_text.is_empty() {
                             cx.set_clipboard(selected_text)
@@ -1356,7 +1517,7 @@ where
             TextEvent::Cut =>
             {
                 #[cfg(feature = "clipboard")]
-                if self.edit {
+                if self.edit && !self.password {
                     if let Some(selected_text) = self.clone_selected(cx) {
                         if !selected_text.is_empty() {
                             cx.set_clipboard(selected_text)
@@ -1386,6 +1547,11 @@ where
             TextEvent::ToggleCaret => {
                 self.show_caret ^= true;
             }
+
+            TextEvent::SetPasswordVisible(flag) => {
+                self.password_visible = *flag;
+                self.update_password_mask(cx);
+            }
         });
     }
 
@@ -1407,3 +1573,63 @@ where
         canvas.restore();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+
+    #[derive(Lens)]
+    struct AppData {
+        text: String,
+    }
+
+    impl Model for AppData {}
+
+    #[test]
+    fn input_filter_accepts() {
+        assert!(InputFilter::Digits.accepts('7'));
+        assert!(!InputFilter::Digits.accepts('a'));
+        assert!(!InputFilter::Digits.accepts('\u{0667}'));
+        assert!(InputFilter::HexDigits.accepts('F'));
+        assert!(!InputFilter::HexDigits.accepts('g'));
+        assert!(InputFilter::custom(char::is_uppercase).accepts('Q'));
+        assert!(!InputFilter::custom(char::is_uppercase).accepts('q'));
+    }
+
+    #[test]
+    fn filter_text_removes_rejected_characters() {
+        assert_eq!(filter_text("1a2-b3", Some(&InputFilter::Digits), None), "123");
+        assert_eq!(filter_text("1a2-b3", None, None), "1a2-b3");
+    }
+
+    #[test]
+    fn filter_text_truncates_graphemes() {
+        assert_eq!(filter_text("hello", None, Some(3)), "hel");
+        assert_eq!(filter_text("hi", None, Some(3)), "hi");
+        assert_eq!(filter_text("hello", None, Some(0)), "");
+        // A grapheme made of several characters counts once and is never split.
+        assert_eq!(filter_text("e\u{301}e\u{301}e\u{301}", None, Some(2)), "e\u{301}e\u{301}");
+        // The filter applies before the text is truncated.
+        assert_eq!(filter_text("a1b2c3d4", Some(&InputFilter::Digits), Some(3)), "123");
+    }
+
+    #[test]
+    fn max_length_only_limits_inserted_text() {
+        let cx = &mut Context::default();
+        AppData { text: String::from("Hello World") }.build(cx);
+        let entity = Textbox::new(cx, AppData::text).max_length(14).entity();
+
+        // Bound text is not truncated, so the textbox shows the same text as the model.
+        assert_eq!(cx.style.text.get(entity).map(String::as_str), Some("Hello World"));
+
+        cx.emit_to(entity, TextEvent::InsertText(String::from("12345")));
+        EventManager::new().flush_events(cx, |_| {});
+        assert_eq!(cx.style.text.get(entity).map(String::as_str), Some("123Hello World"));
+
+        // Once the limit is reached nothing more can be inserted.
+        cx.emit_to(entity, TextEvent::InsertText(String::from("4")));
+        EventManager::new().flush_events(cx, |_| {});
+        assert_eq!(cx.style.text.get(entity).map(String::as_str), Some("123Hello World"));
+    }
+}
@@ -121,6 +121,8 @@ pub enum WindowEvent {
     Redraw,
     /// Request a restyle.
     Restyle,
+    /// Emitted after the style of the given views has been recomputed.
+    StyleChanged(Vec<Entity>),
     /// Requests a relayout.
     Relayout,
     /// Move keyboard focus to the next navigable view.
@@ -59,11 +59,35 @@ pub(crate) fn parse_declaration<'i>(
     input: &mut cssparser::Parser<'i, '_>,
     declarations: &mut DeclarationList<'i>,
     important_declarations: &mut DeclarationList<'i>,
-    _options: &ParserOptions,
+    options: &ParserOptions<'i>,
 ) -> Result<(), ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+
     let property =
         input.parse_until_before(Delimiter::Bang, |input| Property::parse_value(name, input))?;
 
+    // Unparsed and unknown properties are kept in the declarations but reported as warnings.
+    match &property {
+        Property::Unparsed(unparsed) => {
+            let error = match Property::expected_value(&unparsed.name) {
+                Some(expected) => CustomParseError::InvalidPropertyValue {
+                    property: unparsed.name.clone(),
+                    expected,
+                },
+                None => CustomParseError::UnknownProperty(unparsed.name.clone()),
+            };
+            options.warn(location.new_custom_error(error));
+        }
+
+        Property::Custom(custom) if !custom.name.starts_with("--") => {
+            options.warn(
+                location.new_custom_error(CustomParseError::UnknownProperty(custom.name.clone())),
+            );
+        }
+
+        _ => {}
+    }
+
     let important = input
         .try_parse(|input| {
             input.expect_delim('!')?;
@@ -64,6 +64,36 @@ pub enum CustomParseError<'i> {
     AtRuleInvalid(CowRcStr<'i>),
     AtRuleBodyInvalid,
     QualifiedRuleInvalid,
+    /// The value of a known property could not be parsed.
+    InvalidPropertyValue {
+        /// The name of the property.
+        property: CowRcStr<'i>,
+        /// A description of the values accepted by the property.
+        expected: String,
+    },
+    /// The name of a property is not known and is not a custom property.
+    UnknownProperty(CowRcStr<'i>),
+}
+
+impl fmt::Display for CustomParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CustomParseError::*;
+        match self {
+            InvalidValue => write!(f, "Invalid value"),
+            InvalidDeclaration => write!(f, "Invalid declaration"),
+            InvalidNesting => write!(f, "Invalid nesting"),
+            SelectorError(err) => write!(f, "{}", err.reason()),
+            EndOfInput => write!(f, "Unexpected end of input"),
+            UnexpectedToken(token) => write!(f, "Unexpected token: {:?}", token),
+            AtRuleInvalid(name) => write!(f, "Unknown at-rule: @{}", name),
+            AtRuleBodyInvalid => write!(f, "Invalid at-rule body"),
+            QualifiedRuleInvalid => write!(f, "Invalid rule"),
+            InvalidPropertyValue { property, expected } => {
+                write!(f, "Invalid value for property '{}', expected {}", property, expected)
+            }
+            UnknownProperty(name) => write!(f, "Unknown property: {}", name),
+        }
+    }
 }
 
 impl<'i> From<SelectorParseErrorKind<'i>> for CustomParseError<'i> {
@@ -157,7 +187,7 @@ impl<'i> From<SelectorParseErrorKind<'i>> for SelectorError<'i> {
 }
 
 impl SelectorError<'_> {
-    fn _reason(&self) -> String {
+    pub(crate) fn reason(&self) -> String {
         use SelectorError::*;
         match self {
         NoQualifiedNameInAttributeSelector(token) => format!("No qualified name in attribute selector: {:?}.", token),
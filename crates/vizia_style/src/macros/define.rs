@@ -25,6 +25,10 @@ macro_rules! define_enum {
                     $($str => $name::$id,)+
                 }
             }
+
+            expected {
+                [$($str),+].join(" | ")
+            }
         }
 
         #[cfg(test)]
@@ -68,24 +72,45 @@ macro_rules! define_property {
 
                 let state = input.state();
                 let name_ref = name.as_ref();
-                match name_ref {
-                    $(
-                        $str => {
-                            if let Ok(val) = <$inner_ty>::parse(input) {
-                                return Ok($name::$variant(val));
-                            }
-                        }
-                    )+
-                    _ => {
-                        if let Ok(custom) = CustomProperty::parse(name.clone(), input) {
-                            return Ok(Property::Custom(custom));
+                let mut known = false;
+
+                // A property may be listed more than once with different value types, which are
+                // tried in order.
+                $(
+                    if name_ref == $str {
+                        known = true;
+                        if let Ok(val) = <$inner_ty>::parse(input) {
+                            return Ok($name::$variant(val));
                         }
+                        input.reset(&state);
+                    }
+                )+
+
+                if !known {
+                    if let Ok(custom) = CustomProperty::parse(name.clone(), input) {
+                        return Ok(Property::Custom(custom));
                     }
                 }
 
                 input.reset(&state);
                 return Ok(Property::Unparsed(UnparsedProperty::parse(name, input)?));
             }
+
+            /// Returns a description of the values accepted by the property with the given name, or
+            /// `None` if the property is unknown.
+            pub fn expected_value(name: &str) -> Option<String> {
+                let mut expected: Option<String> = None;
+                $(
+                    if name == $str {
+                        let value = <$inner_ty as Parse<'i>>::expected();
+                        expected = Some(match expected {
+                            Some(expected) => format!("{} | {}", expected, value),
+                            None => value,
+                        });
+                    }
+                )+
+                expected
+            }
         }
     };
 }
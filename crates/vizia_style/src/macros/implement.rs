@@ -44,6 +44,11 @@ macro_rules! impl_parse {
                 $custom_closure: expr
             }
         )?
+
+        // Description of the accepted values used in diagnostics
+        expected {
+            $expected: expr
+        }
     ) => {
         impl<'i> Parse<'i> for $name {
             fn parse<'t>(input: &mut cssparser::Parser<'i, 't>) -> Result<Self, cssparser::ParseError<'i, $crate::CustomParseError<'i>>> {
@@ -97,6 +102,10 @@ macro_rules! impl_parse {
                     input.try_parse($custom_closure)
                 )?
             }
+
+            fn expected() -> String {
+                String::from($expected)
+            }
         }
     };
 }
@@ -1,7 +1,7 @@
 use crate::{
     parse_declaration, CssRule, CssRuleList, CustomParseError, DeclarationBlock, DeclarationList,
-    KeyframeListParser, KeyframesName, KeyframesRule, Location, Parse, ParserOptions,
-    SelectorParser, Selectors, StyleRule,
+    FontFaceDeclarationParser, FontFaceRule, KeyframeListParser, KeyframesName, KeyframesRule,
+    Location, Parse, ParserOptions, SelectorParser, Selectors, StyleRule,
 };
 use cssparser::*;
 use selectors::{parser::ParseRelative, SelectorList};
@@ -42,6 +42,7 @@ impl<'a, 'i> TopLevelRuleParser<'a, 'i> {
 pub enum AtRulePrelude<'i> {
     // Property(DashedIdent<'i>),
     Keyframes(KeyframesName<'i>),
+    FontFace,
 }
 
 impl<'i> AtRuleParser<'i> for TopLevelRuleParser<'_, 'i> {
@@ -178,6 +179,9 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 let name = input.try_parse(KeyframesName::parse)?;
                 Ok(AtRulePrelude::Keyframes(name))
             },
+            "font-face" => {
+                Ok(AtRulePrelude::FontFace)
+            },
             _ => Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
         }
     }
@@ -200,6 +204,26 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 }));
                 Ok(())
             }
+
+            AtRulePrelude::FontFace => {
+                let mut parser = FontFaceDeclarationParser;
+                let mut properties = Vec::new();
+                for result in RuleBodyParser::new(input, &mut parser) {
+                    match result {
+                        Ok(property) => properties.push(property),
+                        Err((error, _)) => {
+                            if !self.options.error_recovery {
+                                return Err(error);
+                            }
+
+                            self.options.warn(error);
+                        }
+                    }
+                }
+
+                self.rules.0.push(CssRule::FontFace(FontFaceRule { properties, loc }));
+                Ok(())
+            }
         }
     }
 }
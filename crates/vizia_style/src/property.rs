@@ -1,11 +1,14 @@
 use crate::{
-    define_property, Alignment, Angle, BackgroundImage, BackgroundSize, BlendMode, Border,
-    BorderStyle, BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage, LineClamp,
-    Opacity, Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
-    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, TextStroke,
-    TextStrokeStyle, Transform, Transition, Translate, Units, UnparsedProperty, Visibility,
+    define_property, Alignment, Angle, AnimationDirection, AnimationFillMode, AnimationName,
+    AnimationPlayState, BackgroundImage, BackgroundSize, BlendMode, Border, BorderColor,
+    BorderStyle, BorderStyleKeyword, BorderWidth, BorderWidthValue, ClipPath, Color, Content,
+    CornerRadius, CornerShape, CursorIcon, CustomParseError, CustomProperty, Display, Duration,
+    EasingFunction, Filter, FontFamily, FontFeature, FontSize, FontSlant, FontVariation,
+    FontWeight, FontWidth, Gradient, IterationCount, LayoutType, Length, LengthOrPercentage,
+    LineClamp, LineHeight, Opacity, Outline, Overflow, Parse, PointerEvents, Position,
+    PositionType, Rect, Scale, Shadow, Spacing, TextAlign, TextDecoration, TextDecorationLine,
+    TextDecorationStyle, TextOverflow, TextStroke, TextStrokeStyle, TextTransform, Transform,
+    Transition, Translate, Units, UnparsedProperty, Visibility,
 };
 use cssparser::Parser;
 
@@ -18,6 +21,7 @@ define_property! {
         "overflow-x": OverflowX(Overflow),
         "overflow-y": OverflowY(Overflow),
         "clip-path": ClipPath(ClipPath),
+        "mask-image": MaskImage(Vec<BackgroundImage<'i>>),
         "opacity": Opacity(Opacity),
         "z-index": ZIndex(i32),
         "blend-mode": BlendMode(BlendMode),
@@ -78,12 +82,12 @@ define_property! {
         "border": Border(Border),
 
         // Border Color
-        "border-color": BorderColor(Color),
-        // TODO: Support coloring individual borders.
-        // "border-top-color": BorderTopColor(Color),
-        // "border-right-color": BorderRightColor(Color),
-        // "border-bottom-color": BorderBottomColor(Color),
-        // "border-left-color": BorderLeftColor(Color),
+        "border-color": BorderColor(BorderColor),
+        "border-color": BorderGradient(Gradient),
+        "border-top-color": BorderTopColor(Color),
+        "border-right-color": BorderRightColor(Color),
+        "border-bottom-color": BorderBottomColor(Color),
+        "border-left-color": BorderLeftColor(Color),
 
         // Corner Shape
         "corner-shape": CornerShape(Rect<CornerShape>),
@@ -100,19 +104,18 @@ define_property! {
         "corner-bottom-right-radius": CornerBottomRightRadius(LengthOrPercentage),
 
         // Border Style
-        // TODO: Support styling borders.
         "border-style": BorderStyle(BorderStyle),
-        // "border-top-style": BorderTopStyle(BorderStyleKeyword),
-        // "border-right-style": BorderRightStyle(BorderStyleKeyword),
-        // "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
-        // "border-left-style": BorderLeftStyle(BorderStyleKeyword),
+        "border-top-style": BorderTopStyle(BorderStyleKeyword),
+        "border-right-style": BorderRightStyle(BorderStyleKeyword),
+        "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
+        "border-left-style": BorderLeftStyle(BorderStyleKeyword),
 
         // Border Width
         "border-width": BorderWidth(BorderWidth),
-        // "border-top-width": BorderTopWidth(BorderWidthValue),
-        // "border-right-width": BorderRightWidth(BorderWidthValue),
-        // "border-bottom-width": BorderBottomWidth(BorderWidthValue),
-        // "border-left-width": BorderLeftWidth(BorderWidthValue),
+        "border-top-width": BorderTopWidth(BorderWidthValue),
+        "border-right-width": BorderRightWidth(BorderWidthValue),
+        "border-bottom-width": BorderBottomWidth(BorderWidthValue),
+        "border-left-width": BorderLeftWidth(BorderWidthValue),
 
 
         // ----- Outline -----
@@ -154,17 +157,25 @@ define_property! {
         // Text
         "font-size": FontSize(FontSize),
         "color": FontColor(Color),
+        "color": FontGradient(Gradient),
         "font-family": FontFamily(Vec<FontFamily<'i>>),
         "font-variation-settings": FontVariationSettings(Vec<FontVariation>),
+        "font-feature-settings": FontFeatureSettings(Vec<FontFeature>),
         "font-weight": FontWeight(FontWeight),
         "font-slant": FontSlant(FontSlant),
         "font-width": FontWidth(FontWidth),
-        "selection-color": SelectionColor(Color), // TODO: Remove this once we have the pseudoselector version.
+        // Alias for the `background-color` of a `::selection` rule.
+        "selection-color": SelectionColor(Color),
         "caret-color": CaretColor(Color),
         "text-wrap": TextWrap(bool),
         "text-align": TextAlign(TextAlign),
         "text-overflow": TextOverflow(TextOverflow),
         "line-clamp": LineClamp(LineClamp),
+        "line-height": LineHeight(LineHeight),
+        "letter-spacing": LetterSpacing(Spacing),
+        "word-spacing": WordSpacing(Spacing),
+        "text-shadow": TextShadow(Vec<Shadow>),
+        "text-transform": TextTransform(TextTransform),
         "text-decoration": TextDecoration(TextDecoration),
         "text-decoration-line": TextDecorationLine(TextDecorationLine),
         "text-stroke": TextStroke(TextStroke),
@@ -183,11 +194,22 @@ define_property! {
         // Shadow
         "shadow": Shadow(Vec<Shadow>),
 
+        // Filter
+        "filter": Filter(Vec<Filter>),
+
         // Backdrop Filter
-        "backdrop-filter": BackdropFilter(Filter),
+        "backdrop-filter": BackdropFilter(Vec<Filter>),
 
         // Animations
         "transition": Transition(Vec<Transition>),
+        "animation-name": AnimationName(AnimationName),
+        "animation-duration": AnimationDuration(Duration),
+        "animation-delay": AnimationDelay(Duration),
+        "animation-timing-function": AnimationTimingFunction(EasingFunction),
+        "animation-iteration-count": AnimationIterationCount(IterationCount),
+        "animation-direction": AnimationDirection(AnimationDirection),
+        "animation-fill-mode": AnimationFillMode(AnimationFillMode),
+        "animation-play-state": AnimationPlayState(AnimationPlayState),
 
         // Transform
         "transform": Transform(Vec<Transform>),
@@ -199,6 +221,9 @@ define_property! {
         // Cursor
         "cursor": Cursor(CursorIcon),
         "pointer-events": PointerEvents(PointerEvents),
+
+        // Generated Content
+        "content": Content(Content),
     }
 }
 
@@ -215,4 +240,124 @@ mod tests {
         let _parsed_property =
             Property::parse_value(CowRcStr::from("background-color"), &mut parser);
     }
+
+    #[test]
+    fn parse_border_side_property() {
+        let mut parser_input = ParserInput::new("2px");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property =
+            Property::parse_value(CowRcStr::from("border-bottom-width"), &mut parser);
+        assert!(matches!(parsed_property, Ok(Property::BorderBottomWidth(_))));
+
+        let mut parser_input = ParserInput::new("red blue");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("border-color"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::BorderColor(BorderColor::new(
+                Color::rgb(255, 0, 0),
+                Color::rgb(0, 0, 255),
+                Color::rgb(255, 0, 0),
+                Color::rgb(0, 0, 255),
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_gradient_fill_property() {
+        let mut parser_input = ParserInput::new("red");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("color"), &mut parser);
+        assert!(matches!(parsed_property, Ok(Property::FontColor(_))));
+
+        let mut parser_input = ParserInput::new("linear-gradient(red, blue)");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("color"), &mut parser);
+        assert!(matches!(parsed_property, Ok(Property::FontGradient(Gradient::Linear(_)))));
+
+        let mut parser_input = ParserInput::new("conic-gradient(red, blue)");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("border-color"), &mut parser);
+        assert!(matches!(parsed_property, Ok(Property::BorderGradient(Gradient::Conic(_)))));
+
+        assert_eq!(
+            Property::expected_value("border-color").as_deref(),
+            Some(format!("{} | {}", BorderColor::expected(), Gradient::expected()).as_str())
+        );
+    }
+
+    #[test]
+    fn property_expected_value() {
+        assert_eq!(
+            Property::expected_value("width").as_deref(),
+            Some("<length> | <percentage> | <stretch> | auto")
+        );
+        assert_eq!(
+            Property::expected_value("border-color").as_deref(),
+            Some(
+                "<color>{1,4} | linear-gradient() | repeating-linear-gradient() | radial-gradient() \
+                | repeating-radial-gradient() | conic-gradient() | repeating-conic-gradient()"
+            )
+        );
+        assert_eq!(Property::expected_value("not-a-property"), None);
+    }
+
+    #[test]
+    fn parse_animation_properties() {
+        let mut parser_input = ParserInput::new("pulse");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("animation-name"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::AnimationName(AnimationName::Name(String::from("pulse"))))
+        );
+
+        let mut parser_input = ParserInput::new("infinite");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property =
+            Property::parse_value(CowRcStr::from("animation-iteration-count"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::AnimationIterationCount(IterationCount::Infinite))
+        );
+
+        let mut parser_input = ParserInput::new("alternate-reverse");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property =
+            Property::parse_value(CowRcStr::from("animation-direction"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::AnimationDirection(AnimationDirection::AlternateReverse))
+        );
+    }
+
+    #[test]
+    fn parse_typography_properties() {
+        let mut parser_input = ParserInput::new("1.5");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("line-height"), &mut parser);
+        assert_eq!(parsed_property.ok(), Some(Property::LineHeight(LineHeight::Number(1.5))));
+
+        let mut parser_input = ParserInput::new("2px");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("letter-spacing"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::LetterSpacing(Spacing::Length(LengthOrPercentage::px(2.0))))
+        );
+
+        let mut parser_input = ParserInput::new("1px 1px 2px black, 0 0 4px red");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("text-shadow"), &mut parser);
+        assert!(matches!(parsed_property, Ok(Property::TextShadow(shadows)) if shadows.len() == 2));
+
+        let mut parser_input = ParserInput::new("\"tnum\"");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property =
+            Property::parse_value(CowRcStr::from("font-feature-settings"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::FontFeatureSettings(vec![FontFeature::new(('t', 'n', 'u', 'm'), 1)]))
+        );
+    }
 }
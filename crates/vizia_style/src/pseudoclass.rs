@@ -1,4 +1,5 @@
 use cssparser::*;
+use unic_langid::LanguageIdentifier;
 
 use crate::{Direction, Selectors};
 
@@ -29,7 +30,8 @@ pub enum PseudoClass {
     UserValid,
     UserInvalid,
 
-    Lang(Vec<String>),
+    /// Matches the language ranges which were valid language identifiers when parsed.
+    Lang(Vec<LanguageIdentifier>),
     Dir(Direction),
     Custom(String),
 }
@@ -63,9 +65,24 @@ impl ToCss for PseudoClass {
             PseudoClass::Optional => dest.write_str(":optional"),
             PseudoClass::UserValid => dest.write_str(":user-valid"),
             PseudoClass::UserInvalid => dest.write_str(":user-invalid"),
-            PseudoClass::Lang(ref _lang) => dest.write_str(":lang()"),
-            PseudoClass::Dir(_) => dest.write_str(":dir()"),
-            PseudoClass::Custom(_) => dest.write_str(":custom"),
+            PseudoClass::Lang(ref langs) => {
+                dest.write_str(":lang(")?;
+                for (index, lang) in langs.iter().enumerate() {
+                    if index > 0 {
+                        dest.write_str(", ")?;
+                    }
+                    serialize_identifier(&lang.to_string(), dest)?;
+                }
+                dest.write_char(')')
+            }
+            PseudoClass::Dir(direction) => match direction {
+                Direction::Ltr => dest.write_str(":dir(ltr)"),
+                Direction::Rtl => dest.write_str(":dir(rtl)"),
+            },
+            PseudoClass::Custom(ref name) => {
+                dest.write_char(':')?;
+                serialize_identifier(name, dest)
+            }
         }
     }
 }
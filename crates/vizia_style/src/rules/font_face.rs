@@ -0,0 +1,171 @@
+use cssparser::*;
+
+use crate::{CustomParseError, FontFamily, FontSlant, FontWeight, Location, Parse, Url};
+
+/// A `@font-face` rule, which registers a font to be used by the `font-family` property.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FontFaceRule<'i> {
+    pub properties: Vec<FontFaceProperty<'i>>,
+    pub loc: Location,
+}
+
+impl<'i> FontFaceRule<'i> {
+    /// Returns the family name declared by the rule.
+    pub fn family(&self) -> Option<&FontFamily<'i>> {
+        self.properties.iter().rev().find_map(|property| match property {
+            FontFaceProperty::FontFamily(family) => Some(family),
+            _ => None,
+        })
+    }
+
+    /// Returns the sources of the font declared by the rule, in order of preference.
+    pub fn sources(&self) -> &[FontFaceSource<'i>] {
+        self.properties
+            .iter()
+            .rev()
+            .find_map(|property| match property {
+                FontFaceProperty::Source(sources) => Some(sources.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the font weight declared by the rule.
+    pub fn weight(&self) -> Option<FontWeight> {
+        self.properties.iter().rev().find_map(|property| match property {
+            FontFaceProperty::FontWeight(weight) => Some(*weight),
+            _ => None,
+        })
+    }
+
+    /// Returns the font style declared by the rule.
+    pub fn slant(&self) -> Option<FontSlant> {
+        self.properties.iter().rev().find_map(|property| match property {
+            FontFaceProperty::FontStyle(slant) => Some(*slant),
+            _ => None,
+        })
+    }
+}
+
+/// A descriptor within a `@font-face` rule.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceProperty<'i> {
+    /// The `src` descriptor.
+    Source(Vec<FontFaceSource<'i>>),
+    /// The `font-family` descriptor.
+    FontFamily(FontFamily<'i>),
+    /// The `font-weight` descriptor.
+    FontWeight(FontWeight),
+    /// The `font-style` descriptor.
+    FontStyle(FontSlant),
+}
+
+/// A source of the font in the `src` descriptor of a `@font-face` rule.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceSource<'i> {
+    /// A font file, with an optional format hint.
+    Url(Url<'i>, Option<CowRcStr<'i>>),
+    /// A font installed on the system.
+    Local(FontFamily<'i>),
+}
+
+impl<'i> Parse<'i> for FontFaceSource<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_function_matching("local")).is_ok() {
+            return input
+                .parse_nested_block(|input| Ok(FontFaceSource::Local(FontFamily::parse(input)?)));
+        }
+
+        let url = Url::parse(input)?;
+
+        let format = input
+            .try_parse(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>> {
+                input.expect_function_matching("format")?;
+                input.parse_nested_block(|input| Ok(input.expect_ident_or_string()?.clone()))
+            })
+            .ok();
+
+        Ok(FontFaceSource::Url(url, format))
+    }
+
+    fn expected() -> String {
+        String::from("<url> [ format(<string>) ]? | local(<family-name>)")
+    }
+}
+
+impl<'i> Parse<'i> for Vec<FontFaceSource<'i>> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(FontFaceSource::parse)
+    }
+
+    fn expected() -> String {
+        format!("[ {} ]#", FontFaceSource::expected())
+    }
+}
+
+/// Parses the descriptors within the block of a `@font-face` rule.
+pub(crate) struct FontFaceDeclarationParser;
+
+impl<'i> DeclarationParser<'i> for FontFaceDeclarationParser {
+    type Declaration = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+
+    fn parse_value<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self::Declaration, ParseError<'i, Self::Error>> {
+        let location = input.current_source_location();
+
+        let (property, expected) = match_ignore_ascii_case! { &name,
+            "src" => (
+                input.parse_entirely(Vec::<FontFaceSource>::parse).map(FontFaceProperty::Source),
+                format!("[ {} ]#", FontFaceSource::expected()),
+            ),
+            "font-family" => (
+                input.parse_entirely(FontFamily::parse).map(FontFaceProperty::FontFamily),
+                FontFamily::expected(),
+            ),
+            "font-weight" => (
+                input.parse_entirely(FontWeight::parse).map(FontFaceProperty::FontWeight),
+                FontWeight::expected(),
+            ),
+            "font-style" => (
+                input.parse_entirely(FontSlant::parse).map(FontFaceProperty::FontStyle),
+                FontSlant::expected(),
+            ),
+            _ => return Err(location.new_custom_error(CustomParseError::UnknownProperty(name))),
+        };
+
+        property.map_err(|_| {
+            location.new_custom_error(CustomParseError::InvalidPropertyValue {
+                property: name,
+                expected,
+            })
+        })
+    }
+}
+
+impl<'i> AtRuleParser<'i> for FontFaceDeclarationParser {
+    type Prelude = ();
+    type AtRule = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+}
+
+impl<'i> QualifiedRuleParser<'i> for FontFaceDeclarationParser {
+    type Prelude = ();
+    type QualifiedRule = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, FontFaceProperty<'i>, CustomParseError<'i>>
+    for FontFaceDeclarationParser
+{
+    fn parse_qualified(&self) -> bool {
+        false
+    }
+
+    fn parse_declarations(&self) -> bool {
+        true
+    }
+}
@@ -38,6 +38,10 @@ impl<'i> Parse<'i> for KeyframesName<'i> {
             t => Err(input.new_unexpected_token_error(t.clone())),
         }
     }
+
+    fn expected() -> String {
+        String::from("<custom-ident> | <string>")
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -76,6 +80,10 @@ impl<'i> Parse<'i> for KeyframeSelector {
             ))
         }
     }
+
+    fn expected() -> String {
+        String::from("from | to | <percentage>")
+    }
 }
 
 pub struct KeyframeListParser;
@@ -7,6 +7,9 @@ pub use keyframes::*;
 pub mod property;
 pub use property::*;
 
+pub mod font_face;
+pub use font_face::*;
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct CssRuleList<'i>(pub Vec<CssRule<'i>>);
 
@@ -16,4 +19,5 @@ pub enum CssRule<'i> {
     Property(PropertyRule<'i>),
     Ignored,
     Keyframes(KeyframesRule<'i>),
+    FontFace(FontFaceRule<'i>),
 }
@@ -3,6 +3,7 @@ use std::hash::Hash;
 use cssparser::*;
 use precomputed_hash::PrecomputedHash;
 use selectors::SelectorImpl;
+use unic_langid::LanguageIdentifier;
 
 use crate::{CustomParseError, Direction, Parse, ParserOptions, PseudoClass, PseudoElement};
 
@@ -24,6 +25,12 @@ impl std::convert::From<&str> for SelectorString {
     }
 }
 
+impl AsRef<str> for SelectorString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl ToCss for SelectorString {
     fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
     where
@@ -132,12 +139,14 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
         use PseudoClass::*;
         let pseudo_class = match_ignore_ascii_case! { &name,
             "lang" => {
+                // Language ranges are parsed once here rather than each time the selector is matched.
+                // Ranges which are not valid language identifiers never match, so they are dropped.
                 let langs = parser.parse_comma_separated(|parser|{
                     parser.expect_ident_or_string()
-                        .map(|s| s.to_string())
+                        .map(|s| s.parse::<LanguageIdentifier>().ok())
                         .map_err(|e| e.into())
                 })?;
-                Lang(langs)
+                Lang(langs.into_iter().flatten().collect())
             },
 
             "dir" => {
@@ -160,6 +169,7 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
             "before" => Before,
             "after" => After,
             "selection" => Selection,
+            "placeholder" => Placeholder,
             _=> Custom(name.to_string())
         };
 
@@ -129,6 +129,71 @@ test {
         let style_sheet = StyleSheet::parse(CSS_EXAMPLE, ParserOptions::default());
         println!("{:#?}", style_sheet);
     }
+
+    #[test]
+    fn parse_stylesheet_warnings() {
+        let warnings = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.filename = String::from("test.css");
+        options.warnings = Some(warnings.clone());
+
+        let css = "label {\n    width: red;\n    colr: blue;\n    --custom: 1px;\n}";
+        StyleSheet::parse(css, options).unwrap();
+
+        let warnings = warnings.read().unwrap();
+        assert_eq!(warnings.len(), 2);
+
+        assert_eq!(
+            warnings[0].kind,
+            CustomParseError::InvalidPropertyValue {
+                property: "width".into(),
+                expected: String::from("<length> | <percentage> | <stretch> | auto"),
+            }
+        );
+        let location = warnings[0].location.as_ref().unwrap();
+        assert_eq!((location.filename.as_str(), location.line), ("test.css", 1));
+
+        assert_eq!(warnings[1].kind, CustomParseError::UnknownProperty("colr".into()));
+        assert_eq!(warnings[1].location.as_ref().unwrap().line, 2);
+    }
+
+    #[test]
+    fn parse_font_face() {
+        let warnings = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.warnings = Some(warnings.clone());
+
+        let css = r#"@font-face {
+    font-family: "Inter Display";
+    src: url("fonts/Inter.ttf") format("truetype"), local(Inter);
+    font-weight: bold;
+    font-style: italic;
+    unicode-range: U+0000-00FF;
+}"#;
+        let stylesheet = StyleSheet::parse(css, options).unwrap();
+
+        let crate::CssRule::FontFace(rule) = &stylesheet.rules.0[0] else {
+            panic!("expected a @font-face rule");
+        };
+
+        assert_eq!(rule.family(), Some(&crate::FontFamily::Named("Inter Display".into())));
+        assert_eq!(
+            rule.sources(),
+            &[
+                crate::FontFaceSource::Url(
+                    crate::Url { url: "fonts/Inter.ttf".into() },
+                    Some("truetype".into())
+                ),
+                crate::FontFaceSource::Local(crate::FontFamily::Named("Inter".into())),
+            ]
+        );
+        assert_eq!(rule.weight(), Some(crate::FontWeight(700)));
+        assert_eq!(rule.slant(), Some(crate::FontSlant::Italic));
+
+        let warnings = warnings.read().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, CustomParseError::UnknownProperty("unicode-range".into()));
+    }
 }
 
 // use cssparser::*;
@@ -3,6 +3,10 @@ use cssparser::*;
 
 pub trait Parse<'i>: Sized {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>;
+
+    /// Returns a description of the values accepted by [`Parse::parse`], such as
+    /// `<length> | <percentage>`, used in diagnostics.
+    fn expected() -> String;
 }
 
 pub trait TryAdd<T> {
@@ -17,6 +17,10 @@ impl_parse! {
             "bottom-right" => Alignment::BottomRight,
         }
     }
+
+    expected {
+        "top-left | top-center | top-right | left | center | right | bottom-left | bottom-center | bottom-right"
+    }
 }
 
 #[cfg(test)]
@@ -11,6 +11,10 @@ impl_parse! {
         Percentage,
         f32,
     }
+
+    expected {
+        "<number> | <percentage>"
+    }
 }
 
 impl From<f32> for AlphaValue {
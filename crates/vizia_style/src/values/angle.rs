@@ -30,6 +30,10 @@ impl_parse! {
             "rad" => Angle::Rad,
         }
     }
+
+    expected {
+        "<angle>"
+    }
 }
 
 impl Angle {
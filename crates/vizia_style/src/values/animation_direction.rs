@@ -0,0 +1,18 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether an animation plays forwards, backwards, or alternates between the two on
+    /// each iteration.
+    #[derive(Default)]
+    pub enum AnimationDirection {
+        /// Every iteration plays forwards.
+        #[default]
+        "normal": Normal,
+        /// Every iteration plays backwards.
+        "reverse": Reverse,
+        /// The first iteration plays forwards and subsequent iterations alternate direction.
+        "alternate": Alternate,
+        /// The first iteration plays backwards and subsequent iterations alternate direction.
+        "alternate-reverse": AlternateReverse,
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether an animation applies its values before it starts and after it ends.
+    #[derive(Default)]
+    pub enum AnimationFillMode {
+        /// The animation only applies its values while it is running.
+        #[default]
+        "none": None,
+        /// The animation keeps the values of its last keyframe after it ends.
+        "forwards": Forwards,
+        /// The animation applies the values of its first keyframe during its delay.
+        "backwards": Backwards,
+        /// The animation applies both the `forwards` and `backwards` rules.
+        "both": Both,
+    }
+}
+
+impl AnimationFillMode {
+    /// Returns true if the animation applies its values during its delay.
+    pub fn fills_backwards(&self) -> bool {
+        matches!(self, AnimationFillMode::Backwards | AnimationFillMode::Both)
+    }
+
+    /// Returns true if the animation keeps its values after it ends.
+    pub fn fills_forwards(&self) -> bool {
+        matches!(self, AnimationFillMode::Forwards | AnimationFillMode::Both)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{CustomParseError, KeyframesName, Parse};
+use cssparser::*;
+
+/// The name of the `@keyframes` rule an animation plays.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum AnimationName {
+    /// No animation is played.
+    #[default]
+    None,
+    /// The name of a `@keyframes` rule.
+    Name(String),
+}
+
+impl<'i> Parse<'i> for AnimationName {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(AnimationName::None);
+        }
+
+        Ok(AnimationName::Name(KeyframesName::parse(input)?.as_string()))
+    }
+
+    fn expected() -> String {
+        String::from("none | <keyframes-name>")
+    }
+}
+
+impl From<&str> for AnimationName {
+    fn from(name: &str) -> Self {
+        AnimationName::Name(name.to_owned())
+    }
+}
+
+impl From<String> for AnimationName {
+    fn from(name: String) -> Self {
+        AnimationName::Name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AnimationName, assert_animation_name,
+
+        custom {
+            success {
+                "none" => AnimationName::None,
+                "NONE" => AnimationName::None,
+                "pulse" => AnimationName::Name(String::from("pulse")),
+                "\"fade in\"" => AnimationName::Name(String::from("fade in")),
+            }
+
+            failure {
+                "inherit",
+                "2s",
+                "123",
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether an animation is running or paused.
+    #[derive(Default)]
+    pub enum AnimationPlayState {
+        /// The animation is running.
+        #[default]
+        "running": Running,
+        /// The animation is paused and holds its current value.
+        "paused": Paused,
+    }
+}
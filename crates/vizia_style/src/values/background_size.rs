@@ -38,10 +38,18 @@ impl<'i> Parse<'i> for BackgroundSize {
           ))
         })
     }
+
+    fn expected() -> String {
+        String::from("[ <length> | <percentage> | auto ]{1,2} | cover | contain")
+    }
 }
 
 impl<'i> Parse<'i> for Vec<BackgroundSize> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_comma_separated(BackgroundSize::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", BackgroundSize::expected())
+    }
 }
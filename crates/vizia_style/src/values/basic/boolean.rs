@@ -13,6 +13,10 @@ impl_parse! {
             "no" => false,
         }
     }
+
+    expected {
+        "on | off | true | false | yes | no"
+    }
 }
 
 #[cfg(test)]
@@ -11,6 +11,10 @@ impl<'i> Parse<'i> for Ident<'i> {
         let ident = input.expect_ident()?;
         Ok(Ident(ident.clone()))
     }
+
+    fn expected() -> String {
+        String::from("<ident>")
+    }
 }
 
 impl From<String> for Ident<'_> {
@@ -42,6 +46,10 @@ impl<'i> Parse<'i> for DashedIdent<'i> {
 
         Ok(DashedIdent(ident.clone()))
     }
+
+    fn expected() -> String {
+        String::from("<dashed-ident>")
+    }
 }
 
 #[cfg(test)]
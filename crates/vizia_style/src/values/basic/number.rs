@@ -9,6 +9,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if i8::try_from(*int_value).is_ok() => *int_value as i8,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -19,6 +23,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if i16::try_from(*int_value).is_ok() => *int_value as i16,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -29,6 +37,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } => *int_value,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -39,6 +51,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } => i64::from(*int_value),
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -49,6 +65,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } => i128::from(*int_value),
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -59,6 +79,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if isize::try_from(*int_value).is_ok() => *int_value as isize,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -69,6 +93,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if u8::try_from(*int_value).is_ok() => *int_value as u8,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -79,6 +107,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if u16::try_from(*int_value).is_ok() => *int_value as u16,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -89,6 +121,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if u32::try_from(*int_value).is_ok() => *int_value as u32,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -99,6 +135,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if u64::try_from(*int_value).is_ok() => *int_value as u64,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -109,6 +149,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if u128::try_from(*int_value).is_ok() => *int_value as u128,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -119,6 +163,10 @@ impl_parse! {
             Token::Number { int_value: Some(int_value), .. } if usize::try_from(*int_value).is_ok() => *int_value as usize,
         }
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl_parse! {
@@ -129,6 +177,10 @@ impl_parse! {
             Token::Number { value, .. } => *value,
         }
     }
+
+    expected {
+        "<number>"
+    }
 }
 
 impl_parse! {
@@ -139,4 +191,8 @@ impl_parse! {
             Token::Number { value, .. } => f64::from(*value),
         }
     }
+
+    expected {
+        "<number>"
+    }
 }
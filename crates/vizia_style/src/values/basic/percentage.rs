@@ -13,6 +13,10 @@ impl_parse! {
             Token::Percentage {unit_value, .. } => Percentage(*unit_value * 100.0),
         }
     }
+
+    expected {
+        "<percentage>"
+    }
 }
 
 impl From<Calc<Percentage>> for Percentage {
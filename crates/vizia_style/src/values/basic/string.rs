@@ -9,6 +9,10 @@ impl_parse! {
             Token::QuotedString(ref value) => value.as_ref().to_owned(),
         }
     }
+
+    expected {
+        "<string>"
+    }
 }
 
 #[cfg(test)]
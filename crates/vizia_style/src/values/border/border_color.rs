@@ -25,6 +25,10 @@ impl_parse! {
     try_parse {
         Rect<Color>,
     }
+
+    expected {
+        "<color>{1,4}"
+    }
 }
 
 impl From<Rect<Color>> for BorderColor {
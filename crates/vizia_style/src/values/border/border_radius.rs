@@ -29,6 +29,10 @@ impl_parse! {
     try_parse {
         Rect<LengthOrPercentage>,
     }
+
+    expected {
+        "[ <length> | <percentage> ]{1,4}"
+    }
 }
 
 impl From<Rect<LengthOrPercentage>> for CornerRadius {
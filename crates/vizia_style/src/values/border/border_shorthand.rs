@@ -75,6 +75,15 @@ impl<'i> Parse<'i> for Border {
             })
         }
     }
+
+    fn expected() -> String {
+        format!(
+            "{} || {} || {}",
+            BorderWidthValue::expected(),
+            BorderStyle::expected(),
+            Color::expected()
+        )
+    }
 }
 
 #[cfg(test)]
@@ -31,6 +31,10 @@ impl_parse! {
     try_parse {
         Rect<BorderStyleKeyword>,
     }
+
+    expected {
+        format!("[ {} ]{{1,4}}", BorderStyleKeyword::expected())
+    }
 }
 
 impl From<Rect<BorderStyleKeyword>> for BorderStyle {
@@ -31,6 +31,10 @@ impl_parse! {
     try_parse {
         Rect<BorderWidthValue>,
     }
+
+    expected {
+        format!("[ {} ]{{1,4}}", BorderWidthValue::expected())
+    }
 }
 
 impl From<Rect<BorderWidthValue>> for BorderWidth {
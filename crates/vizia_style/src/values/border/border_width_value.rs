@@ -11,6 +11,10 @@ impl_parse! {
         BorderWidthKeyword,
         Length,
     }
+
+    expected {
+        format!("{} | <length>", BorderWidthKeyword::expected())
+    }
 }
 
 impl From<BorderWidthKeyword> for BorderWidthValue {
@@ -35,6 +39,12 @@ impl From<Length> for BorderWidthValue {
     }
 }
 
+impl From<LengthOrPercentage> for BorderWidthValue {
+    fn from(length: LengthOrPercentage) -> Self {
+        BorderWidthValue(length)
+    }
+}
+
 impl From<BorderWidthValue> for LengthOrPercentage {
     fn from(border_width_value: BorderWidthValue) -> Self {
         border_width_value.0
@@ -114,6 +114,10 @@ impl<
             _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone()))),
         }
     }
+
+    fn expected() -> String {
+        String::from("calc() | min() | max() | clamp()")
+    }
 }
 
 impl<
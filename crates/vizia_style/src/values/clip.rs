@@ -1,11 +1,84 @@
-use crate::{AutoKeyword, Length, LengthOrPercentage, Parse, Rect};
+use crate::{
+    AutoKeyword, CustomParseError, FillRule, Length, LengthOrPercentage, Parse, Position, Rect,
+};
 use cssparser::*;
 
+/// The region of a view, and its descendants, which is drawn and can be hovered.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum ClipPath {
+    /// Clips to the bounds of the view when overflow is hidden.
     #[default]
     Auto,
+    /// Clips to the bounds of the view inset by the given top, right, bottom and left amounts.
     Shape(Rect<LengthOrPercentage>),
+    /// Clips to a circle with the given radius and center.
+    Circle(ShapeRadius, Position),
+    /// Clips to an ellipse with the given horizontal and vertical radii and center.
+    Ellipse(ShapeRadius, ShapeRadius, Position),
+    /// Clips to a polygon with the given vertices, relative to the top-left of the view.
+    Polygon(FillRule, Vec<(LengthOrPercentage, LengthOrPercentage)>),
+    /// Clips to an SVG path, in pixels relative to the top-left of the view.
+    Path(FillRule, String),
+}
+
+/// The radius of a circle or ellipse clip shape.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum ShapeRadius {
+    /// An explicit radius.
+    Length(LengthOrPercentage),
+    /// The distance from the center to the closest side of the view.
+    #[default]
+    ClosestSide,
+    /// The distance from the center to the farthest side of the view.
+    FarthestSide,
+}
+
+impl<'i> Parse<'i> for ShapeRadius {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(length) = input.try_parse(LengthOrPercentage::parse) {
+            return Ok(ShapeRadius::Length(length));
+        }
+
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        match_ignore_ascii_case! { ident,
+            "closest-side" => Ok(ShapeRadius::ClosestSide),
+            "farthest-side" => Ok(ShapeRadius::FarthestSide),
+            _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+        }
+    }
+
+    fn expected() -> String {
+        String::from("<length> | <percentage> | closest-side | farthest-side")
+    }
+}
+
+impl<T: Into<LengthOrPercentage>> From<T> for ShapeRadius {
+    fn from(value: T) -> Self {
+        ShapeRadius::Length(value.into())
+    }
+}
+
+/// Parses the optional `at <position>` of a circle or ellipse, which defaults to the center.
+fn parse_shape_center<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Position, ParseError<'i, CustomParseError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("at")).is_ok() {
+        Position::parse(input)
+    } else {
+        Ok(Position::center())
+    }
+}
+
+/// Parses the optional fill rule, followed by a comma, of a polygon or path.
+fn parse_fill_rule(input: &mut Parser<'_, '_>) -> FillRule {
+    input
+        .try_parse(|input| {
+            let fill_rule = FillRule::parse(input)?;
+            input.expect_comma()?;
+            Ok::<_, ParseError<'_, CustomParseError<'_>>>(fill_rule)
+        })
+        .unwrap_or_default()
 }
 
 impl From<Rect<LengthOrPercentage>> for ClipPath {
@@ -48,6 +121,41 @@ impl<'i> Parse<'i> for ClipPath {
                         Ok(ClipPath::Shape(rect))
                     },
 
+                    "circle" => {
+                        let radius = input.try_parse(ShapeRadius::parse).unwrap_or_default();
+                        let center = parse_shape_center(input)?;
+                        Ok(ClipPath::Circle(radius, center))
+                    },
+
+                    "ellipse" => {
+                        let (radius_x, radius_y) = input
+                            .try_parse(|input| {
+                                Ok::<_, ParseError<'i, CustomParseError<'i>>>((
+                                    ShapeRadius::parse(input)?,
+                                    ShapeRadius::parse(input)?,
+                                ))
+                            })
+                            .unwrap_or_default();
+                        let center = parse_shape_center(input)?;
+                        Ok(ClipPath::Ellipse(radius_x, radius_y, center))
+                    },
+
+                    "polygon" => {
+                        let fill_rule = parse_fill_rule(input);
+                        let points = input.parse_comma_separated(|input| {
+                            let x = LengthOrPercentage::parse(input)?;
+                            let y = LengthOrPercentage::parse(input)?;
+                            Ok::<_, ParseError<'i, CustomParseError<'i>>>((x, y))
+                        })?;
+                        Ok(ClipPath::Polygon(fill_rule, points))
+                    },
+
+                    "path" => {
+                        let fill_rule = parse_fill_rule(input);
+                        let path = input.expect_string()?.to_string();
+                        Ok(ClipPath::Path(fill_rule, path))
+                    },
+
                     _ => {
                         Err(location.new_unexpected_token_error(Token::Ident(function)))
                     }
@@ -55,4 +163,52 @@ impl<'i> Parse<'i> for ClipPath {
             })
         }
     }
+
+    fn expected() -> String {
+        String::from("auto | inset() | rect() | circle() | ellipse() | polygon() | path()")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, HorizontalPosition, VerticalPosition};
+
+    assert_parse! {
+        ClipPath, assert_clip_path,
+
+        custom {
+            success {
+                "auto" => ClipPath::Auto,
+                "circle()" => ClipPath::Circle(ShapeRadius::ClosestSide, Position::center()),
+                "circle(50% at 10px 20px)" => ClipPath::Circle(
+                    ShapeRadius::Length(LengthOrPercentage::Percentage(50.0)),
+                    Position::new(
+                        HorizontalPosition::Length(Length::px(10.0).into()),
+                        VerticalPosition::Length(Length::px(20.0).into()),
+                    ),
+                ),
+                "ellipse(10px farthest-side)" => ClipPath::Ellipse(
+                    ShapeRadius::Length(Length::px(10.0).into()),
+                    ShapeRadius::FarthestSide,
+                    Position::center(),
+                ),
+                "polygon(evenodd, 0px 0px, 100% 0px, 50% 100%)" => ClipPath::Polygon(
+                    FillRule::EvenOdd,
+                    vec![
+                        (Length::px(0.0).into(), Length::px(0.0).into()),
+                        (LengthOrPercentage::Percentage(100.0), Length::px(0.0).into()),
+                        (LengthOrPercentage::Percentage(50.0), LengthOrPercentage::Percentage(100.0)),
+                    ],
+                ),
+                "path(\"M 0 0 L 10 10 Z\")" => ClipPath::Path(FillRule::NonZero, String::from("M 0 0 L 10 10 Z")),
+            }
+
+            failure {
+                "circle(at 10px 10px 10px)",
+                "polygon(0 0, 100%)",
+                "path(M 0 0)",
+            }
+        }
+    }
 }
@@ -66,6 +66,10 @@ impl_parse! {
     try_parse {
         cssparser_color::Color,
     }
+
+    expected {
+        "<color>"
+    }
 }
 
 impl From<RGBA> for Color {
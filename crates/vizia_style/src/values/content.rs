@@ -0,0 +1,73 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Parse};
+
+/// The content generated by a `::before` or `::after` pseudo-element.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Content {
+    /// No content is generated.
+    #[default]
+    None,
+    /// A text string. Multiple strings are concatenated.
+    Text(String),
+    /// An image or SVG, referenced by its path or by the name it was loaded with.
+    Url(String),
+}
+
+impl<'i> Parse<'i> for Content {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok()
+            || input.try_parse(|input| input.expect_ident_matching("normal")).is_ok()
+        {
+            return Ok(Content::None);
+        }
+
+        if let Ok(url) = input.try_parse(|input| input.expect_url().map(|url| url.to_string())) {
+            return Ok(Content::Url(url));
+        }
+
+        let mut text = input.expect_string()?.to_string();
+        while let Ok(string) = input.try_parse(|input| input.expect_string().cloned()) {
+            text.push_str(&string);
+        }
+
+        Ok(Content::Text(text))
+    }
+
+    fn expected() -> String {
+        String::from("none | normal | <string>+ | <url>")
+    }
+}
+
+impl From<&str> for Content {
+    fn from(s: &str) -> Self {
+        Content::Text(s.to_owned())
+    }
+}
+
+impl From<String> for Content {
+    fn from(s: String) -> Self {
+        Content::Text(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Content, ParseError<'_, CustomParseError<'_>>> {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        Content::parse(&mut parser)
+    }
+
+    #[test]
+    fn parse_content() {
+        assert_eq!(parse("none").unwrap(), Content::None);
+        assert_eq!(parse("normal").unwrap(), Content::None);
+        assert_eq!(parse("\"*\"").unwrap(), Content::Text(String::from("*")));
+        assert_eq!(parse("\"a\" 'b'").unwrap(), Content::Text(String::from("ab")));
+        assert_eq!(parse("url(icon.svg)").unwrap(), Content::Url(String::from("icon.svg")));
+        assert!(parse("12px").is_err());
+    }
+}
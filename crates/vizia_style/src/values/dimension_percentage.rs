@@ -40,6 +40,10 @@ impl<
 
         Err(input.new_error_for_next_token())
     }
+
+    fn expected() -> String {
+        format!("{} | <percentage> | {}", D::expected(), Calc::<DimensionPercentage<D>>::expected())
+    }
 }
 
 impl<D: std::ops::Mul<f32, Output = D>> std::ops::Mul<f32> for DimensionPercentage<D> {
@@ -14,6 +14,10 @@ impl_parse! {
             } if unit.as_ref().eq_ignore_ascii_case("ms") => Duration::from_millis(*value as u64),
         }
     }
+
+    expected {
+        "<time>"
+    }
 }
 
 #[cfg(test)]
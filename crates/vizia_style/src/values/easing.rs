@@ -1,7 +1,8 @@
-use crate::{CustomParseError, Parse};
+use crate::{CustomParseError, Parse, Percentage, StepPosition};
 use cssparser::*;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// Describes how an animation or transition progresses over its duration.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum EasingFunction {
     #[default]
     Linear,
@@ -10,7 +11,20 @@ pub enum EasingFunction {
     EaseOut,
     EaseInOut,
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    /// Jumps between a number of equally spaced steps.
+    Steps(u32, StepPosition),
+    /// Straight lines between a list of `(input, output)` points, as declared by `linear()` with
+    /// the omitted inputs filled in.
+    LinearPoints(Vec<(f32, f32)>),
+    /// A damped spring, whose motion determines the duration of the animation.
+    Spring {
+        /// How strongly the spring pulls towards the end value.
+        stiffness: f32,
+        /// How strongly the motion of the spring is resisted.
+        damping: f32,
+        /// The mass attached to the spring.
+        mass: f32,
+    },
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +37,8 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+              "step-end" => EasingFunction::Steps(1, StepPosition::JumpEnd),
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -44,16 +58,175 @@ impl<'i> Parse<'i> for EasingFunction {
                 let y2 = input.try_parse(|input| input.expect_number())?;
                 Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
+              "steps" => {
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  StepPosition::parse(input)
+                }).unwrap_or_default();
+
+                // A step function needs at least one jump.
+                let minimum = if position == StepPosition::JumpNone { 2 } else { 1 };
+                if count < minimum {
+                  return Err(location.new_custom_error(CustomParseError::InvalidValue));
+                }
+
+                Ok(EasingFunction::Steps(count as u32, position))
+              },
+              "linear" => {
+                let stops = input.parse_comma_separated(parse_linear_stop)?;
+                if stops.len() < 2 {
+                  return Err(location.new_custom_error(CustomParseError::InvalidValue));
+                }
+
+                Ok(EasingFunction::LinearPoints(linear_points(stops)))
+              },
+              "spring" => {
+                let stiffness = input.expect_number()?;
+                input.expect_comma()?;
+                let damping = input.expect_number()?;
+                let mass = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  input.expect_number()
+                }).unwrap_or(1.0);
+
+                if stiffness <= 0.0 || damping < 0.0 || mass <= 0.0 {
+                  return Err(location.new_custom_error(CustomParseError::InvalidValue));
+                }
+
+                Ok(EasingFunction::Spring { stiffness, damping, mass })
+              },
               _ => Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
+
+    fn expected() -> String {
+        String::from(
+            "linear | ease | ease-in | ease-out | ease-in-out | step-start | step-end | cubic-bezier() | steps() | linear() | spring()",
+        )
+    }
+}
+
+impl EasingFunction {
+    /// Returns true if the easing function is a spring.
+    pub fn is_spring(&self) -> bool {
+        matches!(self, EasingFunction::Spring { .. })
+    }
+}
+
+/// Parses a stop of a `linear()` function, which is an output value with up to two input
+/// percentages.
+fn parse_linear_stop<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<(f32, Vec<f32>), ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+
+    let mut output = None;
+    let mut inputs = Vec::new();
+
+    loop {
+        if output.is_none() {
+            if let Ok(number) = input.try_parse(|input| input.expect_number()) {
+                output = Some(number);
+                continue;
+            }
+        }
+
+        if inputs.len() < 2 {
+            if let Ok(percentage) = input.try_parse(Percentage::parse) {
+                inputs.push(percentage.0 / 100.0);
+                continue;
+            }
+        }
+
+        break;
+    }
+
+    match output {
+        Some(output) => Ok((output, inputs)),
+        None => Err(location.new_custom_error(CustomParseError::InvalidValue)),
+    }
+}
+
+/// Converts the stops of a `linear()` function into `(input, output)` points with increasing
+/// inputs, filling in any omitted inputs.
+fn linear_points(stops: Vec<(f32, Vec<f32>)>) -> Vec<(f32, f32)> {
+    let mut points = stops
+        .into_iter()
+        .flat_map(|(output, inputs)| {
+            if inputs.is_empty() {
+                vec![(None, output)]
+            } else {
+                inputs.into_iter().map(|input| (Some(input), output)).collect()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let last = points.len() - 1;
+    points[0].0.get_or_insert(0.0);
+    points[last].0.get_or_insert(1.0);
+
+    // Inputs can't be smaller than any input before them.
+    let mut largest = f32::MIN;
+    for (input, _) in points.iter_mut() {
+        if let Some(input) = input {
+            *input = input.max(largest);
+            largest = *input;
+        }
+    }
+
+    // Omitted inputs are spaced evenly between the inputs either side of them.
+    let mut start = 0;
+    for index in 1..points.len() {
+        if let Some(end_input) = points[index].0 {
+            let start_input = points[start].0.unwrap_or_default();
+            let gap = (index - start) as f32;
+            for (offset, point) in points[start + 1..index].iter_mut().enumerate() {
+                point.0 = Some(start_input + (end_input - start_input) * (offset + 1) as f32 / gap);
+            }
+            start = index;
+        }
+    }
+
+    points.into_iter().map(|(input, output)| (input.unwrap_or_default(), output)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, assert_easing_function,
+
+        custom {
+            success {
+                "ease-in" => EasingFunction::EaseIn,
+                "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+                "step-end" => EasingFunction::Steps(1, StepPosition::JumpEnd),
+                "cubic-bezier(0.1, 0.2, 0.3, 0.4)" => EasingFunction::CubicBezier(0.1, 0.2, 0.3, 0.4),
+                "steps(4)" => EasingFunction::Steps(4, StepPosition::JumpEnd),
+                "steps(4, jump-both)" => EasingFunction::Steps(4, StepPosition::JumpBoth),
+                "steps(2, jump-none)" => EasingFunction::Steps(2, StepPosition::JumpNone),
+                "linear(0, 1)" => EasingFunction::LinearPoints(vec![(0.0, 0.0), (1.0, 1.0)]),
+                "linear(0, 0.25, 1)" => EasingFunction::LinearPoints(vec![(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)]),
+                "linear(0, 0.5 25% 75%, 1)" => EasingFunction::LinearPoints(vec![(0.0, 0.0), (0.25, 0.5), (0.75, 0.5), (1.0, 1.0)]),
+                "linear(0, 1 50%, 0.5 25%, 1)" => EasingFunction::LinearPoints(vec![(0.0, 0.0), (0.5, 1.0), (0.5, 0.5), (1.0, 1.0)]),
+                "spring(170, 26)" => EasingFunction::Spring { stiffness: 170.0, damping: 26.0, mass: 1.0 },
+                "spring(100, 10, 2)" => EasingFunction::Spring { stiffness: 100.0, damping: 10.0, mass: 2.0 },
+            }
+
+            failure {
+                "steps(0)",
+                "steps(1, jump-none)",
+                "steps(2.5)",
+                "linear(0)",
+                "linear(50%)",
+                "spring(0, 10)",
+                "spring(100)",
+                "bounce",
+            }
+        }
+    }
 }
@@ -0,0 +1,13 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines which points are inside a shape whose outline intersects itself.
+    #[derive(Default)]
+    pub enum FillRule {
+        /// A point is inside the shape if the outline winds around it a non-zero number of times.
+        #[default]
+        "nonzero": NonZero,
+        /// A point is inside the shape if a ray from it crosses the outline an odd number of times.
+        "evenodd": EvenOdd,
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{Angle, Color, CustomParseError, Length, Parse, PercentageOrNumber, Shadow};
+use cssparser::*;
+
+/// A filter function applied to the rendering of a view, or to the backdrop behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Applies a gaussian blur with the given standard deviation.
+    Blur(Length),
+    /// Multiplies the brightness of the input.
+    Brightness(PercentageOrNumber),
+    /// Adjusts the contrast of the input.
+    Contrast(PercentageOrNumber),
+    /// Converts the input towards grayscale.
+    Grayscale(PercentageOrNumber),
+    /// Rotates the hue of the input.
+    HueRotate(Angle),
+    /// Inverts the colors of the input.
+    Invert(PercentageOrNumber),
+    /// Multiplies the alpha of the input.
+    Opacity(PercentageOrNumber),
+    /// Adjusts the saturation of the input.
+    Saturate(PercentageOrNumber),
+    /// Converts the input towards sepia.
+    Sepia(PercentageOrNumber),
+    /// Draws a blurred, offset and colored copy of the input's alpha mask behind it.
+    DropShadow(Shadow),
+}
+
+impl Filter {
+    /// Returns the value of the same filter function which leaves its input unchanged.
+    pub fn neutral(&self) -> Self {
+        match self {
+            Filter::Blur(_) => Filter::Blur(Length::px(0.0)),
+            Filter::Brightness(_) => Filter::Brightness(PercentageOrNumber::Number(1.0)),
+            Filter::Contrast(_) => Filter::Contrast(PercentageOrNumber::Number(1.0)),
+            Filter::Grayscale(_) => Filter::Grayscale(PercentageOrNumber::Number(0.0)),
+            Filter::HueRotate(_) => Filter::HueRotate(Angle::Deg(0.0)),
+            Filter::Invert(_) => Filter::Invert(PercentageOrNumber::Number(0.0)),
+            Filter::Opacity(_) => Filter::Opacity(PercentageOrNumber::Number(1.0)),
+            Filter::Saturate(_) => Filter::Saturate(PercentageOrNumber::Number(1.0)),
+            Filter::Sepia(_) => Filter::Sepia(PercentageOrNumber::Number(0.0)),
+            Filter::DropShadow(_) => Filter::DropShadow(Shadow::new(
+                Length::px(0.0),
+                Length::px(0.0),
+                Some(Length::px(0.0)),
+                None,
+                Some(Color::rgba(0, 0, 0, 0)),
+                false,
+            )),
+        }
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::Blur(Length::default())
+    }
+}
+
+impl<'i> Parse<'i> for Filter {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => Ok(Filter::Brightness(amount(input))),
+                "contrast" => Ok(Filter::Contrast(amount(input))),
+                "grayscale" => Ok(Filter::Grayscale(amount(input))),
+                "invert" => Ok(Filter::Invert(amount(input))),
+                "opacity" => Ok(Filter::Opacity(amount(input))),
+                "saturate" => Ok(Filter::Saturate(amount(input))),
+                "sepia" => Ok(Filter::Sepia(amount(input))),
+
+                "hue-rotate" => {
+                    Ok(Filter::HueRotate(input.try_parse(Angle::parse).unwrap_or(Angle::Deg(0.0))))
+                },
+
+                "drop-shadow" => {
+                    let mut color = input.try_parse(Color::parse).ok();
+                    let x_offset = Length::parse(input)?;
+                    let y_offset = Length::parse(input)?;
+                    let blur_radius = input.try_parse(Length::parse).ok();
+                    if color.is_none() {
+                        color = input.try_parse(Color::parse).ok();
+                    }
+
+                    let shadow = Shadow::new(x_offset, y_offset, blur_radius, None, color, false);
+                    Ok(Filter::DropShadow(shadow))
+                },
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+
+    fn expected() -> String {
+        String::from(
+            "blur() | brightness() | contrast() | grayscale() | invert() | opacity() | saturate() | sepia() | hue-rotate() | drop-shadow()",
+        )
+    }
+}
+
+/// Parses the optional amount of a filter function, which defaults to `1`.
+fn amount(input: &mut Parser<'_, '_>) -> PercentageOrNumber {
+    input.try_parse(PercentageOrNumber::parse).unwrap_or(PercentageOrNumber::Number(1.0))
+}
+
+impl<'i> Parse<'i> for Vec<Filter> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = vec![Filter::parse(input)?];
+        while !input.is_exhausted() {
+            results.push(Filter::parse(input)?);
+        }
+
+        Ok(results)
+    }
+
+    fn expected() -> String {
+        format!("none | [ {} ]+", Filter::expected())
+    }
+}
+
+impl From<Filter> for Vec<Filter> {
+    fn from(value: Filter) -> Self {
+        vec![value]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Filter, assert_filter,
+
+        custom {
+            success {
+                "blur(4px)" => Filter::Blur(Length::px(4.0)),
+                "brightness(150%)" => Filter::Brightness(PercentageOrNumber::Percentage(150.0)),
+                "contrast(2)" => Filter::Contrast(PercentageOrNumber::Number(2.0)),
+                "grayscale()" => Filter::Grayscale(PercentageOrNumber::Number(1.0)),
+                "hue-rotate(90deg)" => Filter::HueRotate(Angle::Deg(90.0)),
+                "drop-shadow(2px 4px 6px red)" => Filter::DropShadow(Shadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    Some(Length::px(6.0)),
+                    None,
+                    Some(Color::rgb(255, 0, 0)),
+                    false,
+                )),
+                "drop-shadow(red 2px 4px)" => Filter::DropShadow(Shadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    None,
+                    None,
+                    Some(Color::rgb(255, 0, 0)),
+                    false,
+                )),
+            }
+
+            failure {
+                "blur",
+                "glow(2px)",
+                "drop-shadow(red)",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<Filter>, assert_filter_list,
+
+        custom {
+            success {
+                "none" => vec![],
+                "blur(2px) sepia(50%)" => vec![
+                    Filter::Blur(Length::px(2.0)),
+                    Filter::Sepia(PercentageOrNumber::Percentage(50.0)),
+                ],
+            }
+
+            failure {
+                "blur(2px) none",
+                "blur(2px), sepia(50%)",
+            }
+        }
+    }
+}
@@ -22,6 +22,10 @@ impl_parse! {
             "monospace" => GenericFontFamily::Monospace,
         }
     }
+
+    expected {
+        "serif | sans-serif | cursive | fantasy | monospace"
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +50,10 @@ impl<'i> Parse<'i> for FontFamily<'i> {
             })?))
         }
     }
+
+    fn expected() -> String {
+        format!("{} | <family-name>", GenericFontFamily::expected())
+    }
 }
 
 impl<'i> Parse<'i> for Vec<FontFamily<'i>> {
@@ -54,4 +62,8 @@ impl<'i> Parse<'i> for Vec<FontFamily<'i>> {
     ) -> Result<Self, ParseError<'i, crate::CustomParseError<'i>>> {
         input.parse_comma_separated(FontFamily::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", FontFamily::expected())
+    }
 }
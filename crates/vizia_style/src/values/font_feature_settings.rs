@@ -0,0 +1,125 @@
+use cssparser::*;
+use skia_safe::FourByteTag;
+
+use crate::{CustomParseError, Parse};
+
+/// An OpenType font feature and the value it is set to.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct FontFeature {
+    /// The four character tag of the feature, e.g. `"tnum"`.
+    pub tag: FourByteTag,
+    /// The value of the feature, where `0` disables it and `1` enables it.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Creates a new font feature with the given tag and value.
+    pub fn new(tag: impl Into<FourByteTag>, value: u32) -> Self {
+        Self { tag: tag.into(), value }
+    }
+
+    /// Returns the tag of the feature as a string.
+    pub fn name(&self) -> String {
+        [self.tag.a(), self.tag.b(), self.tag.c(), self.tag.d()]
+            .into_iter()
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl<'i> Parse<'i> for FontFeature {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let tag = FourByteTag::parse(input)?;
+
+        if input.is_exhausted() {
+            return Ok(FontFeature { tag, value: 1 });
+        }
+
+        let location = input.current_source_location();
+        let value = match input.next()? {
+            Token::Number { int_value: Some(value), .. } if *value >= 0 => *value as u32,
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("on") => 1,
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("off") => 0,
+            _ => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+                    location,
+                })
+            }
+        };
+
+        Ok(FontFeature { tag, value })
+    }
+
+    fn expected() -> String {
+        String::from("<string> [ <integer> | on | off ]?")
+    }
+}
+
+impl<'i> Parse<'i> for Vec<FontFeature> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        input.parse_comma_separated(FontFeature::parse)
+    }
+
+    fn expected() -> String {
+        format!("normal | [ {} ]#", FontFeature::expected())
+    }
+}
+
+impl From<&str> for FontFeature {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        FontFeature::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        FontFeature, assert_font_feature,
+
+        custom {
+            success {
+                "\"tnum\"" => FontFeature::new(('t', 'n', 'u', 'm'), 1),
+                "\"tnum\" 0" => FontFeature::new(('t', 'n', 'u', 'm'), 0),
+                "\"liga\" off" => FontFeature::new(('l', 'i', 'g', 'a'), 0),
+                "\"ss02\" on" => FontFeature::new(('s', 's', '0', '2'), 1),
+                "\"salt\" 3" => FontFeature::new(('s', 'a', 'l', 't'), 3),
+            }
+
+            failure {
+                "tnum",
+                "\"tnu\"",
+                "\"tnum\" -1",
+                "\"tnum\" 1.5",
+            }
+        }
+    }
+
+    #[test]
+    fn parse_font_feature_list() {
+        let mut input = ParserInput::new("\"tnum\", \"liga\" off");
+        let mut parser = Parser::new(&mut input);
+        assert_eq!(
+            Vec::<FontFeature>::parse(&mut parser).unwrap(),
+            vec![
+                FontFeature::new(('t', 'n', 'u', 'm'), 1),
+                FontFeature::new(('l', 'i', 'g', 'a'), 0)
+            ]
+        );
+
+        let mut input = ParserInput::new("normal");
+        let mut parser = Parser::new(&mut input);
+        assert_eq!(Vec::<FontFeature>::parse(&mut parser).unwrap(), Vec::new());
+
+        assert_eq!(FontFeature::new(('t', 'n', 'u', 'm'), 1).name(), "tnum");
+    }
+}
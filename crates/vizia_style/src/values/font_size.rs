@@ -13,6 +13,10 @@ impl_parse! {
         FontSizeKeyword,
         f32,
     }
+
+    expected {
+        format!("{} | <number>", FontSizeKeyword::expected())
+    }
 }
 
 impl From<FontSizeKeyword> for FontSize {
@@ -22,12 +22,20 @@ impl<'i> Parse<'i> for FontVariation {
             })
         }
     }
+
+    fn expected() -> String {
+        String::from("<string> <number>")
+    }
 }
 
 impl<'i> Parse<'i> for Vec<FontVariation> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_comma_separated(FontVariation::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", FontVariation::expected())
+    }
 }
 
 impl<'i> Parse<'i> for FourByteTag {
@@ -48,6 +56,10 @@ impl<'i> Parse<'i> for FourByteTag {
 
         Ok(u32::from_be_bytes(bytes).into())
     }
+
+    fn expected() -> String {
+        String::from("<string>")
+    }
 }
 
 impl From<&str> for FontVariation {
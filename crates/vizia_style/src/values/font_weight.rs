@@ -14,6 +14,10 @@ impl_parse! {
         FontWeightKeyword,
         u16,
     }
+
+    expected {
+        format!("{} | <integer>", FontWeightKeyword::expected())
+    }
 }
 
 impl From<FontWeightKeyword> for FontWeight {
@@ -42,6 +42,12 @@ impl<'i> Parse<'i> for FontWidth {
             Err(_) => input.try_parse(Percentage::parse).map(|val| val.into()),
         }
     }
+
+    fn expected() -> String {
+        String::from(
+            "ultra-condensed | extra-condensed | condensed | semi-condensed | normal | semi-expanded | expanded | extra-expanded | ultra-expanded | <percentage>",
+        )
+    }
 }
 
 impl From<Percentage> for FontWidth {
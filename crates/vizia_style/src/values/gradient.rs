@@ -1,6 +1,6 @@
 use crate::{
-    Angle, Color, CustomParseError, HorizontalPositionKeyword, LengthOrPercentage, Parse, Position,
-    VerticalPositionKeyword,
+    Angle, AngleOrPercentage, Color, CustomParseError, HorizontalPositionKeyword,
+    LengthOrPercentage, Parse, Position, VerticalPositionKeyword,
 };
 use cssparser::*;
 
@@ -9,7 +9,15 @@ pub enum Gradient {
     #[default]
     None,
     Linear(LinearGradient),
+    /// A linear gradient whose color stops repeat along the gradient line.
+    RepeatingLinear(LinearGradient),
     Radial(RadialGradient),
+    /// A radial gradient whose color stops repeat outwards from the center.
+    RepeatingRadial(RadialGradient),
+    /// A gradient whose color stops are placed around a center point.
+    Conic(ConicGradient),
+    /// A conic gradient whose color stops repeat around the center point.
+    RepeatingConic(ConicGradient),
 }
 
 impl From<LinearGradient> for Gradient {
@@ -25,11 +33,21 @@ impl<'i> Parse<'i> for Gradient {
         input.parse_nested_block(|input| {
             match_ignore_ascii_case! { &func,
               "linear-gradient" => Ok(Gradient::Linear(LinearGradient::parse(input)?)),
+              "repeating-linear-gradient" => Ok(Gradient::RepeatingLinear(LinearGradient::parse(input)?)),
               "radial-gradient" => Ok(Gradient::Radial(RadialGradient::parse(input)?)),
+              "repeating-radial-gradient" => Ok(Gradient::RepeatingRadial(RadialGradient::parse(input)?)),
+              "conic-gradient" => Ok(Gradient::Conic(ConicGradient::parse(input)?)),
+              "repeating-conic-gradient" => Ok(Gradient::RepeatingConic(ConicGradient::parse(input)?)),
               _ => Err(location.new_unexpected_token_error(cssparser::Token::Ident(func.clone())))
             }
         })
     }
+
+    fn expected() -> String {
+        String::from(
+            "linear-gradient() | repeating-linear-gradient() | radial-gradient() | repeating-radial-gradient() | conic-gradient() | repeating-conic-gradient()",
+        )
+    }
 }
 
 impl From<&str> for Gradient {
@@ -75,6 +93,10 @@ impl<'i> Parse<'i> for LineDirection {
         }
         Ok(LineDirection::Vertical(y))
     }
+
+    fn expected() -> String {
+        String::from("<angle> | to [ left | right ] || [ top | bottom ]")
+    }
 }
 
 impl From<&str> for LineDirection {
@@ -127,6 +149,10 @@ impl<'i> Parse<'i> for LinearGradient {
         let stops = parse_items(input)?;
         Ok(LinearGradient { direction, stops })
     }
+
+    fn expected() -> String {
+        format!("[ {} , ]? <color-stop-list>", LineDirection::expected())
+    }
 }
 
 fn parse_items<'i, 't, D: Parse<'i>>(
@@ -134,24 +160,27 @@ fn parse_items<'i, 't, D: Parse<'i>>(
 ) -> Result<Vec<ColorStop<D>>, ParseError<'i, CustomParseError<'i>>> {
     let mut items = Vec::new();
     let mut seen_stop = false;
+    let mut hint = None;
 
     loop {
         input.parse_until_before(Delimiter::Comma, |input| {
-            // if seen_stop {
-            //     if let Ok(hint) = input.try_parse(D::parse) {
-            //         seen_stop = false;
-            //         items.push(GradientItem::Hint(hint));
-            //         return Ok(());
-            //     }
-            // }
+            // A transition hint may only appear between two color stops.
+            if seen_stop {
+                if let Ok(position) = input.try_parse(D::parse) {
+                    seen_stop = false;
+                    hint = Some(position);
+                    return Ok(());
+                }
+            }
 
-            let stop = ColorStop::parse(input)?;
+            let mut stop = ColorStop::parse(input)?;
+            stop.hint = hint.take();
 
             if let Ok(position) = input.try_parse(D::parse) {
                 let color = stop.color;
                 items.push(stop);
 
-                items.push(ColorStop { color, position: Some(position) })
+                items.push(ColorStop { color, position: Some(position), hint: None })
             } else {
                 items.push(stop);
             }
@@ -167,6 +196,10 @@ fn parse_items<'i, 't, D: Parse<'i>>(
         }
     }
 
+    if hint.is_some() {
+        return Err(input.new_custom_error(CustomParseError::InvalidValue));
+    }
+
     Ok(items)
 }
 
@@ -188,9 +221,9 @@ impl<'i> RadialGradient {
             })
             .ok();
 
-        // if shape.is_some() || position.is_some() {
-        //     input.expect_comma()?;
-        // }
+        if position.is_some() {
+            input.expect_comma()?;
+        }
 
         let stops = parse_items(input)?;
         Ok(RadialGradient {
@@ -201,28 +234,137 @@ impl<'i> RadialGradient {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    pub angle: Angle,
+    pub position: Position,
+    pub stops: Vec<ColorStop<AngleOrPercentage>>,
+}
+
+impl<'i> Parse<'i> for ConicGradient {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let angle = input
+            .try_parse(|input| {
+                input.expect_ident_matching("from")?;
+                Angle::parse(input)
+            })
+            .ok();
+
+        let position = input
+            .try_parse(|input| {
+                input.expect_ident_matching("at")?;
+                Position::parse(input)
+            })
+            .ok();
+
+        if angle.is_some() || position.is_some() {
+            input.expect_comma()?;
+        }
+
+        let stops = parse_items(input)?;
+        Ok(ConicGradient {
+            angle: angle.unwrap_or(Angle::Deg(0.0)),
+            position: position.unwrap_or(Position::center()),
+            stops,
+        })
+    }
+
+    fn expected() -> String {
+        String::from("[ from <angle> ]? [ at <position> ]? , <color-stop-list>")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ColorStop<D> {
     pub color: Color,
     pub position: Option<D>,
+    /// The position at which the transition from the previous stop reaches the midpoint color,
+    /// halfway between the two stops if `None`.
+    pub hint: Option<D>,
 }
 
 impl<'i, D: Parse<'i>> Parse<'i> for ColorStop<D> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let color = Color::parse(input)?;
         let position = input.try_parse(D::parse).ok();
-        Ok(ColorStop { color, position })
+        Ok(ColorStop { color, position, hint: None })
+    }
+
+    fn expected() -> String {
+        format!("<color> [ {} ]?", D::expected())
     }
 }
 
 impl<D> From<Color> for ColorStop<D> {
     fn from(value: Color) -> Self {
-        ColorStop { color: value, position: None }
+        ColorStop { color: value, position: None, hint: None }
     }
 }
 
 impl<D> From<(Color, D)> for ColorStop<D> {
     fn from(value: (Color, D)) -> Self {
-        ColorStop { color: value.0, position: Some(value.1) }
+        ColorStop { color: value.0, position: Some(value.1), hint: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, Length};
+
+    assert_parse! {
+        Gradient, assert_gradient,
+
+        custom {
+            success {
+                "repeating-linear-gradient(90deg, red, blue 20px)" => Gradient::RepeatingLinear(LinearGradient {
+                    direction: LineDirection::Angle(Angle::Deg(90.0)),
+                    stops: vec![
+                        ColorStop::from(Color::rgb(255, 0, 0)),
+                        ColorStop::from((Color::rgb(0, 0, 255), LengthOrPercentage::Length(Length::px(20.0)))),
+                    ],
+                }),
+                "linear-gradient(red, 30%, blue)" => Gradient::Linear(LinearGradient {
+                    direction: LineDirection::Vertical(VerticalPositionKeyword::Bottom),
+                    stops: vec![
+                        ColorStop::from(Color::rgb(255, 0, 0)),
+                        ColorStop {
+                            color: Color::rgb(0, 0, 255),
+                            position: None,
+                            hint: Some(LengthOrPercentage::Percentage(30.0)),
+                        },
+                    ],
+                }),
+                "radial-gradient(at center, red, blue)" => Gradient::Radial(RadialGradient {
+                    position: Position::center(),
+                    stops: vec![
+                        ColorStop::from(Color::rgb(255, 0, 0)),
+                        ColorStop::from(Color::rgb(0, 0, 255)),
+                    ],
+                }),
+                "conic-gradient(from 45deg, red, blue 50%)" => Gradient::Conic(ConicGradient {
+                    angle: Angle::Deg(45.0),
+                    position: Position::center(),
+                    stops: vec![
+                        ColorStop::from(Color::rgb(255, 0, 0)),
+                        ColorStop::from((Color::rgb(0, 0, 255), AngleOrPercentage::Percentage(50.0))),
+                    ],
+                }),
+                "repeating-conic-gradient(red 0deg, blue 30deg)" => Gradient::RepeatingConic(ConicGradient {
+                    angle: Angle::Deg(0.0),
+                    position: Position::center(),
+                    stops: vec![
+                        ColorStop::from((Color::rgb(255, 0, 0), AngleOrPercentage::Angle(Angle::Deg(0.0)))),
+                        ColorStop::from((Color::rgb(0, 0, 255), AngleOrPercentage::Angle(Angle::Deg(30.0)))),
+                    ],
+                }),
+            }
+
+            failure {
+                "linear-gradient(red, 30%)",
+                "linear-gradient(red, 30%, 40%, blue)",
+                "conic-gradient(from 45deg red, blue)",
+            }
+        }
     }
 }
@@ -33,6 +33,10 @@ impl<'i> Parse<'i> for BackgroundImage<'i> {
 
         Err(input.new_error_for_next_token())
     }
+
+    fn expected() -> String {
+        format!("none | {} | {}", Url::expected(), Gradient::expected())
+    }
 }
 
 impl From<Gradient> for BackgroundImage<'_> {
@@ -53,4 +57,8 @@ impl<'i> Parse<'i> for Vec<BackgroundImage<'i>> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_comma_separated(BackgroundImage::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", BackgroundImage::expected())
+    }
 }
@@ -0,0 +1,81 @@
+use crate::{macros::impl_parse, Parse};
+
+/// The number of times an animation plays before it ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IterationCount {
+    /// The animation repeats forever.
+    Infinite,
+    /// The animation plays the given, possibly fractional, number of times.
+    Count(f32),
+}
+
+impl Default for IterationCount {
+    fn default() -> Self {
+        IterationCount::Count(1.0)
+    }
+}
+
+impl IterationCount {
+    /// Returns the number of iterations, which is infinite for [`IterationCount::Infinite`].
+    pub fn to_f32(&self) -> f32 {
+        match self {
+            IterationCount::Infinite => f32::INFINITY,
+            IterationCount::Count(count) => *count,
+        }
+    }
+}
+
+impl_parse! {
+    IterationCount,
+
+    tokens {
+        ident {
+            "infinite" => IterationCount::Infinite,
+        }
+
+        custom {
+            cssparser::Token::Number { value, .. } if *value >= 0.0 => IterationCount::Count(*value),
+        }
+    }
+
+    expected {
+        "infinite | <number>"
+    }
+}
+
+impl From<f32> for IterationCount {
+    fn from(count: f32) -> Self {
+        IterationCount::Count(count)
+    }
+}
+
+impl From<u32> for IterationCount {
+    fn from(count: u32) -> Self {
+        IterationCount::Count(count as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        IterationCount, assert_iteration_count,
+
+        custom {
+            success {
+                "infinite" => IterationCount::Infinite,
+                "1" => IterationCount::Count(1.0),
+                "2.5" => IterationCount::Count(2.5),
+                "0" => IterationCount::Count(0.0),
+            }
+
+            failure {
+                "-1",
+                "1s",
+                "forever",
+            }
+        }
+    }
+}
@@ -14,6 +14,10 @@ impl_parse! {
             "auto" => AutoKeyword,
         }
     }
+
+    expected {
+        "auto"
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,10 @@ impl_parse! {
             "inset" => InsetKeyword,
         }
     }
+
+    expected {
+        "inset"
+    }
 }
 
 #[cfg(test)]
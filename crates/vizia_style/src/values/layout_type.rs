@@ -11,6 +11,10 @@ impl_parse! {
             "grid" => LayoutType::Grid,
         }
     }
+
+    expected {
+        "row | column | grid"
+    }
 }
 
 #[cfg(test)]
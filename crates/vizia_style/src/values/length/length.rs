@@ -34,6 +34,10 @@ impl_parse! {
             Ok(Length::Value(length))
         }
     }
+
+    expected {
+        "<length>"
+    }
 }
 
 impl std::ops::Mul<f32> for Length {
@@ -22,6 +22,10 @@ impl_parse! {
             })
         }
     }
+
+    expected {
+        "<length>"
+    }
 }
 
 #[cfg(test)]
@@ -97,6 +97,10 @@ impl_parse! {
             "vmax" => LengthValue::Vmax,
         }
     }
+
+    expected {
+        "<length>"
+    }
 }
 
 impl LengthValue {
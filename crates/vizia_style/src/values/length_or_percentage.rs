@@ -55,6 +55,10 @@ impl_parse! {
         Length,
         Percentage,
     }
+
+    expected {
+        "<length> | <percentage>"
+    }
 }
 
 impl From<LengthValue> for LengthOrPercentage {
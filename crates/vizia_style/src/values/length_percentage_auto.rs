@@ -40,6 +40,10 @@ impl_parse! {
         LengthOrPercentage,
         AutoKeyword,
     }
+
+    expected {
+        "<length> | <percentage> | auto"
+    }
 }
 
 impl From<LengthValue> for LengthPercentageOrAuto {
@@ -0,0 +1,101 @@
+use crate::{CustomParseError, LengthOrPercentage, Parse};
+use cssparser::*;
+
+/// The height of each line of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum LineHeight {
+    /// The line height is determined by the metrics of the font.
+    #[default]
+    Normal,
+    /// The line height is a multiple of the font size.
+    Number(f32),
+    /// The line height is a fixed length, or a percentage of the font size.
+    Length(LengthOrPercentage),
+}
+
+impl LineHeight {
+    /// Returns the line height as a multiple of the font size, or `None` for [`LineHeight::Normal`].
+    pub fn to_factor(&self, font_size: f32, scale: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Number(number) => Some(*number),
+            LineHeight::Length(length) => {
+                if font_size <= 0.0 {
+                    return None;
+                }
+
+                Some(length.to_pixels(font_size, scale) / font_size)
+            }
+        }
+    }
+}
+
+impl<'i> Parse<'i> for LineHeight {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+            return Ok(LineHeight::Normal);
+        }
+
+        let location = input.current_source_location();
+
+        if let Ok(number) = input.try_parse(f32::parse) {
+            if number < 0.0 {
+                return Err(location.new_custom_error(CustomParseError::InvalidValue));
+            }
+
+            return Ok(LineHeight::Number(number));
+        }
+
+        Ok(LineHeight::Length(LengthOrPercentage::parse(input)?))
+    }
+
+    fn expected() -> String {
+        String::from("normal | <number> | <length> | <percentage>")
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(number: f32) -> Self {
+        LineHeight::Number(number)
+    }
+}
+
+impl From<LengthOrPercentage> for LineHeight {
+    fn from(length: LengthOrPercentage) -> Self {
+        LineHeight::Length(length)
+    }
+}
+
+impl From<&str> for LineHeight {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        LineHeight::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LineHeight, assert_line_height,
+
+        custom {
+            success {
+                "normal" => LineHeight::Normal,
+                "1.5" => LineHeight::Number(1.5),
+                "0" => LineHeight::Number(0.0),
+                "20px" => LineHeight::Length(LengthOrPercentage::px(20.0)),
+                "150%" => LineHeight::Length(LengthOrPercentage::Percentage(150.0)),
+            }
+
+            failure {
+                "-1",
+                "auto",
+                "1.5s",
+            }
+        }
+    }
+}
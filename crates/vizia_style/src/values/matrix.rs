@@ -43,6 +43,10 @@ where
             })
         }
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#{{6}}", T::expected())
+    }
 }
 
 impl<T> From<(T, T, T, T, T, T)> for Matrix<T> {
@@ -1,7 +1,11 @@
 pub mod alignment;
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
+pub mod angle_or_percentage;
+pub mod animation_direction;
+pub mod animation_fill_mode;
+pub mod animation_name;
+pub mod animation_play_state;
 pub mod background_size;
 pub mod basic;
 pub mod blend_mode;
@@ -9,6 +13,7 @@ pub mod border;
 pub mod calc;
 pub mod clip;
 pub mod color;
+pub mod content;
 pub mod cursor_icon;
 pub mod custom;
 pub mod dimension_percentage;
@@ -16,7 +21,10 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod fill_rule;
+pub mod filter;
 pub mod font_family;
+pub mod font_feature_settings;
 pub mod font_size;
 pub mod font_size_keyword;
 pub mod font_slant;
@@ -27,11 +35,13 @@ pub mod font_width;
 pub mod gradient;
 pub mod horizontal_position_keyword;
 pub mod image;
+pub mod iteration_count;
 pub mod keywords;
 pub mod layout_type;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod line_height;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -44,11 +54,14 @@ pub mod rect;
 pub mod rotate;
 pub mod scale;
 pub mod shadow;
+pub mod spacing;
+pub mod step_position;
 pub mod stretch;
 pub mod text_align;
 pub mod text_decoration;
 pub mod text_overflow;
 pub mod text_stroke;
+pub mod text_transform;
 pub mod transform;
 pub mod transition;
 pub mod translate;
@@ -60,7 +73,11 @@ pub mod visibility;
 pub use alignment::*;
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
+pub use angle_or_percentage::*;
+pub use animation_direction::*;
+pub use animation_fill_mode::*;
+pub use animation_name::*;
+pub use animation_play_state::*;
 pub use background_size::*;
 pub use basic::*;
 pub use blend_mode::*;
@@ -68,6 +85,7 @@ pub use border::*;
 pub use calc::*;
 pub use clip::*;
 pub use color::*;
+pub use content::*;
 pub use cursor_icon::*;
 pub use custom::*;
 pub use dimension_percentage::*;
@@ -75,7 +93,10 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use fill_rule::*;
+pub use filter::*;
 pub use font_family::*;
+pub use font_feature_settings::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
 pub use font_slant::*;
@@ -86,11 +107,13 @@ pub use font_width::*;
 pub use gradient::*;
 pub use horizontal_position_keyword::*;
 pub use image::*;
+pub use iteration_count::*;
 pub use keywords::*;
 pub use layout_type::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use line_height::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
@@ -103,11 +126,14 @@ pub use rect::*;
 pub use rotate::*;
 pub use scale::*;
 pub use shadow::*;
+pub use spacing::*;
+pub use step_position::*;
 pub use stretch::*;
 pub use text_align::*;
 pub use text_decoration::*;
 pub use text_overflow::*;
 pub use text_stroke::*;
+pub use text_transform::*;
 pub use transform::*;
 pub use transition::*;
 pub use translate::*;
@@ -41,6 +41,10 @@ impl_parse! {
         Percentage,
         f32,
     }
+
+    expected {
+        "<number> | <percentage>"
+    }
 }
 
 impl From<Percentage> for PercentageOrNumber {
@@ -19,6 +19,10 @@ impl_parse! {
         Percentage,
         f32,
     }
+
+    expected {
+        "<number> | <percentage>"
+    }
 }
 
 impl From<&str> for Opacity {
@@ -76,6 +76,10 @@ impl<'i> Parse<'i> for Position {
             return Ok(Position { x: HorizontalPosition::Center, y: VerticalPosition::Center });
         }
     }
+
+    fn expected() -> String {
+        format!("[ {} ] [ {} ]?", HorizontalPosition::expected(), VerticalPosition::expected())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -119,6 +123,10 @@ impl<'i, S: Parse<'i> + Copy + Into<LengthOrPercentage>> Parse<'i> for PositionC
         let keyword = S::parse(input)?;
         Ok(PositionComponent::Side(keyword))
     }
+
+    fn expected() -> String {
+        format!("center | <length> | <percentage> | {}", S::expected())
+    }
 }
 
 impl From<HorizontalPositionKeyword> for LengthOrPercentage {
@@ -10,6 +10,10 @@ impl_parse! {
             "relative" => PositionType::Relative,
         }
     }
+
+    expected {
+        "absolute | relative"
+    }
 }
 
 #[cfg(test)]
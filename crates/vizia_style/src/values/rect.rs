@@ -51,6 +51,10 @@ where
             })
         }
     }
+
+    fn expected() -> String {
+        format!("[ {} ]{{1,4}}", T::expected())
+    }
 }
 
 impl<T: Default> Default for Rect<T> {
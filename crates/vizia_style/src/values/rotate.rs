@@ -22,6 +22,10 @@ impl_parse! {
             Ok(Rotate { angle })
         }
     }
+
+    expected {
+        "<angle>"
+    }
 }
 
 impl<T: Into<Angle>> From<T> for Rotate {
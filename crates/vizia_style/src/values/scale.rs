@@ -37,6 +37,10 @@ impl_parse! {
             }
         }
     }
+
+    expected {
+        "[ <number> | <percentage> ]{1,2}"
+    }
 }
 
 impl From<&str> for Scale {
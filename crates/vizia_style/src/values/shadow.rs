@@ -50,12 +50,20 @@ impl<'i> Parse<'i> for Shadow {
 
         Ok(Shadow::new(x_offset, y_offset, blur_radius, spread_radius, color, inset))
     }
+
+    fn expected() -> String {
+        String::from("<length>{2,4} <color>? inset?")
+    }
 }
 
 impl<'i> Parse<'i> for Vec<Shadow> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_comma_separated(Shadow::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", Shadow::expected())
+    }
 }
 
 impl From<&str> for Shadow {
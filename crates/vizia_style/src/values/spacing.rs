@@ -0,0 +1,84 @@
+use crate::{macros::impl_parse, Length, LengthOrPercentage, Parse};
+use cssparser::*;
+
+/// Additional spacing between letters or words of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Spacing {
+    /// No additional spacing is added.
+    #[default]
+    Normal,
+    /// A fixed amount of spacing, or a percentage of the font size.
+    Length(LengthOrPercentage),
+}
+
+impl Spacing {
+    /// Returns the spacing in pixels, resolving percentages against the font size.
+    pub fn to_pixels(&self, font_size: f32, scale: f32) -> f32 {
+        match self {
+            Spacing::Normal => 0.0,
+            Spacing::Length(length) => length.to_pixels(font_size, scale),
+        }
+    }
+}
+
+impl_parse! {
+    Spacing,
+
+    custom {
+        |input| {
+            if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+                return Ok(Spacing::Normal);
+            }
+
+            Ok(Spacing::Length(LengthOrPercentage::parse(input)?))
+        }
+    }
+
+    expected {
+        "normal | <length> | <percentage>"
+    }
+}
+
+impl From<LengthOrPercentage> for Spacing {
+    fn from(length: LengthOrPercentage) -> Self {
+        Spacing::Length(length)
+    }
+}
+
+impl From<Length> for Spacing {
+    fn from(length: Length) -> Self {
+        Spacing::Length(length.into())
+    }
+}
+
+impl From<&str> for Spacing {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        Spacing::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Spacing, assert_spacing,
+
+        custom {
+            success {
+                "normal" => Spacing::Normal,
+                "2px" => Spacing::Length(LengthOrPercentage::px(2.0)),
+                "-1px" => Spacing::Length(LengthOrPercentage::px(-1.0)),
+                "10%" => Spacing::Length(LengthOrPercentage::Percentage(10.0)),
+            }
+
+            failure {
+                "wide",
+                "2s",
+            }
+        }
+    }
+}
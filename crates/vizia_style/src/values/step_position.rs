@@ -0,0 +1,71 @@
+use crate::{macros::impl_parse, Parse};
+
+/// Determines where the jumps of a `steps()` easing function occur.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins.
+    JumpStart,
+    /// The last jump happens when the animation ends.
+    #[default]
+    JumpEnd,
+    /// There is no jump at either end, so the first and last steps are held.
+    JumpNone,
+    /// There is a jump both when the animation begins and when it ends.
+    JumpBoth,
+}
+
+impl StepPosition {
+    /// Returns the number of jumps made by a `steps()` function with the given number of steps.
+    pub fn jumps(&self, count: u32) -> u32 {
+        match self {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => count.saturating_sub(1),
+            StepPosition::JumpBoth => count + 1,
+        }
+    }
+}
+
+impl_parse! {
+    StepPosition,
+
+    tokens {
+        ident {
+            "jump-start" => StepPosition::JumpStart,
+            "start" => StepPosition::JumpStart,
+            "jump-end" => StepPosition::JumpEnd,
+            "end" => StepPosition::JumpEnd,
+            "jump-none" => StepPosition::JumpNone,
+            "jump-both" => StepPosition::JumpBoth,
+        }
+    }
+
+    expected {
+        "jump-start | jump-end | jump-none | jump-both | start | end"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        StepPosition, assert_step_position,
+
+        custom {
+            success {
+                "jump-start" => StepPosition::JumpStart,
+                "start" => StepPosition::JumpStart,
+                "jump-end" => StepPosition::JumpEnd,
+                "end" => StepPosition::JumpEnd,
+                "jump-none" => StepPosition::JumpNone,
+                "jump-both" => StepPosition::JumpBoth,
+            }
+
+            failure {
+                "middle",
+                "1",
+            }
+        }
+    }
+}
@@ -12,6 +12,10 @@ impl_parse! {
             "s" => Stretch,
         }
     }
+
+    expected {
+        "<stretch>"
+    }
 }
 
 impl From<f32> for Stretch {
@@ -57,6 +57,15 @@ impl<'i> Parse<'i> for TextDecoration {
             color: color.unwrap_or(Color::CurrentColor),
         })
     }
+
+    fn expected() -> String {
+        format!(
+            "{} || {} || [ <length> | <percentage> ] || {}",
+            TextDecorationLine::expected(),
+            TextDecorationStyle::expected(),
+            Color::expected()
+        )
+    }
 }
 
 bitflags! {
@@ -111,6 +120,10 @@ impl<'i> Parse<'i> for TextDecorationLine {
 
         Ok(value)
     }
+
+    fn expected() -> String {
+        String::from("none | [ underline || overline || strikethrough ]")
+    }
 }
 
 impl From<TextDecorationLine> for skia_safe::textlayout::TextDecoration {
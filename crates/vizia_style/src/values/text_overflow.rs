@@ -28,6 +28,10 @@ impl_parse! {
     try_parse {
         u32,
     }
+
+    expected {
+        "<integer>"
+    }
 }
 
 impl From<&str> for LineClamp {
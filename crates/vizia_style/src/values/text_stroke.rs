@@ -35,6 +35,10 @@ impl_parse! {
             Ok(TextStroke::new(width, style))
         }
     }
+
+    expected {
+        format!("<length> [ {} ]?", TextStrokeStyle::expected())
+    }
 }
 
 impl From<TextStrokeStyle> for skia_safe::PaintStyle {
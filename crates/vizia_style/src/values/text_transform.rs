@@ -0,0 +1,92 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how the capitalization of text is changed before it is displayed.
+    #[derive(Default)]
+    pub enum TextTransform {
+        /// The text is displayed as written.
+        #[default]
+        "none": None,
+        /// All characters are converted to uppercase.
+        "uppercase": Uppercase,
+        /// All characters are converted to lowercase.
+        "lowercase": Lowercase,
+        /// The first letter of each word is converted to uppercase.
+        "capitalize": Capitalize,
+    }
+}
+
+impl TextTransform {
+    /// Applies the transform to the given text.
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            TextTransform::None => text.to_owned(),
+            TextTransform::Uppercase => text.to_uppercase(),
+            TextTransform::Lowercase => text.to_lowercase(),
+            TextTransform::Capitalize => {
+                let mut result = String::with_capacity(text.len());
+                let mut word_start = true;
+                for c in text.chars() {
+                    if word_start && c.is_alphabetic() {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.push(c);
+                    }
+
+                    word_start = !c.is_alphanumeric() && c != '\'' && c != '\u{2019}';
+                }
+                result
+            }
+        }
+    }
+
+    /// Applies the transform to each character of the given text, returning the byte offset of each
+    /// character along with the text it is transformed into. A character can be transformed into
+    /// several characters, e.g. `ß` is `SS` in uppercase.
+    pub fn apply_chars(&self, text: &str) -> Vec<(usize, String)> {
+        let mut word_start = true;
+        text.char_indices()
+            .map(|(offset, c)| {
+                let transformed = match self {
+                    TextTransform::Uppercase => c.to_uppercase().collect(),
+                    TextTransform::Lowercase => c.to_lowercase().collect(),
+                    TextTransform::Capitalize if word_start && c.is_alphabetic() => {
+                        c.to_uppercase().collect()
+                    }
+                    _ => c.to_string(),
+                };
+
+                word_start = !c.is_alphanumeric() && c != '\'' && c != '\u{2019}';
+                (offset, transformed)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod apply_tests {
+    use super::*;
+
+    #[test]
+    fn apply_text_transform() {
+        assert_eq!(TextTransform::Uppercase.apply("Hello world"), "HELLO WORLD");
+        assert_eq!(TextTransform::Lowercase.apply("Hello World"), "hello world");
+        assert_eq!(
+            TextTransform::Capitalize.apply("hello wide-world, it's 3am"),
+            "Hello Wide-World, It's 3am"
+        );
+    }
+
+    #[test]
+    fn apply_text_transform_by_char() {
+        let chars = TextTransform::Uppercase.apply_chars("aßé");
+        assert_eq!(
+            chars,
+            vec![(0, String::from("A")), (1, String::from("SS")), (3, String::from("É"))]
+        );
+
+        let capitalized = TextTransform::Capitalize.apply_chars("it's ok");
+        let capitalized = capitalized.into_iter().map(|(_, c)| c).collect::<String>();
+        assert_eq!(capitalized, TextTransform::Capitalize.apply("it's ok"));
+    }
+}
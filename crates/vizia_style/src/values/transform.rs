@@ -110,6 +110,12 @@ impl<'i> Parse<'i> for Transform {
             }
         })
     }
+
+    fn expected() -> String {
+        String::from(
+            "translate() | translatex() | translatey() | scale() | scalex() | scaley() | rotate() | skew() | skewx() | skewy() | matrix()",
+        )
+    }
 }
 
 impl<'i> Parse<'i> for Vec<Transform> {
@@ -133,6 +139,10 @@ impl<'i> Parse<'i> for Vec<Transform> {
             }
         }
     }
+
+    fn expected() -> String {
+        format!("[ {} ]+", Transform::expected())
+    }
 }
 
 impl From<Transform> for Vec<Transform> {
@@ -31,11 +31,20 @@ impl<'i> Parse<'i> for Transition {
         let location = input.current_source_location();
 
         let property = Ident::parse(input)?.into();
-        let duration = Duration::parse(input)?;
+        let duration = input.try_parse(Duration::parse).ok();
         let delay = input.try_parse(Duration::parse).ok();
         let timing_function = input.try_parse(EasingFunction::parse).ok();
 
-        if input.is_exhausted() {
+        // The duration of a spring transition comes from the spring, so it may be omitted.
+        let duration = match duration {
+            Some(duration) => Some(duration),
+            None if timing_function.as_ref().is_some_and(EasingFunction::is_spring) => {
+                Some(Duration::ZERO)
+            }
+            None => None,
+        };
+
+        if let (Some(duration), true) = (duration, input.is_exhausted()) {
             Ok(Self { property, duration, delay, timing_function })
         } else {
             Err(ParseError {
@@ -44,18 +53,27 @@ impl<'i> Parse<'i> for Transition {
             })
         }
     }
+
+    fn expected() -> String {
+        format!("<ident> <time>? <time>? [ {} ]?", EasingFunction::expected())
+    }
 }
 
 impl<'i> Parse<'i> for Vec<Transition> {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_comma_separated(Transition::parse)
     }
+
+    fn expected() -> String {
+        format!("[ {} ]#", Transition::expected())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tests::assert_parse;
+    use crate::StepPosition;
 
     assert_parse! {
         Transition, assert_transition,
@@ -65,11 +83,14 @@ mod tests {
                 "width 2s" => Transition::new(String::from("width"), Duration::from_secs(2), None, None),
                 "height 2s 1s" => Transition::new(String::from("height"), Duration::from_secs(2), Some(Duration::from_secs(1)), None),
                 "color 200ms linear" => Transition::new(String::from("color"), Duration::from_millis(200), None, Some(EasingFunction::Linear)),
+                "left spring(170, 26)" => Transition::new(String::from("left"), Duration::ZERO, None, Some(EasingFunction::Spring { stiffness: 170.0, damping: 26.0, mass: 1.0 })),
+                "opacity 1s steps(4, jump-start)" => Transition::new(String::from("opacity"), Duration::from_secs(1), None, Some(EasingFunction::Steps(4, StepPosition::JumpStart))),
             }
 
             failure {
                 "height 2s 1s 1s",
                 "1s 2s height",
+                "width linear",
             }
         }
     }
@@ -26,6 +26,10 @@ impl_parse! {
             Ok(Translate { x, y })
         }
     }
+
+    expected {
+        "[ <length> | <percentage> ]{1,2}"
+    }
 }
 
 impl<T: Into<LengthOrPercentage>> From<T> for Translate {
@@ -13,6 +13,10 @@ impl_parse! {
         Percentage,
         LengthPixels,
     }
+
+    expected {
+        "<length> | <percentage> | <stretch> | auto"
+    }
 }
 
 impl<'i> Parse<'i> for Vec<Units> {
@@ -24,6 +28,10 @@ impl<'i> Parse<'i> for Vec<Units> {
 
         Ok(values)
     }
+
+    fn expected() -> String {
+        format!("[{}]+", Units::expected())
+    }
 }
 
 impl From<AutoKeyword> for Units {
@@ -12,6 +12,10 @@ impl<'i> Parse<'i> for Url<'i> {
         let url = input.expect_url_or_string()?;
         Ok(Url { url })
     }
+
+    fn expected() -> String {
+        String::from("<url>")
+    }
 }
 
 impl<'a> From<&'a str> for Url<'a> {
@@ -18,6 +18,23 @@ fn main() -> Result<(), ApplicationError> {
                 .class("span");
         })
         .class("testy");
+
+        Label::rich(
+            cx,
+            "Links within a paragraph can be hovered, pressed, or focused with Tab: ",
+            |cx| {
+                TextSpan::link(cx, "https://github.com/vizia/vizia", |cx| {
+                    TextSpan::new(cx, "a link which wraps across more than one line", |_| {});
+                });
+                TextSpan::new(cx, ", followed by ", |_| {});
+                TextSpan::link(cx, "https://docs.rs/vizia", |cx| {
+                    TextSpan::new(cx, "another link", |_| {});
+                });
+                TextSpan::new(cx, ".", |_| {});
+            },
+        )
+        .width(Pixels(300.0))
+        .text_wrap(true);
     })
     .run()
 }
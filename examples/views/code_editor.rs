@@ -0,0 +1,105 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+const SOURCE: &str = r#"/* A small program
+   which greets the world. */
+fn main() {
+    let greeting = "Hello World";
+    for i in 0..3 {
+        // Print the greeting three times.
+        println!("{}: {}", i, greeting);
+    }
+}
+"#;
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "else", "enum", "false", "fn", "for", "if", "impl", "in",
+    "let", "loop", "match", "mod", "mut", "pub", "return", "self", "Self", "struct", "true", "use",
+    "where", "while",
+];
+
+/// The state of a line which ends within a block comment.
+const IN_COMMENT: HighlightState = HighlightState(1);
+
+/// A simple highlighter for Rust source code.
+fn highlight_rust(line: &str, state: &mut HighlightState) -> Vec<CodeToken> {
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < line.len() {
+        let rest = &line[index..];
+        let start = index;
+        if *state == IN_COMMENT {
+            index = match rest.find("*/") {
+                Some(end) => {
+                    *state = HighlightState::default();
+                    index + end + 2
+                }
+                None => line.len(),
+            };
+            tokens.push(CodeToken::new(start..index, TokenKind::Comment));
+        } else if rest.starts_with("//") {
+            index = line.len();
+            tokens.push(CodeToken::new(start..index, TokenKind::Comment));
+        } else if rest.starts_with("/*") {
+            *state = IN_COMMENT;
+            index += 2;
+            tokens.push(CodeToken::new(start..index, TokenKind::Comment));
+        } else if rest.starts_with('"') {
+            let mut escaped = false;
+            let end = rest[1..].find(|c: char| {
+                let end = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                end
+            });
+            index = end.map_or(line.len(), |end| index + end + 2);
+            tokens.push(CodeToken::new(start..index, TokenKind::String));
+        } else {
+            let c = rest.chars().next().unwrap();
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len())
+                .max(c.len_utf8());
+            index += len;
+            if c.is_ascii_digit() {
+                tokens.push(CodeToken::new(start..index, TokenKind::Number));
+            } else if KEYWORDS.contains(&&rest[..len]) {
+                tokens.push(CodeToken::new(start..index, TokenKind::Keyword));
+            }
+        }
+    }
+
+    tokens
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    source: Rope,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetSource(source) => self.source = source.clone(),
+        });
+    }
+}
+
+pub enum AppEvent {
+    SetSource(Rope),
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData { source: Rope::from_str(SOURCE) }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            CodeEditor::new(cx, AppData::source)
+                .size(Stretch(1.0))
+                .highlighter(highlight_rust)
+                .on_edit(|cx, source| cx.emit(AppEvent::SetSource(source)));
+        });
+    })
+    .title("Code Editor")
+    .run()
+}
@@ -47,6 +47,8 @@ fn main() -> Result<(), ApplicationError> {
                 .text_wrap(false)
                 .font_slant(FontSlant::Italic);
 
+            Label::new(cx, "Selectable text can be copied with Ctrl+C.").selectable(true);
+
             HStack::new(cx, |cx| {
                 Checkbox::new(cx, AppData::checked)
                     .on_toggle(|cx| cx.emit(AppEvent::Toggle))
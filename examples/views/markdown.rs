@@ -1,49 +1,108 @@
 use vizia::prelude::*;
 
-#[derive(Lens)]
-pub struct AppData {}
-
-impl Model for AppData {}
-
-fn main() -> Result<(), ApplicationError> {
-    Application::new(|cx| {
-        AppData {}.build(cx);
-
-        //         Markdown::new(
-        //             cx,
-        //             r#"
-        // # This is a heading
-
-        // ## This is a subheading
-
-        // This is **some strong text**. ~~And some more text~~.
+const DOCUMENT: &str = r#"# Markdown
 
-        // - List A
-        //   - List A1
-        //     - Item
-        //   - List A2
-        //     - Item with *emphasis*
+This is **some strong text**. ~~And some more text~~.
 
-        // ```
-        // fn some_code() {
+> A blockquote with *emphasis*.
 
-        // }
-        // ```
+- [x] A completed task
+- [ ] A task which still needs doing
 
-        // A [link](https://github.com/vizia/vizia)
-        // "#,
-        //         );
-        ScrollView::new(cx, |cx| {
-            Markdown::new(
-                cx,
-                "
 | Month    | Savings |
 | -------- | ------- |
 | January  | $250    |
 | February | $80     |
 | March    | $420    |
-",
-            );
+
+---
+
+A [link](https://github.com/vizia/vizia) and a footnote.[^1]
+
+```rust
+// Code blocks are highlighted by the highlighter for their language.
+fn main() {
+    let answer = 42;
+    println!("The answer is {answer}");
+}
+```
+
+[^1]: The text of the footnote.
+"#;
+
+const KEYWORDS: &[&str] = &["fn", "let", "mut", "if", "else", "for", "in", "return", "pub", "use"];
+
+/// A minimal highlighter for rust which finds comments, strings, numbers, keywords and macros.
+fn highlight_rust(line: &str, _: &mut HighlightState) -> Vec<CodeToken> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+        let kind = if line[start..].starts_with("//") {
+            end = line.len();
+            TokenKind::Comment
+        } else if c == '"' {
+            end = line[end..].find('"').map_or(line.len(), |len| end + len + 1);
+            TokenKind::String
+        } else if c.is_ascii_digit() {
+            end = line[start..]
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .map_or(line.len(), |len| start + len);
+            TokenKind::Number
+        } else if c.is_alphabetic() || c == '_' {
+            end = line[start..]
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .map_or(line.len(), |len| start + len);
+            if line[end..].starts_with('!') {
+                end += 1;
+                TokenKind::Macro
+            } else if KEYWORDS.contains(&&line[start..end]) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Variable
+            }
+        } else {
+            continue;
+        };
+
+        tokens.push(CodeToken::new(start..end, kind));
+        while chars.next_if(|(index, _)| *index < end).is_some() {}
+    }
+
+    tokens
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    document: String,
+}
+
+pub enum AppEvent {
+    SetDocument(String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetDocument(document) => self.document = document.clone(),
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_code_highlighter("rust", highlight_rust);
+
+        AppData { document: String::from(DOCUMENT) }.build(cx);
+
+        HStack::new(cx, |cx| {
+            Textbox::new_multiline(cx, AppData::document, true)
+                .size(Stretch(1.0))
+                .on_edit(|cx, text| cx.emit(AppEvent::SetDocument(text)));
+
+            ScrollView::new(cx, |cx| {
+                Markdown::new(cx, AppData::document).selectable(true);
+            });
         });
     })
     .title("Markdown")
@@ -0,0 +1,57 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    text: RichText,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetText(text) => self.text = text.clone(),
+        });
+    }
+}
+
+pub enum AppEvent {
+    SetText(RichText),
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        let text = RichText::from("Select some text and press Ctrl+B for ")
+            .with("bold", RunStyle { bold: true, ..Default::default() })
+            .with(", Ctrl+I for ", RunStyle::default())
+            .with("italic", RunStyle { italic: true, ..Default::default() })
+            .with(" or Ctrl+U for ", RunStyle::default())
+            .with("underlined", RunStyle { underline: true, ..Default::default() })
+            .with(" text.\nText can also be ", RunStyle::default())
+            .with(
+                "colored",
+                RunStyle { color: Some(Color::rgb(220, 80, 60)), ..Default::default() },
+            )
+            .with(" or ", RunStyle::default())
+            .with(
+                "linked",
+                RunStyle {
+                    link: Some(String::from("https://github.com/vizia/vizia")),
+                    ..Default::default()
+                },
+            )
+            .with(".", RunStyle::default());
+
+        AppData { text }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            RichTextbox::new(cx, AppData::text)
+                .height(Pixels(200.0))
+                .on_edit(|cx, text| cx.emit(AppEvent::SetText(text)));
+
+            Label::new(cx, AppData::text.map(|text| text.to_html())).width(Stretch(1.0));
+        });
+    })
+    .title("Rich Textbox")
+    .run()
+}
@@ -0,0 +1,39 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    document: Rope,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetDocument(document) => self.document = document.clone(),
+        });
+    }
+}
+
+pub enum AppEvent {
+    SetDocument(Rope),
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        // A large document of one hundred thousand lines.
+        let document = (0..100_000)
+            .map(|line| format!("{line:>6}: The quick brown fox jumps over the lazy dog.\n"))
+            .collect::<String>();
+
+        AppData { document: Rope::from_str(&document) }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            TextEditor::new(cx, AppData::document)
+                .size(Stretch(1.0))
+                .on_edit(|cx, document| cx.emit(AppEvent::SetDocument(document)));
+        });
+    })
+    .title("Text Editor")
+    .run()
+}
@@ -7,7 +7,8 @@ pub use vizia_winit::application::{Application, ApplicationError};
 
 #[cfg(all(not(feature = "winit"), feature = "baseview"))]
 pub use vizia_baseview::{
-    Application, ApplicationError, ParentWindow, WindowHandle, WindowScalePolicy,
+    Application, ApplicationError, ParentWindow, WindowHandle, WindowResizePolicy,
+    WindowScalePolicy,
 };
 
 pub use vizia_core::*;
@@ -25,5 +26,8 @@ pub mod prelude {
     };
 
     #[cfg(all(not(feature = "winit"), feature = "baseview"))]
-    pub use vizia_baseview::{Application, ApplicationError, WindowHandle, WindowScalePolicy};
+    pub use vizia_baseview::{
+        Application, ApplicationError, Window, WindowHandle, WindowModifiers, WindowResizePolicy,
+        WindowScalePolicy,
+    };
 }